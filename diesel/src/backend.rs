@@ -431,7 +431,19 @@ pub(crate) mod sql_dialect {
         #[derive(Debug, Copy, Clone)]
         pub struct DoesNotSupportReturningClause;
 
+        /// A marker trait indicating if a `RETURNING` clause is supported for
+        /// `UPDATE` statements as well
+        ///
+        /// Backends that only support `RETURNING` clauses for `INSERT` and
+        /// `DELETE` statements implement only [`SupportsReturningClause`]
+        #[diagnostic::on_unimplemented(
+            message = "the backend does not support `RETURNING` clauses for `UPDATE` statements",
+            note = "some backends, like MariaDB, only support `RETURNING` for `INSERT` and `DELETE` statements"
+        )]
+        pub trait SupportsReturningClauseForUpdate: SupportsReturningClause {}
+
         impl SupportsReturningClause for PgLikeReturningClause {}
+        impl SupportsReturningClauseForUpdate for PgLikeReturningClause {}
     }
 
     /// This module contains all reusable options to configure
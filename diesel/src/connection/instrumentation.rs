@@ -171,6 +171,59 @@ pub enum InstrumentationEvent<'a> {
         /// back transaction
         depth: NonZeroU32,
     },
+    /// An event that is emitted after a query
+    /// finished, reporting counters collected
+    /// while running the prepared statement
+    ///
+    /// Currently only emitted by `SqliteConnection`
+    /// after enabling it via
+    /// `SqliteConnection::set_statement_statistics`
+    #[non_exhaustive]
+    StatementStatistics {
+        /// A opaque representation of the query
+        ///
+        /// This type implements [`Debug`] and [`Display`],
+        /// but should be considered otherwise as opaque.
+        ///
+        /// The exact output of the [`Debug`] and [`Display`]
+        /// implementation is not considered as part of the
+        /// stable API.
+        query: &'a dyn DebugQuery,
+        /// The counters collected for this query
+        statistics: StatementStatistics,
+    },
+}
+
+/// Counters collected while running a single prepared statement
+///
+/// Reported via [`InstrumentationEvent::StatementStatistics`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StatementStatistics {
+    /// Number of times the database stepped forward
+    /// in a table as part of a full table scan
+    pub full_scan_steps: u64,
+    /// Number of sort operations that occurred
+    pub sorts: u64,
+    /// Number of rows inserted into transient indices
+    /// that were automatically created to help joins run faster
+    pub auto_index_rows: u64,
+    /// Number of virtual machine operations executed
+    /// by the statement
+    pub vm_steps: u64,
+}
+
+#[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
+impl StatementStatistics {
+    /// Create a new `StatementStatistics` instance
+    pub fn new(full_scan_steps: u64, sorts: u64, auto_index_rows: u64, vm_steps: u64) -> Self {
+        Self {
+            full_scan_steps,
+            sorts,
+            auto_index_rows,
+            vm_steps,
+        }
+    }
 }
 
 // these constructors exist to
@@ -231,6 +284,15 @@ impl<'a> InstrumentationEvent<'a> {
     pub fn commit_transaction(depth: NonZeroU32) -> Self {
         Self::CommitTransaction { depth }
     }
+
+    /// Create a new `InstrumentationEvent::StatementStatistics` event
+    #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
+    pub fn statement_statistics(
+        query: &'a dyn DebugQuery,
+        statistics: StatementStatistics,
+    ) -> Self {
+        Self::StatementStatistics { query, statistics }
+    }
 }
 
 /// A type that provides an connection `Instrumentation`
@@ -27,7 +27,8 @@ use core::fmt::Debug;
 pub use self::instrumentation::set_default_instrumentation;
 #[doc(inline)]
 pub use self::instrumentation::{
-    DebugQuery, Instrumentation, InstrumentationEvent, get_default_instrumentation,
+    DebugQuery, Instrumentation, InstrumentationEvent, StatementStatistics,
+    get_default_instrumentation,
 };
 #[doc(inline)]
 pub use self::transaction_manager::{
@@ -127,6 +127,9 @@ type_name! {
         Jsonb => "jsonb",
         Inet => "inet",
         Cidr => "cidr",
+        TsVector => "tsvector",
+        TsQuery => "tsquery",
+        RegConfig => "regconfig",
     }
     diesel::mysql::Mysql: "mysql_backend" {
         Int8 => "signed",
@@ -232,6 +235,8 @@ casts_impl!(
     "postgres_backend": (Text <- Cidr),
     "postgres_backend": (Inet <- Cidr),
     "postgres_backend": (Cidr <- Inet),
+    "postgres_backend": (Text <- TsVector),
+    "postgres_backend": (Text <- TsQuery),
 );
 
 macro_rules! fallible_casts_impl {
@@ -269,4 +274,7 @@ fallible_casts_impl!(
     "postgres_backend": (Uuid <- Text),
     "postgres_backend": (Inet <- Text),
     "postgres_backend": (Cidr <- Text),
+    "postgres_backend": (TsVector <- Text),
+    "postgres_backend": (TsQuery <- Text),
+    "postgres_backend": (RegConfig <- Text),
 );
@@ -0,0 +1,561 @@
+use crate::backend::Backend;
+use crate::expression::{
+    AppearsOnTable, AsExpression, Expression, SelectableExpression, TypedExpressionType,
+    ValidGrouping, helper_types,
+};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{IntoNullable, SqlType};
+use core::marker::PhantomData;
+use diesel_derives::DieselNumericOps;
+
+/// Creates a SQL `COALESCE(...)` expression
+///
+/// Returns the first of its arguments that is not null. The arguments are
+/// passed as a tuple of at least two elements. The first element must be an
+/// expression, which determines the SQL type of the whole expression. The
+/// other elements can be expressions of the same type (nullable or not) or
+/// plain Rust values.
+///
+/// The result is only nullable if all arguments are nullable.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::coalesce;
+///
+/// let names = animals
+///     .select(coalesce((name, species)))
+///     .order(id)
+///     .load::<String>(connection)?;
+/// assert_eq!(vec!["Jack", "spider"], names);
+///
+/// let names = animals
+///     .select(coalesce((name, "unknown")))
+///     .order(id)
+///     .load::<String>(connection)?;
+/// assert_eq!(vec!["Jack", "unknown"], names);
+///
+/// let names = animals
+///     .select(coalesce((name, name)))
+///     .order(id)
+///     .load::<Option<String>>(connection)?;
+/// assert_eq!(vec![Some("Jack".to_owned()), None], names);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn coalesce<T, N>(arguments: T) -> helper_types::coalesce<T, N>
+where
+    T: ConditionalArguments<N>,
+{
+    Coalesce {
+        arguments: arguments.into_arguments(),
+        sql_type: PhantomData,
+    }
+}
+
+/// Creates a SQL `GREATEST(...)` expression
+///
+/// Returns the largest of its arguments. The arguments are passed in the same
+/// way as for [`coalesce()`]. This function renders as `MAX(...)` on SQLite.
+///
+/// The result is nullable if any of the arguments is nullable. Null values
+/// are ignored by PostgreSQL, while SQLite and MySQL return `NULL` as soon as
+/// one of the arguments is `NULL`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::greatest;
+///
+/// let legs_at_least_six = animals
+///     .select(greatest((legs, 6)))
+///     .order(id)
+///     .load::<i32>(connection)?;
+/// assert_eq!(vec![6, 8], legs_at_least_six);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn greatest<T, N>(arguments: T) -> helper_types::greatest<T, N>
+where
+    T: ConditionalArguments<N>,
+{
+    Greatest {
+        arguments: arguments.into_arguments(),
+        sql_type: PhantomData,
+    }
+}
+
+/// Creates a SQL `LEAST(...)` expression
+///
+/// Returns the smallest of its arguments. The arguments are passed in the same
+/// way as for [`coalesce()`]. This function renders as `MIN(...)` on SQLite.
+///
+/// The result is nullable if any of the arguments is nullable. Null values
+/// are ignored by PostgreSQL, while SQLite and MySQL return `NULL` as soon as
+/// one of the arguments is `NULL`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::least;
+///
+/// let legs_at_most_six = animals
+///     .select(least((legs, 6)))
+///     .order(id)
+///     .load::<i32>(connection)?;
+/// assert_eq!(vec![4, 6], legs_at_most_six);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn least<T, N>(arguments: T) -> helper_types::least<T, N>
+where
+    T: ConditionalArguments<N>,
+{
+    Least {
+        arguments: arguments.into_arguments(),
+        sql_type: PhantomData,
+    }
+}
+
+/// Creates a SQL `NULLIF(expr, value)` expression
+///
+/// Returns `NULL` if `expr` is equal to `value`, otherwise `expr`. The result
+/// is always nullable.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::nullif;
+///
+/// let unusual_legs = animals
+///     .select(nullif(legs, 4))
+///     .order(id)
+///     .load::<Option<i32>>(connection)?;
+/// assert_eq!(vec![None, Some(8)], unusual_legs);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn nullif<T, U>(expr: T, value: U) -> helper_types::nullif<T, U>
+where
+    T: Expression,
+    U: AsExpression<T::SqlType>,
+    T::SqlType: SqlType,
+{
+    NullIf {
+        expr,
+        value: value.as_expression(),
+    }
+}
+
+pub(crate) use self::non_public_types::*;
+
+mod non_public_types {
+    use super::*;
+    use crate::sql_types::is_nullable::{IsNullable, NotNull};
+    use crate::sql_types::{
+        AllAreNullable, IntoNotNullable, MaybeNullableType, Nullable, OneIsNullable, SingleValue,
+    };
+
+    /// A SQL `COALESCE(...)` expression
+    #[derive(Debug, Clone, Copy, QueryId, DieselNumericOps)]
+    pub struct Coalesce<Args, ST> {
+        pub(super) arguments: Args,
+        pub(super) sql_type: PhantomData<ST>,
+    }
+
+    /// A SQL `GREATEST(...)` expression
+    #[derive(Debug, Clone, Copy, QueryId, DieselNumericOps)]
+    pub struct Greatest<Args, ST> {
+        // only rendered by the backend specific `QueryFragment` impls
+        #[cfg_attr(
+            not(any(
+                feature = "postgres_backend",
+                feature = "mysql_backend",
+                feature = "mariadb_backend",
+                feature = "__sqlite-shared"
+            )),
+            allow(dead_code)
+        )]
+        pub(super) arguments: Args,
+        pub(super) sql_type: PhantomData<ST>,
+    }
+
+    /// A SQL `LEAST(...)` expression
+    #[derive(Debug, Clone, Copy, QueryId, DieselNumericOps)]
+    pub struct Least<Args, ST> {
+        // only rendered by the backend specific `QueryFragment` impls
+        #[cfg_attr(
+            not(any(
+                feature = "postgres_backend",
+                feature = "mysql_backend",
+                feature = "mariadb_backend",
+                feature = "__sqlite-shared"
+            )),
+            allow(dead_code)
+        )]
+        pub(super) arguments: Args,
+        pub(super) sql_type: PhantomData<ST>,
+    }
+
+    /// A SQL `NULLIF(expr, value)` expression
+    #[derive(Debug, Clone, Copy, QueryId, DieselNumericOps, ValidGrouping)]
+    pub struct NullIf<T, U> {
+        pub(super) expr: T,
+        pub(super) value: U,
+    }
+
+    /// The argument list of [`coalesce()`], [`greatest()`] and [`least()`]
+    ///
+    /// This trait is implemented for tuples with at least two elements.
+    /// `N` is a tuple describing the nullability of each argument, it is
+    /// inferred by the compiler.
+    pub trait ConditionalArguments<N> {
+        /// The not nullable SQL type shared by all arguments
+        type SqlType;
+
+        /// The SQL type of `COALESCE(...)` for these arguments
+        type CoalesceSqlType: SqlType + TypedExpressionType;
+
+        /// The SQL type of `GREATEST(...)` and `LEAST(...)` for these arguments
+        type ComparisonSqlType: SqlType + TypedExpressionType;
+
+        /// The arguments converted to expressions
+        type Arguments;
+
+        /// Convert the arguments into expressions
+        fn into_arguments(self) -> Self::Arguments;
+    }
+
+    /// Converts a single argument into an expression of either `ST` or
+    /// `Nullable<ST>`
+    ///
+    /// `N` is either [`NotNull`] or [`IsNullable`]. Both impls can never apply
+    /// to the same type, which allows the compiler to infer `N` for plain Rust
+    /// values as well as for nullable and not nullable expressions.
+    pub trait ConditionalArgument<ST, N> {
+        /// The argument converted to an expression
+        type Expression;
+
+        /// Convert the argument into an expression
+        fn into_argument(self) -> Self::Expression;
+    }
+
+    impl<T, ST> ConditionalArgument<ST, NotNull> for T
+    where
+        T: AsExpression<ST>,
+        ST: SqlType<IsNull = NotNull> + TypedExpressionType,
+    {
+        type Expression = T::Expression;
+
+        fn into_argument(self) -> Self::Expression {
+            self.as_expression()
+        }
+    }
+
+    impl<T, ST> ConditionalArgument<ST, IsNullable> for T
+    where
+        T: Expression<SqlType = Nullable<ST>>,
+        ST: SqlType<IsNull = NotNull>,
+    {
+        type Expression = T;
+
+        fn into_argument(self) -> Self::Expression {
+            self
+        }
+    }
+
+    /// Combines the nullability of all arguments
+    pub trait ArgumentsNullability {
+        /// [`IsNullable`] if all arguments are nullable
+        type All;
+
+        /// [`IsNullable`] if any argument is nullable
+        type Any;
+    }
+
+    /// The nullability of arguments which are all expressions
+    ///
+    /// Used as default for the nullability parameter of the helper types
+    pub trait ExpressionArgumentsNullability {
+        /// A tuple containing the nullability of each argument
+        type Nullability;
+    }
+
+    macro_rules! conditional_arguments_impls {
+        ($(
+            $Tuple:tt {
+                $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+            }
+        )+) => {
+            $(
+                conditional_arguments_impl!($(($idx) -> $T, $ST,)+);
+            )+
+        }
+    }
+
+    macro_rules! conditional_arguments_impl {
+        ((0) -> $T0:ident, $N0:ident,) => {
+            impl<$N0> ArgumentsNullability for ($N0,) {
+                type All = $N0;
+                type Any = $N0;
+            }
+        };
+        ((0) -> $T0:ident, $N0:ident, $(($idx:tt) -> $T:ident, $N:ident,)+) => {
+            impl<$N0, $($N,)+> ArgumentsNullability for ($N0, $($N,)+)
+            where
+                ($($N,)+): ArgumentsNullability,
+                $N0: AllAreNullable<<($($N,)+) as ArgumentsNullability>::All>
+                    + OneIsNullable<<($($N,)+) as ArgumentsNullability>::Any>,
+            {
+                type All = <$N0 as AllAreNullable<<($($N,)+) as ArgumentsNullability>::All>>::Out;
+                type Any = <$N0 as OneIsNullable<<($($N,)+) as ArgumentsNullability>::Any>>::Out;
+            }
+
+            impl<$T0, $($T,)+> ExpressionArgumentsNullability for ($T0, $($T,)+)
+            where
+                $T0: Expression<SqlType: SqlType>,
+                $($T: Expression<SqlType: SqlType>,)+
+            {
+                type Nullability = (
+                    <$T0::SqlType as SqlType>::IsNull,
+                    $(<$T::SqlType as SqlType>::IsNull,)+
+                );
+            }
+
+            impl<$T0, $($T,)+ $N0, $($N,)+ BaseType> ConditionalArguments<($N0, $($N,)+)>
+                for ($T0, $($T,)+)
+            where
+                $T0: Expression<SqlType: IntoNotNullable<NotNullable = BaseType>>
+                    + ConditionalArgument<BaseType, $N0>,
+                $($T: ConditionalArgument<BaseType, $N>,)+
+                BaseType: SqlType<IsNull = NotNull> + SingleValue + TypedExpressionType,
+                ($N0, $($N,)+): ArgumentsNullability<
+                    All: MaybeNullableType<BaseType>,
+                    Any: MaybeNullableType<BaseType>,
+                >,
+            {
+                type SqlType = BaseType;
+                type CoalesceSqlType =
+                    <<($N0, $($N,)+) as ArgumentsNullability>::All as MaybeNullableType<BaseType>>::Out;
+                type ComparisonSqlType =
+                    <<($N0, $($N,)+) as ArgumentsNullability>::Any as MaybeNullableType<BaseType>>::Out;
+                type Arguments = ($T0::Expression, $($T::Expression,)+);
+
+                fn into_arguments(self) -> Self::Arguments {
+                    (self.0.into_argument(), $(self.$idx.into_argument(),)+)
+                }
+            }
+        };
+    }
+
+    crate::for_each_tuple!(conditional_arguments_impls);
+
+    macro_rules! variadic_function_impls {
+        ($($Function:ident,)+) => {
+            $(
+                impl<Args, ST> Expression for $Function<Args, ST>
+                where
+                    Args: Expression,
+                    ST: SqlType + TypedExpressionType,
+                {
+                    type SqlType = ST;
+                }
+
+                impl<Args, ST, GB> ValidGrouping<GB> for $Function<Args, ST>
+                where
+                    Args: ValidGrouping<GB>,
+                {
+                    type IsAggregate = Args::IsAggregate;
+                }
+
+                impl<Args, ST, QS> AppearsOnTable<QS> for $Function<Args, ST>
+                where
+                    Self: Expression,
+                    Args: AppearsOnTable<QS>,
+                {
+                }
+
+                impl<Args, ST, QS> SelectableExpression<QS> for $Function<Args, ST>
+                where
+                    Self: AppearsOnTable<QS>,
+                    Args: SelectableExpression<QS>,
+                {
+                }
+            )+
+        };
+    }
+
+    variadic_function_impls!(Coalesce, Greatest, Least,);
+
+    impl<Args, ST, DB> QueryFragment<DB> for Coalesce<Args, ST>
+    where
+        DB: Backend,
+        Args: QueryFragment<DB>,
+    {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+            out.push_sql("COALESCE(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+
+    impl<T, U> Expression for NullIf<T, U>
+    where
+        T: Expression<SqlType: IntoNullable<Nullable: TypedExpressionType>>,
+        U: Expression,
+    {
+        type SqlType = <T::SqlType as IntoNullable>::Nullable;
+    }
+
+    impl<T, U, QS> AppearsOnTable<QS> for NullIf<T, U>
+    where
+        Self: Expression,
+        T: AppearsOnTable<QS>,
+        U: AppearsOnTable<QS>,
+    {
+    }
+
+    impl<T, U, QS> SelectableExpression<QS> for NullIf<T, U>
+    where
+        Self: AppearsOnTable<QS>,
+        T: SelectableExpression<QS>,
+        U: SelectableExpression<QS>,
+    {
+    }
+
+    impl<T, U, DB> QueryFragment<DB> for NullIf<T, U>
+    where
+        DB: Backend,
+        T: QueryFragment<DB>,
+        U: QueryFragment<DB>,
+    {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+            out.push_sql("NULLIF(");
+            self.expr.walk_ast(out.reborrow())?;
+            out.push_sql(", ");
+            self.value.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+mod postgres {
+    use super::*;
+    use crate::pg::Pg;
+
+    impl<Args: QueryFragment<Pg>, ST> QueryFragment<Pg> for Greatest<Args, ST> {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+            out.push_sql("GREATEST(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+
+    impl<Args: QueryFragment<Pg>, ST> QueryFragment<Pg> for Least<Args, ST> {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+            out.push_sql("LEAST(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+mod mysql_like {
+    use super::*;
+    use crate::mysql_like::MysqlLikeBackend;
+
+    impl<Args, ST, DB> QueryFragment<DB> for Greatest<Args, ST>
+    where
+        DB: MysqlLikeBackend,
+        Args: QueryFragment<DB>,
+    {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+            out.push_sql("GREATEST(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+
+    impl<Args, ST, DB> QueryFragment<DB> for Least<Args, ST>
+    where
+        DB: MysqlLikeBackend,
+        Args: QueryFragment<DB>,
+    {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+            out.push_sql("LEAST(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "__sqlite-shared")]
+mod sqlite {
+    use super::*;
+    use crate::sqlite::Sqlite;
+
+    // SQLite has no `GREATEST`/`LEAST`, but the multi-argument forms of
+    // `MAX`/`MIN` are scalar functions with the same behaviour
+    impl<Args: QueryFragment<Sqlite>, ST> QueryFragment<Sqlite> for Greatest<Args, ST> {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Sqlite>) -> QueryResult<()> {
+            out.push_sql("MAX(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+
+    impl<Args: QueryFragment<Sqlite>, ST> QueryFragment<Sqlite> for Least<Args, ST> {
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Sqlite>) -> QueryResult<()> {
+            out.push_sql("MIN(");
+            self.arguments.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+            Ok(())
+        }
+    }
+}
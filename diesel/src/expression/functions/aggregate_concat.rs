@@ -0,0 +1,78 @@
+#[cfg(doc)]
+use super::aggregate_expressions::AggregateExpressionMethods;
+use crate::expression::functions::declare_sql_function;
+use crate::expression_methods::text_expression_methods::private::TextOrNullableText;
+use crate::sql_types::SingleValue;
+
+#[declare_sql_function]
+#[backend_bounds(crate::expression::functions::aggregate_concat::private::SupportsGroupConcat)]
+extern "SQL" {
+    /// Represents a SQL `GROUP_CONCAT` function, which concatenates the non-null
+    /// values of `expression`, separated by a comma.
+    ///
+    /// Returns `NULL` if there are no non-null input values. The order of
+    /// the concatenated values is arbitrary. SQLite 3.44 or newer allows to
+    /// specify the order via [`AggregateExpressionMethods::aggregate_order`].
+    /// MySQL and MariaDB truncate the result to the length given by the
+    /// `group_concat_max_len` system variable.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression on SQLite. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::group_concat;
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let all_names = animals
+    ///     .select(group_concat(name))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("Jack".to_owned()), all_names);
+    ///
+    /// let no_names = animals
+    ///     .select(group_concat(name))
+    ///     .filter(legs.gt(8))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(None, no_names);
+    /// # #[cfg(feature = "sqlite")]
+    /// # {
+    /// #     assert_version!(connection, 3, 44, 0);
+    ///
+    /// let all_species = animals
+    ///     .select(group_concat(species).aggregate_order(species.desc()))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("spider,dog".to_owned()), all_species);
+    /// # }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    fn group_concat<T: TextOrNullableText + SingleValue>(expression: T) -> Nullable<Text>;
+}
+
+mod private {
+    /// Marker trait for the backends providing the `GROUP_CONCAT` aggregate function
+    pub trait SupportsGroupConcat {}
+
+    #[cfg(feature = "__sqlite-shared")]
+    impl SupportsGroupConcat for crate::sqlite::Sqlite {}
+    #[cfg(feature = "mysql_backend")]
+    impl SupportsGroupConcat for crate::mysql::Mysql {}
+    #[cfg(feature = "mariadb_backend")]
+    impl SupportsGroupConcat for crate::mariadb::Mariadb {}
+}
@@ -23,7 +23,7 @@ macro_rules! empty_clause {
     };
 }
 
-mod aggregate_filter;
+pub(crate) mod aggregate_filter;
 mod aggregate_order;
 pub(crate) mod frame_clause;
 mod over_clause;
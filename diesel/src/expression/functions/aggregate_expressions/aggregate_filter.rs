@@ -16,7 +16,7 @@ use crate::sql_types::BoolOrNullableBool;
 empty_clause!(NoFilter);
 
 #[derive(QueryId, Copy, Clone, Debug)]
-pub struct Filter<P>(P);
+pub struct Filter<P>(pub(crate) P);
 
 impl<P, DB> QueryFragment<DB> for Filter<P>
 where
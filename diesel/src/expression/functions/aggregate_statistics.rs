@@ -0,0 +1,329 @@
+#[cfg(doc)]
+use super::aggregate_expressions::{AggregateExpressionMethods, WindowExpressionMethods};
+use crate::expression::functions::declare_sql_function;
+use crate::sql_types::Foldable;
+
+#[declare_sql_function]
+#[backend_bounds(
+    crate::expression::functions::aggregate_statistics::private::SupportsStatisticalAggregates
+)]
+extern "SQL" {
+    /// Represents a SQL `STDDEV` function. This function can only take types which are
+    /// Foldable.
+    ///
+    /// On PostgreSQL this is an alias for [`stddev_samp`](stddev_samp()),
+    /// on MySQL and MariaDB an alias for [`stddev_pop`](stddev_pop()).
+    ///
+    /// ## Window Function Usage
+    ///
+    /// This function can be used as window function. See [`WindowExpressionMethods`] for details
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     numbers (number) {
+    /// #         number -> Double,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", not(feature = "__sqlite-shared")))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::stddev;
+    /// #     use self::numbers::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE numbers (number FLOAT8)").execute(conn)?;
+    /// diesel::insert_into(numbers)
+    ///     .values(&vec![number.eq(4.0), number.eq(8.0)])
+    ///     .execute(conn)?;
+    /// let deviation = numbers.select(stddev(number)).get_result::<Option<f64>>(conn)?;
+    /// # #[cfg(feature = "postgres")]
+    /// assert!((deviation.unwrap() - 8f64.sqrt()).abs() < 1e-10);
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # assert_eq!(Some(2.0), deviation);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(any(feature = "postgres", not(feature = "__sqlite-shared"))))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    #[window]
+    fn stddev<ST: Foldable>(expr: ST) -> ST::Avg;
+
+    /// Represents a SQL `STDDEV_POP` function, which computes the population
+    /// standard deviation. This function can only take types which are Foldable.
+    ///
+    /// ## Window Function Usage
+    ///
+    /// This function can be used as window function. See [`WindowExpressionMethods`] for details
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     numbers (number) {
+    /// #         number -> Double,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", not(feature = "__sqlite-shared")))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::stddev_pop;
+    /// #     use self::numbers::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE numbers (number FLOAT8)").execute(conn)?;
+    /// diesel::insert_into(numbers)
+    ///     .values(&vec![number.eq(4.0), number.eq(8.0)])
+    ///     .execute(conn)?;
+    /// let deviation = numbers.select(stddev_pop(number)).get_result::<Option<f64>>(conn)?;
+    /// assert_eq!(Some(2.0), deviation);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(any(feature = "postgres", not(feature = "__sqlite-shared"))))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    #[window]
+    fn stddev_pop<ST: Foldable>(expr: ST) -> ST::Avg;
+
+    /// Represents a SQL `STDDEV_SAMP` function, which computes the sample
+    /// standard deviation. This function can only take types which are Foldable.
+    ///
+    /// ## Window Function Usage
+    ///
+    /// This function can be used as window function. See [`WindowExpressionMethods`] for details
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     numbers (number) {
+    /// #         number -> Double,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", not(feature = "__sqlite-shared")))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::stddev_samp;
+    /// #     use self::numbers::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE numbers (number FLOAT8)").execute(conn)?;
+    /// diesel::insert_into(numbers)
+    ///     .values(&vec![number.eq(4.0), number.eq(8.0)])
+    ///     .execute(conn)?;
+    /// let deviation = numbers.select(stddev_samp(number)).get_result::<Option<f64>>(conn)?;
+    /// assert!((deviation.unwrap() - 8f64.sqrt()).abs() < 1e-10);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(any(feature = "postgres", not(feature = "__sqlite-shared"))))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    #[window]
+    fn stddev_samp<ST: Foldable>(expr: ST) -> ST::Avg;
+
+    /// Represents a SQL `VARIANCE` function. This function can only take types which are
+    /// Foldable.
+    ///
+    /// On PostgreSQL this is an alias for [`var_samp`](var_samp()),
+    /// on MySQL and MariaDB an alias for [`var_pop`](var_pop()).
+    ///
+    /// ## Window Function Usage
+    ///
+    /// This function can be used as window function. See [`WindowExpressionMethods`] for details
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     numbers (number) {
+    /// #         number -> Double,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", not(feature = "__sqlite-shared")))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::variance;
+    /// #     use self::numbers::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE numbers (number FLOAT8)").execute(conn)?;
+    /// diesel::insert_into(numbers)
+    ///     .values(&vec![number.eq(4.0), number.eq(8.0)])
+    ///     .execute(conn)?;
+    /// let variance = numbers.select(variance(number)).get_result::<Option<f64>>(conn)?;
+    /// # #[cfg(feature = "postgres")]
+    /// assert_eq!(Some(8.0), variance);
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # assert_eq!(Some(4.0), variance);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(any(feature = "postgres", not(feature = "__sqlite-shared"))))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    #[window]
+    fn variance<ST: Foldable>(expr: ST) -> ST::Avg;
+
+    /// Represents a SQL `VAR_POP` function, which computes the population
+    /// variance. This function can only take types which are Foldable.
+    ///
+    /// ## Window Function Usage
+    ///
+    /// This function can be used as window function. See [`WindowExpressionMethods`] for details
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     numbers (number) {
+    /// #         number -> Double,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", not(feature = "__sqlite-shared")))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::var_pop;
+    /// #     use self::numbers::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE numbers (number FLOAT8)").execute(conn)?;
+    /// diesel::insert_into(numbers)
+    ///     .values(&vec![number.eq(4.0), number.eq(8.0)])
+    ///     .execute(conn)?;
+    /// let variance = numbers.select(var_pop(number)).get_result::<Option<f64>>(conn)?;
+    /// assert_eq!(Some(4.0), variance);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(any(feature = "postgres", not(feature = "__sqlite-shared"))))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    #[window]
+    fn var_pop<ST: Foldable>(expr: ST) -> ST::Avg;
+
+    /// Represents a SQL `VAR_SAMP` function, which computes the sample
+    /// variance. This function can only take types which are Foldable.
+    ///
+    /// ## Window Function Usage
+    ///
+    /// This function can be used as window function. See [`WindowExpressionMethods`] for details
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     numbers (number) {
+    /// #         number -> Double,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", not(feature = "__sqlite-shared")))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::var_samp;
+    /// #     use self::numbers::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE numbers (number FLOAT8)").execute(conn)?;
+    /// diesel::insert_into(numbers)
+    ///     .values(&vec![number.eq(4.0), number.eq(8.0)])
+    ///     .execute(conn)?;
+    /// let variance = numbers.select(var_samp(number)).get_result::<Option<f64>>(conn)?;
+    /// assert_eq!(Some(8.0), variance);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(any(feature = "postgres", not(feature = "__sqlite-shared"))))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[aggregate]
+    #[window]
+    fn var_samp<ST: Foldable>(expr: ST) -> ST::Avg;
+}
+
+mod private {
+    /// Marker trait for the backends providing the `STDDEV` and `VARIANCE`
+    /// families of aggregate functions
+    pub trait SupportsStatisticalAggregates {}
+
+    #[cfg(feature = "postgres_backend")]
+    impl SupportsStatisticalAggregates for crate::pg::Pg {}
+    #[cfg(feature = "mysql_backend")]
+    impl SupportsStatisticalAggregates for crate::mysql::Mysql {}
+    #[cfg(feature = "mariadb_backend")]
+    impl SupportsStatisticalAggregates for crate::mariadb::Mariadb {}
+}
@@ -19,6 +19,62 @@ pub type sum<Expr> = super::aggregate_folding::sum<SqlTypeOf<Expr>, Expr>;
 /// The return type of [`avg(expr)`](crate::dsl::avg())
 pub type avg<Expr> = super::aggregate_folding::avg<SqlTypeOf<Expr>, Expr>;
 
+/// The return type of [`stddev(expr)`](crate::dsl::stddev())
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type stddev<Expr> = super::aggregate_statistics::stddev<SqlTypeOf<Expr>, Expr>;
+
+/// The return type of [`stddev_pop(expr)`](crate::dsl::stddev_pop())
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type stddev_pop<Expr> = super::aggregate_statistics::stddev_pop<SqlTypeOf<Expr>, Expr>;
+
+/// The return type of [`stddev_samp(expr)`](crate::dsl::stddev_samp())
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type stddev_samp<Expr> = super::aggregate_statistics::stddev_samp<SqlTypeOf<Expr>, Expr>;
+
+/// The return type of [`variance(expr)`](crate::dsl::variance())
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type variance<Expr> = super::aggregate_statistics::variance<SqlTypeOf<Expr>, Expr>;
+
+/// The return type of [`var_pop(expr)`](crate::dsl::var_pop())
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type var_pop<Expr> = super::aggregate_statistics::var_pop<SqlTypeOf<Expr>, Expr>;
+
+/// The return type of [`var_samp(expr)`](crate::dsl::var_samp())
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type var_samp<Expr> = super::aggregate_statistics::var_samp<SqlTypeOf<Expr>, Expr>;
+
+/// The return type of [`group_concat(expr)`](crate::dsl::group_concat())
+#[cfg(any(
+    feature = "__sqlite-shared",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub type group_concat<Expr> = super::aggregate_concat::group_concat<SqlTypeOf<Expr>, Expr>;
+
 /// The return type of [`exists(expr)`](crate::dsl::exists())
 pub type exists<Expr> = crate::expression::exists::Exists<Expr>;
 
@@ -101,9 +101,21 @@ macro_rules! no_arg_sql_function {
     };
 }
 
+#[cfg(any(
+    feature = "__sqlite-shared",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub(crate) mod aggregate_concat;
 pub(crate) mod aggregate_expressions;
 pub(crate) mod aggregate_folding;
 pub(crate) mod aggregate_ordering;
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+pub(crate) mod aggregate_statistics;
 pub(crate) mod date_and_time;
 pub(crate) mod helper_types;
 pub(crate) mod window_functions;
@@ -161,6 +161,38 @@ pub type Otherwise<W, E> = expression::case_when::CaseWhen<
     expression::case_when::ElseExpression<Grouped<AsExprOf<E, <W as expression::case_when::CaseWhenTypesExtractor>::OutputExpressionSpecifiedSqlType>>>,
 >;
 
+/// The return type of [`coalesce()`](crate::dsl::coalesce())
+#[allow(non_camel_case_types)] // required for `#[auto_type]`
+pub type coalesce<
+    T,
+    N = <T as expression::conditional::ExpressionArgumentsNullability>::Nullability,
+> = expression::conditional::Coalesce<
+    <T as expression::conditional::ConditionalArguments<N>>::Arguments,
+    <T as expression::conditional::ConditionalArguments<N>>::CoalesceSqlType,
+>;
+
+/// The return type of [`greatest()`](crate::dsl::greatest())
+#[allow(non_camel_case_types)] // required for `#[auto_type]`
+pub type greatest<
+    T,
+    N = <T as expression::conditional::ExpressionArgumentsNullability>::Nullability,
+> = expression::conditional::Greatest<
+    <T as expression::conditional::ConditionalArguments<N>>::Arguments,
+    <T as expression::conditional::ConditionalArguments<N>>::ComparisonSqlType,
+>;
+
+/// The return type of [`least()`](crate::dsl::least())
+#[allow(non_camel_case_types)] // required for `#[auto_type]`
+pub type least<T, N = <T as expression::conditional::ExpressionArgumentsNullability>::Nullability> =
+    expression::conditional::Least<
+        <T as expression::conditional::ConditionalArguments<N>>::Arguments,
+        <T as expression::conditional::ConditionalArguments<N>>::ComparisonSqlType,
+    >;
+
+/// The return type of [`nullif()`](crate::dsl::nullif())
+#[allow(non_camel_case_types)] // required for `#[auto_type]`
+pub type nullif<T, U> = expression::conditional::NullIf<T, AsExpr<U, T>>;
+
 /// Represents the return type of [`.as_select()`](crate::prelude::SelectableHelper::as_select)
 pub type AsSelect<Source, DB> = SelectBy<Source, DB>;
 
@@ -219,6 +251,11 @@ pub use crate::sqlite::expression::helper_types::*;
 #[allow(unreachable_pub)]
 pub use crate::sqlite::expression::helper_types::*;
 
+#[doc(inline)]
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+#[allow(unreachable_pub, ambiguous_glob_reexports)]
+pub use crate::mysql_like::expression::helper_types::*;
+
 /// The return type of [`lhs.retrieve_as_text(rhs)`](crate::expression_methods::AnyJsonExpressionMethods::retrieve_as_text)
 #[cfg(any(feature = "postgres_backend", feature = "__sqlite-shared"))]
 pub type RetrieveAsText<Lhs, Rhs> = Grouped<
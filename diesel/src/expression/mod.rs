@@ -38,6 +38,7 @@ pub(crate) mod nullable;
 pub(crate) mod operators;
 mod case_when;
 pub(crate) mod cast;
+mod conditional;
 pub(crate) mod select_by;
 mod sql_literal;
 pub(crate) mod subselect;
@@ -58,14 +59,30 @@ pub(crate) mod dsl {
     #[doc(inline)]
     pub use super::case_when::case_when;
     #[doc(inline)]
+    pub use super::conditional::{coalesce, greatest, least, nullif};
+    #[doc(inline)]
     pub use super::count::*;
     #[doc(inline)]
     pub use super::exists::exists;
     #[doc(inline)]
+    #[cfg(any(
+        feature = "__sqlite-shared",
+        feature = "mysql_backend",
+        feature = "mariadb_backend"
+    ))]
+    pub use super::functions::aggregate_concat::*;
+    #[doc(inline)]
     pub use super::functions::aggregate_folding::*;
     #[doc(inline)]
     pub use super::functions::aggregate_ordering::*;
     #[doc(inline)]
+    #[cfg(any(
+        feature = "postgres_backend",
+        feature = "mysql_backend",
+        feature = "mariadb_backend"
+    ))]
+    pub use super::functions::aggregate_statistics::*;
+    #[doc(inline)]
     pub use super::functions::date_and_time::*;
     #[doc(inline)]
     pub use super::functions::window_functions::*;
@@ -81,6 +98,10 @@ pub(crate) mod dsl {
     #[cfg(feature = "__sqlite-shared")]
     pub use crate::sqlite::expression::dsl::*;
 
+    #[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+    #[allow(ambiguous_glob_reexports)]
+    pub use crate::mysql_like::expression::dsl::*;
+
     /// The return type of [`count(expr)`](crate::dsl::count())
     pub type count<Expr> = super::count::count<SqlTypeOf<Expr>, Expr>;
 
@@ -10,7 +10,7 @@ mod escape_expression_methods;
 mod global_expression_methods;
 #[cfg(any(feature = "__sqlite-shared", feature = "postgres_backend"))]
 pub(crate) mod json_expression_methods;
-mod text_expression_methods;
+pub(crate) mod text_expression_methods;
 
 #[doc(inline)]
 pub use self::bool_expression_methods::{BoolExpressionMethods, PreferredBoolSqlType};
@@ -48,3 +48,7 @@ pub use crate::pg::expression::expression_methods::*;
 #[cfg(feature = "__sqlite-shared")]
 #[doc(inline)]
 pub use crate::sqlite::expression::expression_methods::*;
+
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+#[doc(inline)]
+pub use crate::mysql_like::expression::expression_methods::*;
@@ -182,7 +182,7 @@ where
 {
 }
 
-mod private {
+pub(crate) mod private {
     use crate::sql_types::{Nullable, Text};
 
     /// Marker trait used to implement `TextExpressionMethods` on the appropriate
@@ -175,3 +175,9 @@ pub mod enum_ {
     #[doc(hidden)]
     pub use crate::types::enum_::{EnumMapping, EnumVariant};
 }
+
+#[doc(hidden)]
+pub mod pg_composite {
+    #[doc(hidden)]
+    pub use crate::expand_pg;
+}
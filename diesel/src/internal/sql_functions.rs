@@ -3,6 +3,10 @@ pub use crate::expression::functions::aggregate_expressions::{
     FunctionFragment, IsAggregateFunction, IsWindowFunction, Order, OverClause,
     WindowFunctionFragment,
 };
+#[doc(hidden)]
+pub use crate::query_source::table_function::{
+    JsonTableColumnList, JsonTableColumnType, TableFunctionColumnList,
+};
 
 #[macro_export]
 #[doc(hidden)]
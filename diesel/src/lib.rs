@@ -196,6 +196,15 @@
 //!   mariadb backend
 //! - `returning_clauses_for_sqlite_3_35`: This feature enables support for `RETURNING` clauses in the sqlite backend.
 //!   Enabling this feature requires sqlite 3.35.0 or newer.
+//! - `sqlite_preupdate_hook`: This feature enables `SqliteConnection::on_preupdate`
+//!   in the sqlite backend. It enables the `preupdate_hook` feature of `libsqlite3-sys`, which generates the
+//!   bindings at build time and therefore requires `libclang`. It does not bundle `libsqlite3`, the linked
+//!   `libsqlite3` needs to be compiled with `SQLITE_ENABLE_PREUPDATE_HOOK`, for the bundled version set
+//!   `LIBSQLITE3_FLAGS=SQLITE_ENABLE_PREUPDATE_HOOK` while building.
+//! - `sqlite_snapshot`: This feature enables `SqliteConnection::snapshot`
+//!   and `SqliteConnection::read_transaction_at` in the
+//!   sqlite backend. It requires a `libsqlite3` compiled with `SQLITE_ENABLE_SNAPSHOT`, for the bundled version
+//!   set `LIBSQLITE3_FLAGS=SQLITE_ENABLE_SNAPSHOT` while building.
 //! - `32-column-tables`: This feature enables support for tables with up to 32 columns.
 //!   This feature is enabled by default. Consider disabling this feature if you write a library crate
 //!   providing general extensions for diesel or if you do not need to support tables with more than 16 columns
@@ -229,6 +238,10 @@
 //!   values from the database using types provided by `ipnet`.
 //! - `numeric`: This feature flag enables support for (de)serializing numeric values from the database using types
 //!   provided by `bigdecimal`
+//! - `bitvec`: This feature flag enables support for (de)serializing PostgreSQL bit string values from the
+//!   database using types provided by `bitvec`
+//! - `pgvector`: This feature flag enables support for (de)serializing `halfvec` values of the PostgreSQL
+//!   `pgvector` extension using types provided by `half`. It implies the `postgres_backend` feature flag.
 //! - `r2d2`: This feature flag enables support for the `r2d2` connection pool implementation.
 //! - `extras`: This feature enables the feature flagged support for any third party crate. This implies the
 //!   following feature flags: `serde_json`, `chrono`, `uuid`, `network-address`, `numeric`, `r2d2`
@@ -401,6 +414,10 @@ pub mod dsl {
     #[cfg(feature = "postgres_backend")]
     #[doc(inline)]
     pub use crate::pg::expression::extensions::TablesampleDsl;
+
+    #[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+    #[doc(inline)]
+    pub use crate::mysql_like::IndexHintDsl;
 }
 
 pub mod helper_types {
@@ -539,6 +556,9 @@ pub mod helper_types {
     /// Represents the return type of [`.nullable()`](SelectNullableDsl::nullable)
     pub type NullableSelect<Source> = <Source as SelectNullableDsl>::Output;
 
+    /// Represents the return type of [`.optimizer_hint(hint)`](crate::prelude::QueryDsl::optimizer_hint)
+    pub type OptimizerHint<Source> = <Source as OptimizerHintDsl>::Output;
+
     /// Represents the return type of [`.group_by(expr)`](crate::prelude::QueryDsl::group_by)
     pub type GroupBy<Source, Expr> = <Source as GroupByDsl<Expr>>::Output;
 
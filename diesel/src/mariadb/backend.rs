@@ -29,7 +29,7 @@ impl TypeMetadata for Mariadb {
 }
 
 impl SqlDialect for Mariadb {
-    type ReturningClause = sql_dialect::returning_clause::DoesNotSupportReturningClause;
+    type ReturningClause = MariadbReturningClause;
 
     type OnConflictClause = MariadbOnConflictClause;
 
@@ -63,6 +63,13 @@ impl SqlDialect for Mariadb {
 impl DieselReserveSpecialization for Mariadb {}
 impl TrustedBackend for Mariadb {}
 
+/// MariaDB supports `RETURNING` clauses for `INSERT`, `REPLACE` and `DELETE`
+/// statements, but not for `UPDATE` statements
+#[derive(Debug, Copy, Clone)]
+pub struct MariadbReturningClause;
+
+impl sql_dialect::returning_clause::SupportsReturningClause for MariadbReturningClause {}
+
 pub(crate) type MariadbOnConflictClause = crate::mysql_like::query_fragments::MysqlOnConflictClause;
 pub(crate) type MariadbStyleDefaultValueClause =
     crate::mysql_like::query_fragments::MysqlStyleDefaultValueClause;
@@ -87,6 +94,7 @@ impl MapErrorNumber for Mariadb {
             1048 | 1364 => DatabaseErrorKind::NotNullViolation,
             4025 => DatabaseErrorKind::CheckViolation,
             1213 => DatabaseErrorKind::SerializationFailure,
+            1205 => DatabaseErrorKind::LockWaitTimeout,
             _ => DatabaseErrorKind::Unknown,
         }
     }
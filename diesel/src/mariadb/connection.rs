@@ -95,6 +95,7 @@ mod tests {
     use crate::connection::Connection;
     use crate::connection::SimpleConnection;
     use crate::query_dsl::RunQueryDsl;
+    use crate::{ExpressionMethods, QueryDsl};
 
     fn connection() -> MariadbConnection {
         dotenvy::dotenv().ok();
@@ -148,4 +149,98 @@ mod tests {
 
         assert_eq!(output, 1);
     }
+
+    crate::table! {
+        returning_test {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    fn setup_returning_test(conn: &mut MariadbConnection) {
+        conn.batch_execute(
+            "DROP TABLE IF EXISTS returning_test; \
+             CREATE TABLE returning_test(id INTEGER PRIMARY KEY AUTO_INCREMENT, name TEXT NOT NULL);",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn returning_clause_is_rendered_without_table_names() {
+        use self::returning_test::dsl::*;
+
+        let query = crate::insert_into(returning_test)
+            .values(name.eq("Sean"))
+            .returning((id, name));
+        assert_eq!(
+            crate::debug_query::<Mariadb, _>(&query).to_string(),
+            "INSERT INTO `returning_test` (`name`) VALUES (?) RETURNING `id`, `name` -- binds: [\"Sean\"]"
+        );
+
+        let query = crate::delete(returning_test.filter(id.eq(1))).returning(name);
+        assert_eq!(
+            crate::debug_query::<Mariadb, _>(&query).to_string(),
+            "DELETE FROM `returning_test` WHERE (`returning_test`.`id` = ?) RETURNING `name` -- binds: [1]"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn insert_returning_loads_inserted_rows() {
+        use self::returning_test::dsl::*;
+        let conn = &mut connection();
+        setup_returning_test(conn);
+
+        let inserted = crate::insert_into(returning_test)
+            .values(&vec![name.eq("Sean"), name.eq("Tess")])
+            .returning((id, name))
+            .get_results::<(i32, String)>(conn)
+            .unwrap();
+        assert_eq!(
+            inserted,
+            vec![(1, "Sean".to_string()), (2, "Tess".to_string())]
+        );
+
+        let inserted = crate::insert_into(returning_test)
+            .values(name.eq("Jim"))
+            .get_result::<(i32, String)>(conn)
+            .unwrap();
+        assert_eq!(inserted, (3, "Jim".to_string()));
+    }
+
+    #[diesel_test_helper::test]
+    fn replace_returning_loads_replaced_rows() {
+        use self::returning_test::dsl::*;
+        let conn = &mut connection();
+        setup_returning_test(conn);
+
+        crate::insert_into(returning_test)
+            .values((id.eq(1), name.eq("Sean")))
+            .execute(conn)
+            .unwrap();
+        let replaced = crate::replace_into(returning_test)
+            .values((id.eq(1), name.eq("Tess")))
+            .returning(name)
+            .get_result::<String>(conn)
+            .unwrap();
+        assert_eq!(replaced, "Tess");
+    }
+
+    #[diesel_test_helper::test]
+    fn delete_returning_loads_deleted_rows() {
+        use self::returning_test::dsl::*;
+        let conn = &mut connection();
+        setup_returning_test(conn);
+
+        crate::insert_into(returning_test)
+            .values(&vec![name.eq("Sean"), name.eq("Tess")])
+            .execute(conn)
+            .unwrap();
+        let deleted = crate::delete(returning_test.filter(name.eq("Sean")))
+            .get_results::<(i32, String)>(conn)
+            .unwrap();
+        assert_eq!(deleted, vec![(1, "Sean".to_string())]);
+
+        let remaining = returning_test.select(name).load::<String>(conn).unwrap();
+        assert_eq!(remaining, vec!["Tess".to_string()]);
+    }
 }
@@ -6,6 +6,7 @@
 pub(crate) mod backend;
 #[cfg(feature = "mariadb")]
 mod connection;
+mod returning;
 
 #[doc(inline)]
 pub use self::backend::{Mariadb, MariadbType};
@@ -13,6 +14,9 @@ pub use self::backend::{Mariadb, MariadbType};
 #[doc(inline)]
 #[cfg(feature = "mariadb")]
 pub use self::connection::MariadbConnection;
+#[doc(inline)]
+#[cfg(feature = "mariadb")]
+pub use crate::mysql_like::{MysqlConnectOptions, TransactionBuilder};
 
 /// The Mariadb query builder
 pub type MariadbQueryBuilder = crate::mysql_like::query_builder::MysqlLikeQueryBuilder<Mariadb>;
@@ -0,0 +1,18 @@
+use crate::backend::Backend;
+use crate::mariadb::backend::MariadbReturningClause;
+use crate::query_builder::returning::ReturningClause;
+use crate::query_builder::{AstPass, QueryFragment};
+use crate::result::QueryResult;
+
+impl<Expr, DB> QueryFragment<DB, MariadbReturningClause> for ReturningClause<Expr>
+where
+    DB: Backend<ReturningClause = MariadbReturningClause>,
+    Expr: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.skip_from(true);
+        out.push_sql(" RETURNING ");
+        self.0.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
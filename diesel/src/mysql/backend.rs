@@ -81,6 +81,7 @@ impl MapErrorNumber for Mysql {
             1048 | 1364 => DatabaseErrorKind::NotNullViolation,
             3819 => DatabaseErrorKind::CheckViolation,
             1213 => DatabaseErrorKind::SerializationFailure,
+            1205 => DatabaseErrorKind::LockWaitTimeout,
             _ => DatabaseErrorKind::Unknown,
         }
     }
@@ -21,6 +21,9 @@ pub use super::mysql_like::sql_types;
 pub use super::mysql_like::{MysqlType, MysqlValue, NumericRepresentation};
 #[doc(inline)]
 pub use crate::mysql_like::data_types;
+#[cfg(feature = "mysql")]
+#[doc(inline)]
+pub use crate::mysql_like::{MysqlConnectOptions, TransactionBuilder};
 
 /// The MySQL query builder
 pub type MysqlQueryBuilder = MysqlLikeQueryBuilder<Mysql>;
@@ -270,7 +270,15 @@ impl Drop for BindData {
 
 impl BindData {
     fn for_input((tpe, data): (MysqlType, Option<Vec<u8>>)) -> Self {
-        let (tpe, flags) = tpe.into();
+        let (tpe, flags) = match tpe {
+            // libmysqlclient does not accept bit input buffers,
+            // so bit values are bound as unsigned integers instead
+            MysqlType::Bit => (
+                ffi::enum_field_types::MYSQL_TYPE_LONGLONG,
+                Flags::UNSIGNED_FLAG,
+            ),
+            tpe => tpe.into(),
+        };
         let is_null = ffi::my_bool::from(data.is_none());
         let (ptr, len, capacity) = bind_buffer(data.unwrap_or_default());
         Self {
@@ -410,14 +418,27 @@ impl BindData {
                 | (MysqlType::Enum, ffi::enum_field_types::MYSQL_TYPE_LONG_BLOB)
                 | (MysqlType::Enum, ffi::enum_field_types::MYSQL_TYPE_BLOB)
                 | (MysqlType::Enum, ffi::enum_field_types::MYSQL_TYPE_VAR_STRING)
-                | (MysqlType::Enum, ffi::enum_field_types::MYSQL_TYPE_STRING) => {
+                | (MysqlType::Enum, ffi::enum_field_types::MYSQL_TYPE_STRING)
+                | (MysqlType::Bit, ffi::enum_field_types::MYSQL_TYPE_TINY)
+                | (MysqlType::Bit, ffi::enum_field_types::MYSQL_TYPE_SHORT)
+                | (MysqlType::Bit, ffi::enum_field_types::MYSQL_TYPE_LONG)
+                | (MysqlType::Bit, ffi::enum_field_types::MYSQL_TYPE_INT24)
+                | (MysqlType::Bit, ffi::enum_field_types::MYSQL_TYPE_LONGLONG) => {
                     (metadata.field_type(), metadata.flags()?)
                 }
 
                 (tpe, _) => tpe.into(),
             }
         } else {
-            (metadata.field_type(), metadata.flags()?)
+            match metadata.field_type() {
+                // libmysqlclient does not accept geometry output buffers
+                // so we request the raw internal representation instead
+                ffi::enum_field_types::MYSQL_TYPE_GEOMETRY => (
+                    ffi::enum_field_types::MYSQL_TYPE_BLOB,
+                    metadata.flags()? | Flags::BINARY_FLAG,
+                ),
+                field_type => (field_type, metadata.flags()?),
+            }
         };
         Ok(Self::from_tpe_and_flags((tpe, flags)))
     }
@@ -794,13 +815,15 @@ impl From<(ffi::enum_field_types, Flags)> for MysqlType {
                  If you ever see this error, something has gone very wrong. \
                  Please open an issue at the diesel github repo in this case"
             ),
+            // Spatial values are transmitted in their internal binary representation
+            enum_field_types::MYSQL_TYPE_GEOMETRY => MysqlType::Blob,
+
             // Those exist in libmysqlclient
             // but are just not supported
             //
             enum_field_types::MYSQL_TYPE_VARCHAR
             | enum_field_types::MYSQL_TYPE_ENUM
-            | enum_field_types::MYSQL_TYPE_SET
-            | enum_field_types::MYSQL_TYPE_GEOMETRY => {
+            | enum_field_types::MYSQL_TYPE_SET => {
                 unimplemented!(
                     "Hit a type that should be unsupported in libmysqlclient. If \
                      you ever see this error, they probably have added support for \
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use crate::result::{ConnectionError, ConnectionResult};
+
+/// Options controlling how a [`MysqlLikeConnection`](super::MysqlLikeConnection)
+/// is established, passed to
+/// [`establish_with_options`](super::MysqlLikeConnection::establish_with_options).
+///
+/// Each option can also be set as query parameter of the connection URL
+/// (`connect_timeout`, `read_timeout`, `write_timeout`, `compress`,
+/// `init_command`, `charset` and `collation`). Options set here take precedence
+/// over the ones given in the URL, except for init commands, which are
+/// cumulative: the `init_command` of the URL runs first, followed by the
+/// commands added with [`init_command`](Self::init_command).
+///
+/// The defaults match [`Connection::establish`](crate::Connection::establish):
+/// the timeouts of the client library, no compression and the `utf8mb4`
+/// character set with its default collation.
+///
+/// ```rust
+/// # use diesel::mysql_like::MysqlConnectOptions;
+/// let options = MysqlConnectOptions::new()
+///     .connect_timeout(5)
+///     .read_timeout(30)
+///     .write_timeout(30)
+///     .init_command("SET SESSION sql_mode = 'TRADITIONAL'");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MysqlConnectOptions {
+    connect_timeout: Option<u32>,
+    read_timeout: Option<u32>,
+    write_timeout: Option<u32>,
+    compress: Option<bool>,
+    init_commands: Vec<String>,
+    charset: Option<String>,
+    collation: Option<String>,
+}
+
+impl MysqlConnectOptions {
+    /// Creates options matching the behaviour of
+    /// [`Connection::establish`](crate::Connection::establish).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails establishing the connection if the server does not answer
+    /// within `seconds` (`MYSQL_OPT_CONNECT_TIMEOUT`).
+    pub fn connect_timeout(mut self, seconds: u32) -> Self {
+        self.connect_timeout = Some(seconds);
+        self
+    }
+
+    /// Fails a query if reading from the server takes longer than `seconds`
+    /// (`MYSQL_OPT_READ_TIMEOUT`). The client library retries a read once
+    /// on timeout, so a query may wait up to twice as long.
+    pub fn read_timeout(mut self, seconds: u32) -> Self {
+        self.read_timeout = Some(seconds);
+        self
+    }
+
+    /// Fails a query if writing to the server takes longer than `seconds`
+    /// (`MYSQL_OPT_WRITE_TIMEOUT`).
+    pub fn write_timeout(mut self, seconds: u32) -> Self {
+        self.write_timeout = Some(seconds);
+        self
+    }
+
+    /// Whether to compress the traffic between client and server
+    /// (`MYSQL_OPT_COMPRESS`). Defaults to `false`.
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = Some(compress);
+        self
+    }
+
+    /// Runs `sql` whenever the connection is established
+    /// (`MYSQL_INIT_COMMAND`), in the order the commands were added.
+    /// Calling this method again adds another command instead of replacing
+    /// the previous one, and an `init_command` given in the connection URL
+    /// is kept as well.
+    ///
+    /// The statement is sent to the server verbatim, so it must not come
+    /// from untrusted input.
+    pub fn init_command(mut self, sql: impl Into<String>) -> Self {
+        self.init_commands.push(sql.into());
+        self
+    }
+
+    /// Sets the character set of the connection. Defaults to `utf8mb4`.
+    ///
+    /// Diesel expects all text sent by the server to be valid UTF-8, so only
+    /// `utf8mb4`, `utf8mb3` and `utf8` are accepted. Any other value fails
+    /// establishing the connection.
+    pub fn charset(mut self, charset: impl Into<String>) -> Self {
+        self.charset = Some(charset.into());
+        self
+    }
+
+    /// Sets the collation of the connection (`collation_connection`),
+    /// which must belong to the character set of the connection.
+    pub fn collation(mut self, collation: impl Into<String>) -> Self {
+        self.collation = Some(collation.into());
+        self
+    }
+
+    pub(super) fn from_query_pairs(
+        query_pairs: &HashMap<String, String>,
+    ) -> ConnectionResult<Self> {
+        let timeout = |name: &str| match query_pairs.get(name) {
+            Some(v) => v.parse().map(Some).map_err(|_| {
+                ConnectionError::InvalidConnectionUrl(alloc::format!("invalid {name}"))
+            }),
+            None => Ok(None),
+        };
+
+        let compress = match query_pairs.get("compress") {
+            Some(v) => match v.parse() {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    let msg = "invalid compress";
+                    return Err(ConnectionError::InvalidConnectionUrl(msg.into()));
+                }
+            },
+            None => None,
+        };
+
+        let options = MysqlConnectOptions {
+            connect_timeout: timeout("connect_timeout")?,
+            read_timeout: timeout("read_timeout")?,
+            write_timeout: timeout("write_timeout")?,
+            compress,
+            init_commands: query_pairs
+                .get("init_command")
+                .cloned()
+                .into_iter()
+                .collect(),
+            charset: query_pairs.get("charset").cloned(),
+            collation: query_pairs.get("collation").cloned(),
+        };
+        options
+            .validate()
+            .map_err(|msg| ConnectionError::InvalidConnectionUrl(msg.into()))?;
+        Ok(options)
+    }
+
+    /// Overrides these options with all options set in `other`, appending
+    /// its init commands to the ones already set
+    pub(super) fn merge(&mut self, other: &MysqlConnectOptions) -> ConnectionResult<()> {
+        self.connect_timeout = other.connect_timeout.or(self.connect_timeout);
+        self.read_timeout = other.read_timeout.or(self.read_timeout);
+        self.write_timeout = other.write_timeout.or(self.write_timeout);
+        self.compress = other.compress.or(self.compress);
+        self.init_commands
+            .extend(other.init_commands.iter().cloned());
+        if other.charset.is_some() {
+            self.charset.clone_from(&other.charset);
+        }
+        if other.collation.is_some() {
+            self.collation.clone_from(&other.collation);
+        }
+        self.validate()
+            .map_err(|msg| ConnectionError::BadConnection(msg.into()))
+    }
+
+    fn validate(&self) -> Result<(), &'static str> {
+        let is_utf8 = self.charset.as_deref().is_none_or(|charset| {
+            matches!(
+                charset.to_lowercase().as_str(),
+                "utf8mb4" | "utf8mb3" | "utf8"
+            )
+        });
+        if !is_utf8 {
+            return Err("diesel requires a UTF-8 charset, use `utf8mb4`, `utf8mb3` or `utf8`");
+        }
+        // the collation is interpolated into a `SET` statement
+        let is_valid_collation = self.collation.as_deref().is_none_or(|collation| {
+            !collation.is_empty()
+                && collation
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+        if !is_valid_collation {
+            return Err("invalid collation");
+        }
+        Ok(())
+    }
+
+    pub(super) fn connect_timeout_secs(&self) -> Option<u32> {
+        self.connect_timeout
+    }
+
+    pub(super) fn read_timeout_secs(&self) -> Option<u32> {
+        self.read_timeout
+    }
+
+    pub(super) fn write_timeout_secs(&self) -> Option<u32> {
+        self.write_timeout
+    }
+
+    pub(super) fn compression(&self) -> bool {
+        self.compress.unwrap_or(false)
+    }
+
+    pub(super) fn init_commands(&self) -> &[String] {
+        &self.init_commands
+    }
+
+    pub(super) fn charset_name(&self) -> &str {
+        self.charset.as_deref().unwrap_or("utf8mb4")
+    }
+
+    pub(super) fn collation_name(&self) -> Option<&str> {
+        self.collation.as_deref()
+    }
+}
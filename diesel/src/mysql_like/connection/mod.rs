@@ -1,20 +1,29 @@
 mod bind;
+mod connect_options;
+mod procedure;
 mod raw;
 mod stmt;
 mod url;
 
 use core::num::NonZeroU64;
 
+pub use self::connect_options::MysqlConnectOptions;
+pub use self::procedure::{ProcedureResultSet, ProcedureResults};
+
 use self::raw::RawConnection;
 use self::stmt::Statement;
-use self::stmt::iterator::StatementIterator;
+use self::stmt::iterator::{StatementIterator, collect_result_set};
 use self::url::ConnectionOptions;
 use crate::RunQueryDsl;
 use crate::connection::instrumentation::{DebugQuery, DynInstrumentation, StrQueryHelper};
 use crate::connection::statement_cache::{MaybeCached, StatementCache};
 use crate::connection::*;
 use crate::expression::QueryMetadata;
-use crate::mysql_like::MysqlLikeBackend;
+use crate::mysql_like::query_builder::load_data::{
+    InternalLoadDataQuery, LoadDataExpression, LoadDataReader,
+};
+use crate::mysql_like::query_builder::procedure_call::ProcedureCall;
+use crate::mysql_like::{MysqlLikeBackend, TransactionBuilder};
 use crate::query_builder::bind_collector::RawBytesBindCollector;
 use crate::query_builder::*;
 use crate::result::*;
@@ -157,13 +166,19 @@ impl<DB: MysqlLikeBackend> Connection for MysqlLikeConnection<DB> {
     /// * `ssl_mode` expects a value defined for MySQL client command option `--ssl-mode`
     ///   See <https://dev.mysql.com/doc/refman/5.7/en/connection-options.html#option_general_ssl-mode>
     /// * `local_infile` expects a boolean to enable or disable LOAD DATA LOCAL
+    /// * `connect_timeout`, `read_timeout` and `write_timeout` expect a number of seconds
+    /// * `compress` expects a boolean to enable or disable protocol compression
+    /// * `init_command` expects a SQL statement to run after connecting
+    /// * `charset` and `collation` override the `utf8mb4` character set and its default collation
+    ///
+    /// See [`MysqlConnectOptions`] for details on these options.
     fn establish(database_url: &str) -> ConnectionResult<Self> {
         let mut instrumentation = DynInstrumentation::default_instrumentation();
         instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
             url: database_url,
         });
 
-        let establish_result = Self::establish_inner(database_url);
+        let establish_result = Self::establish_inner(database_url, &MysqlConnectOptions::new());
         instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
             url: database_url,
             error: establish_result.as_ref().err(),
@@ -333,6 +348,202 @@ fn prepared_query<'a, DB: MysqlLikeBackend + Default, T: QueryFragment<DB> + Que
 }
 
 impl<DB: MysqlLikeBackend> MysqlLikeConnection<DB> {
+    /// Build a transaction, specifying additional details such as isolation level
+    ///
+    /// See [`TransactionBuilder`] for more examples.
+    ///
+    /// [`TransactionBuilder`]: crate::mysql_like::TransactionBuilder
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .repeatable_read()
+    ///     .with_consistent_snapshot()
+    ///     .read_only()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn build_transaction(&mut self) -> TransactionBuilder<'_, Self> {
+        TransactionBuilder::new(self)
+    }
+
+    /// Establish a connection to the database specified by `database_url`,
+    /// using the connection `options`.
+    ///
+    /// This behaves like [`Connection::establish`], but allows to set timeouts,
+    /// protocol compression, commands to run after connecting and the
+    /// character set and collation of the connection. Options set in `options`
+    /// take precedence over the same options given as query parameters of
+    /// `database_url`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::mysql_like::MysqlConnectOptions;
+    /// # use diesel::sql_types::Text;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let database_url = database_url_for_env();
+    /// let options = MysqlConnectOptions::new()
+    ///     .connect_timeout(5)
+    ///     .read_timeout(30)
+    ///     .write_timeout(30)
+    ///     .init_command("SET @application = 'diesel'");
+    /// let conn = &mut DbConnection::establish_with_options(&database_url, &options)?;
+    ///
+    /// let application = diesel::select(sql::<Text>("@application"))
+    ///     .get_result::<String>(conn)?;
+    /// assert_eq!("diesel", application);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn establish_with_options(
+        database_url: &str,
+        options: &MysqlConnectOptions,
+    ) -> ConnectionResult<Self> {
+        let mut instrumentation = DynInstrumentation::default_instrumentation();
+        instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
+            url: database_url,
+        });
+
+        let establish_result = Self::establish_inner(database_url, options);
+        instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
+            url: database_url,
+            error: establish_result.as_ref().err(),
+        });
+        let mut conn = establish_result?;
+        conn.instrumentation = instrumentation;
+        Ok(conn)
+    }
+
+    /// Calls the stored procedure `name` and returns all result sets it produced
+    ///
+    /// `args` is rendered as the argument list of the `CALL` statement. Pass a tuple
+    /// of bind parameters created by [`IntoSql::into_sql`], or `()` for a procedure
+    /// without arguments. The name may be qualified with the database name,
+    /// e.g. `"my_db.my_procedure"`.
+    ///
+    /// Each [`ProcedureResultSet`] can be loaded either into a type implementing
+    /// [`QueryableByName`] via [`load`] or into a type implementing
+    /// [`Queryable`] via [`load_typed`]. All result sets are read before this
+    /// function returns, so the connection can be used again right away.
+    ///
+    /// # `OUT` and `INOUT` parameters
+    ///
+    /// Pass a bind parameter for every `OUT` or `INOUT` parameter, using `NULL`
+    /// (e.g. `None::<i32>.into_sql::<Nullable<Integer>>()`) for `OUT` parameters.
+    /// If the procedure declares such parameters, the server returns their values
+    /// after all other result sets, as an additional result set containing a
+    /// single row with one column per parameter.
+    ///
+    /// [`IntoSql::into_sql`]: crate::expression::IntoSql::into_sql
+    /// [`QueryableByName`]: crate::deserialize::QueryableByName
+    /// [`Queryable`]: crate::deserialize::Queryable
+    /// [`load`]: ProcedureResultSet::load
+    /// [`load_typed`]: ProcedureResultSet::load_typed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::connection::SimpleConnection;
+    /// # use diesel::sql_types::{Integer, Nullable};
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users;
+    /// #     let conn = &mut establish_connection();
+    /// #[derive(QueryableByName, Debug, PartialEq)]
+    /// #[diesel(table_name = users)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// conn.batch_execute("DROP PROCEDURE IF EXISTS users_from")?;
+    /// conn.batch_execute(
+    ///     "CREATE PROCEDURE users_from(IN min_id INTEGER, OUT user_count INTEGER)
+    ///      BEGIN
+    ///          SELECT id, name FROM users WHERE id >= min_id ORDER BY id;
+    ///          SELECT COUNT(*) INTO user_count FROM users WHERE id >= min_id;
+    ///      END",
+    /// )?;
+    ///
+    /// let mut results = conn.call_procedure(
+    ///     "users_from",
+    ///     (
+    ///         2.into_sql::<Integer>(),
+    ///         None::<i32>.into_sql::<Nullable<Integer>>(),
+    ///     ),
+    /// )?;
+    ///
+    /// let users = results.next().unwrap().load::<User>()?;
+    /// assert_eq!(vec![User { id: 2, name: "Tess".into() }], users);
+    ///
+    /// let user_count = results
+    ///     .next()
+    ///     .unwrap()
+    ///     .load_typed::<Nullable<Integer>, Option<i32>>()?;
+    /// assert_eq!(vec![Some(1)], user_count);
+    /// assert!(results.next().is_none());
+    /// #     conn.batch_execute("DROP PROCEDURE users_from")?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn call_procedure<A>(&mut self, name: &str, args: A) -> QueryResult<ProcedureResults<DB>>
+    where
+        A: QueryFragment<DB> + QueryId,
+    {
+        let source = ProcedureCall::new(name, args);
+        #[allow(unsafe_code)] // call to unsafe function
+        update_transaction_manager_status(
+            prepared_query(
+                &source,
+                &mut self.statement_cache,
+                &mut self.raw_connection,
+                &mut *self.instrumentation,
+            )
+            .and_then(|stmt| {
+                // SAFETY: `prepared_query` returned this statement freshly
+                // bound, so no result set is pending, which is `execute`'s
+                // requirement.
+                let mut stmt_use = unsafe { stmt.execute() }?;
+                let mut result_sets = Vec::new();
+                loop {
+                    // the final result of a `CALL` statement only carries
+                    // its status and no columns
+                    if stmt_use.field_count() > 0 {
+                        let rows = collect_result_set(&stmt_use)?;
+                        result_sets.push(ProcedureResultSet::new(rows));
+                    }
+                    if !stmt_use.next_result()? {
+                        break;
+                    }
+                }
+                Ok(ProcedureResults::new(result_sets))
+            }),
+            &mut self.transaction_state,
+            &mut self.instrumentation,
+            &crate::debug_query(&source),
+        )
+    }
+
     /// Executes `source` and returns its `mysql_stmt_insert_id`, zero mapped
     /// to `None`. Public entry point: [`InsertStatement::execute_returning_id`].
     pub(crate) fn execute_returning_id<T>(&mut self, source: &T) -> QueryResult<Option<NonZeroU64>>
@@ -360,19 +571,64 @@ impl<DB: MysqlLikeBackend> MysqlLikeConnection<DB> {
         )
     }
 
-    fn set_config_options(&mut self) -> QueryResult<()> {
+    /// Executes a `LOAD DATA LOCAL INFILE` statement. Public entry point:
+    /// [`ExecuteLoadDataDsl::execute`](crate::mysql_like::ExecuteLoadDataDsl::execute).
+    pub(crate) fn load_data<S, T>(&mut self, mut source: S) -> Result<usize, S::Error>
+    where
+        S: LoadDataExpression<T, DB>,
+    {
+        let query = InternalLoadDataQuery::<S, T>::new(&source);
+        let mut query_builder = DB::QueryBuilder::default();
+        query.to_sql(&mut query_builder, &DB::default())?;
+        let sql = query_builder.finish();
+
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::StartQuery {
+                query: &StrQueryHelper::new(&sql),
+            });
+        let mut reader = LoadDataReader::<S, T, DB>::new(&mut source);
+        let res = self
+            .raw_connection
+            .load_local_infile::<DB>(&sql, &mut reader)
+            .and_then(|rows| usize::try_from(rows).map_err(Error::IntegerConversion));
+        // an error of the source is more helpful than the
+        // generic error reported by the server in that case
+        let source_error = reader.error.take();
+        let res = update_transaction_manager_status(
+            res,
+            &mut self.transaction_state,
+            &mut self.instrumentation,
+            &StrQueryHelper::new(&sql),
+        );
+        match source_error {
+            Some(e) => Err(e),
+            None => Ok(res?),
+        }
+    }
+
+    fn set_config_options(&mut self, connect_options: &MysqlConnectOptions) -> QueryResult<()> {
+        // the charset and collation names are validated
+        // by `MysqlConnectOptions`
+        let charset = connect_options.charset_name();
         crate::sql_query("SET time_zone = '+00:00';").execute(self)?;
-        crate::sql_query("SET character_set_client = 'utf8mb4'").execute(self)?;
-        crate::sql_query("SET character_set_connection = 'utf8mb4'").execute(self)?;
-        crate::sql_query("SET character_set_results = 'utf8mb4'").execute(self)?;
+        crate::sql_query(format!("SET character_set_client = '{charset}'")).execute(self)?;
+        crate::sql_query(format!("SET character_set_connection = '{charset}'")).execute(self)?;
+        crate::sql_query(format!("SET character_set_results = '{charset}'")).execute(self)?;
+        if let Some(collation) = connect_options.collation_name() {
+            crate::sql_query(format!("SET collation_connection = '{collation}'")).execute(self)?;
+        }
         Ok(())
     }
 
-    fn establish_inner(database_url: &str) -> Result<MysqlLikeConnection<DB>, ConnectionError> {
+    fn establish_inner(
+        database_url: &str,
+        options: &MysqlConnectOptions,
+    ) -> Result<MysqlLikeConnection<DB>, ConnectionError> {
         use crate::ConnectionError::CouldntSetupConfiguration;
 
         let raw_connection = RawConnection::new();
-        let connection_options = ConnectionOptions::parse::<DB>(database_url)?;
+        let mut connection_options = ConnectionOptions::parse::<DB>(database_url)?;
+        connection_options.merge_connect_options(options)?;
         raw_connection.connect(&connection_options)?;
         let mut conn = MysqlLikeConnection {
             raw_connection,
@@ -380,7 +636,7 @@ impl<DB: MysqlLikeBackend> MysqlLikeConnection<DB> {
             statement_cache: StatementCache::new(),
             instrumentation: DynInstrumentation::none(),
         };
-        conn.set_config_options()
+        conn.set_config_options(connection_options.connect_options())
             .map_err(CouldntSetupConfiguration)?;
         Ok(conn)
     }
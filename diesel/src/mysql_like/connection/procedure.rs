@@ -0,0 +1,89 @@
+use super::stmt::iterator::MysqlRow;
+use crate::deserialize::FromSqlRow;
+use crate::mysql_like::MysqlLikeBackend;
+use crate::result::{Error, QueryResult};
+use crate::sql_types::Untyped;
+
+/// The result sets returned by a stored procedure
+///
+/// This iterator is returned by
+/// [`MysqlLikeConnection::call_procedure`](crate::mysql_like::MysqlLikeConnection::call_procedure)
+/// and yields one [`ProcedureResultSet`] for each result set the procedure returned,
+/// in the order they were sent by the server.
+#[allow(missing_debug_implementations)]
+pub struct ProcedureResults<DB: MysqlLikeBackend> {
+    result_sets: alloc::vec::IntoIter<ProcedureResultSet<DB>>,
+}
+
+impl<DB: MysqlLikeBackend> ProcedureResults<DB> {
+    pub(super) fn new(result_sets: Vec<ProcedureResultSet<DB>>) -> Self {
+        ProcedureResults {
+            result_sets: result_sets.into_iter(),
+        }
+    }
+}
+
+impl<DB: MysqlLikeBackend> Iterator for ProcedureResults<DB> {
+    type Item = ProcedureResultSet<DB>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.result_sets.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.result_sets.size_hint()
+    }
+}
+
+impl<DB: MysqlLikeBackend> ExactSizeIterator for ProcedureResults<DB> {}
+
+/// A single result set returned by a stored procedure
+///
+/// See [`MysqlLikeConnection::call_procedure`](crate::mysql_like::MysqlLikeConnection::call_procedure)
+/// for details.
+#[allow(missing_debug_implementations)]
+pub struct ProcedureResultSet<DB: MysqlLikeBackend> {
+    rows: Vec<MysqlRow<DB>>,
+}
+
+impl<DB: MysqlLikeBackend> ProcedureResultSet<DB> {
+    pub(super) fn new(rows: Vec<MysqlRow<DB>>) -> Self {
+        ProcedureResultSet { rows }
+    }
+
+    /// The number of rows in this result set
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if this result set contains no rows
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Loads the rows of this result set into a type implementing
+    /// [`QueryableByName`](crate::deserialize::QueryableByName)
+    ///
+    /// Columns are looked up by their name, just as for
+    /// [`sql_query`](crate::sql_query()).
+    pub fn load<U>(&self) -> QueryResult<Vec<U>>
+    where
+        U: FromSqlRow<Untyped, DB>,
+    {
+        self.load_typed::<Untyped, U>()
+    }
+
+    /// Loads the rows of this result set into a type implementing
+    /// [`Queryable`](crate::deserialize::Queryable) for the SQL type `ST`
+    ///
+    /// Columns are mapped by their position.
+    pub fn load_typed<ST, U>(&self) -> QueryResult<Vec<U>>
+    where
+        U: FromSqlRow<ST, DB>,
+    {
+        self.rows
+            .iter()
+            .map(|row| U::build_from_row(row).map_err(Error::DeserializationError))
+            .collect()
+    }
+}
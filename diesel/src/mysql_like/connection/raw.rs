@@ -1,4 +1,5 @@
 #![allow(unsafe_code)] // module uses ffi
+use alloc::ffi::CString;
 use core::ffi as libc;
 use core::ffi::CStr;
 use core::ptr::{self, NonNull};
@@ -80,6 +81,24 @@ impl RawConnection {
             self.set_local_infile(local_infile)
         }
 
+        let connect_options = connection_options.connect_options();
+        if let Some(timeout) = connect_options.connect_timeout_secs() {
+            self.set_timeout(ffi::mysql_option::MYSQL_OPT_CONNECT_TIMEOUT, timeout)
+        }
+        if let Some(timeout) = connect_options.read_timeout_secs() {
+            self.set_timeout(ffi::mysql_option::MYSQL_OPT_READ_TIMEOUT, timeout)
+        }
+        if let Some(timeout) = connect_options.write_timeout_secs() {
+            self.set_timeout(ffi::mysql_option::MYSQL_OPT_WRITE_TIMEOUT, timeout)
+        }
+        if connect_options.compression() {
+            self.set_compress()
+        }
+        for init_command in connect_options.init_commands() {
+            self.set_init_command(&CString::new(init_command.as_bytes())?)
+        }
+        self.set_charset_name(&CString::new(connect_options.charset_name())?);
+
         unsafe {
             // Make sure you don't use the fake one!
             ffi::mysql_real_connect(
@@ -132,6 +151,55 @@ impl RawConnection {
         Ok(())
     }
 
+    /// Executes a `LOAD DATA LOCAL INFILE` statement, answering the file
+    /// request of the server with the data produced by `source`
+    ///
+    /// Returns the number of loaded rows
+    pub(super) fn load_local_infile<DB: MysqlLikeBackend>(
+        &self,
+        query: &str,
+        source: &mut dyn std::io::Read,
+    ) -> QueryResult<u64> {
+        use crate::result::Error::DatabaseError;
+
+        let mut state = LocalInfileState {
+            source,
+            error: None,
+            panic: None,
+        };
+        let result = unsafe {
+            ffi::mysql_set_local_infile_handler(
+                self.0.as_ptr(),
+                Some(local_infile_init),
+                Some(local_infile_read),
+                Some(local_infile_end),
+                Some(local_infile_error),
+                &mut state as *mut LocalInfileState<'_> as *mut libc::c_void,
+            );
+            let result = ffi::mysql_real_query(
+                self.0.as_ptr(),
+                query.as_ptr() as *const libc::c_char,
+                query.len() as libc::c_ulong,
+            );
+            // the handler references `state`, so it must not outlive this call
+            ffi::mysql_set_local_infile_default(self.0.as_ptr());
+            result
+        };
+        if let Some(panic) = state.panic {
+            std::panic::resume_unwind(panic);
+        }
+        if result != 0 {
+            let error_number = unsafe { ffi::mysql_errno(self.0.as_ptr()) };
+            return Err(DatabaseError(
+                DB::map_error_number(error_number),
+                Box::new(self.last_error_message()),
+            ));
+        }
+        let affected_rows = unsafe { ffi::mysql_affected_rows(self.0.as_ptr()) };
+        self.flush_pending_results()?;
+        Ok(affected_rows)
+    }
+
     pub(super) fn enable_multi_statements<T, F>(&self, f: F) -> QueryResult<T>
     where
         F: FnOnce() -> QueryResult<T>,
@@ -260,6 +328,49 @@ impl RawConnection {
         };
     }
 
+    fn set_timeout(&self, option: ffi::mysql_option, seconds: u32) {
+        let v_ptr: *const u32 = &seconds;
+        unsafe {
+            mysqlclient_sys::mysql_options(
+                self.0.as_ptr(),
+                option,
+                v_ptr as *const core::ffi::c_void,
+            )
+        };
+    }
+
+    fn set_compress(&self) {
+        unsafe {
+            mysqlclient_sys::mysql_options(
+                self.0.as_ptr(),
+                mysqlclient_sys::mysql_option::MYSQL_OPT_COMPRESS,
+                ptr::null(),
+            )
+        };
+    }
+
+    fn set_init_command(&self, init_command: &CStr) {
+        // the client library copies the command and
+        // runs all commands set this way in order
+        unsafe {
+            mysqlclient_sys::mysql_options(
+                self.0.as_ptr(),
+                mysqlclient_sys::mysql_option::MYSQL_INIT_COMMAND,
+                init_command.as_ptr() as *const core::ffi::c_void,
+            )
+        };
+    }
+
+    fn set_charset_name(&self, charset: &CStr) {
+        unsafe {
+            mysqlclient_sys::mysql_options(
+                self.0.as_ptr(),
+                mysqlclient_sys::mysql_option::MYSQL_SET_CHARSET_NAME,
+                charset.as_ptr() as *const core::ffi::c_void,
+            )
+        };
+    }
+
     fn set_local_infile(&self, local_infile: bool) {
         let v = local_infile as u32;
         let v_ptr: *const u32 = &v;
@@ -282,6 +393,79 @@ impl Drop for RawConnection {
     }
 }
 
+struct LocalInfileState<'a> {
+    source: &'a mut dyn std::io::Read,
+    error: Option<std::io::Error>,
+    panic: Option<Box<dyn core::any::Any + Send>>,
+}
+
+// CR_UNKNOWN_ERROR, reported to the server if reading the data fails
+const LOCAL_INFILE_READ_ERROR: libc::c_int = 2000;
+
+unsafe extern "C" fn local_infile_init(
+    ptr: *mut *mut libc::c_void,
+    _filename: *const libc::c_char,
+    userdata: *mut libc::c_void,
+) -> libc::c_int {
+    // the file name is ignored, the data always comes from the source
+    // registered by `load_local_infile`
+    unsafe { *ptr = userdata };
+    0
+}
+
+unsafe extern "C" fn local_infile_read(
+    ptr: *mut libc::c_void,
+    buf: *mut libc::c_char,
+    buf_len: libc::c_uint,
+) -> libc::c_int {
+    let state = unsafe { &mut *(ptr as *mut LocalInfileState<'_>) };
+    if state.panic.is_some() || state.error.is_some() {
+        return -1;
+    }
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf as *mut u8, buf_len as usize) };
+    // the number of read bytes is returned as `c_int`, so a single
+    // read must not fill more than `c_int::MAX` bytes
+    let max_len = usize::try_from(libc::c_int::MAX).unwrap_or(usize::MAX);
+    let len = buf.len().min(max_len);
+    let buf = &mut buf[..len];
+    // unwinding through the C library is undefined behaviour, so
+    // panics are caught here and resumed after the query returned
+    match std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| state.source.read(buf))) {
+        Ok(Ok(len)) => libc::c_int::try_from(len).unwrap_or(libc::c_int::MAX),
+        Ok(Err(e)) => {
+            state.error = Some(e);
+            -1
+        }
+        Err(panic) => {
+            state.panic = Some(panic);
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn local_infile_end(_ptr: *mut libc::c_void) {}
+
+unsafe extern "C" fn local_infile_error(
+    ptr: *mut libc::c_void,
+    buf: *mut libc::c_char,
+    buf_len: libc::c_uint,
+) -> libc::c_int {
+    let state = unsafe { &*(ptr as *const LocalInfileState<'_>) };
+    let message = match (&state.error, &state.panic) {
+        (Some(e), _) => e.to_string(),
+        (None, Some(_)) => String::from("Reading the data for `LOAD DATA` panicked"),
+        (None, None) => String::from("Failed to read the data for `LOAD DATA`"),
+    };
+    if buf_len > 0 {
+        let len = usize::min(message.len(), buf_len as usize - 1);
+        unsafe {
+            ptr::copy_nonoverlapping(message.as_ptr(), buf as *mut u8, len);
+            *buf.add(len) = 0;
+        }
+    }
+    LOCAL_INFILE_READ_ERROR
+}
+
 /// > In a non-multi-threaded environment, `mysql_init()` invokes
 /// > `mysql_library_init()` automatically as necessary. However,
 /// > `mysql_library_init()` is not thread-safe in a multi-threaded environment,
@@ -40,6 +40,28 @@ impl<'a, DB: MysqlLikeBackend> StatementIterator<'a, DB> {
     }
 }
 
+/// Copies out all rows of the current result set of `stmt`
+///
+/// This is used for statements returning several result sets, where
+/// each result set needs to be consumed before advancing to the next one.
+pub(in crate::mysql_like::connection) fn collect_result_set<DB: MysqlLikeBackend>(
+    stmt: &StatementUse<'_, DB>,
+) -> QueryResult<Vec<MysqlRow<DB>>> {
+    let metadata = Rc::new(stmt.metadata()?);
+    let mut output_binds = OutputBinds::from_output_types(&[], &metadata)
+        .map_err(crate::result::Error::DeserializationError)?;
+
+    let mut rows = Vec::new();
+    while stmt.populate_row_buffers(&mut output_binds)?.is_some() {
+        rows.push(MysqlRow {
+            row: Rc::new(RefCell::new(PrivateMysqlRow::Copied(output_binds.clone()))),
+            metadata: metadata.clone(),
+            _phantom: PhantomData,
+        });
+    }
+    Ok(rows)
+}
+
 impl<DB: MysqlLikeBackend> Iterator for StatementIterator<'_, DB> {
     type Item = QueryResult<MysqlRow<DB>>;
 
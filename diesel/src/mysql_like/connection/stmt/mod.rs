@@ -182,6 +182,50 @@ impl<DB: MysqlLikeBackend> StatementUse<'_, DB> {
         usize::try_from(size).map_err(|e| Error::DeserializationError(Box::new(e)))
     }
 
+    /// The number of columns of the current result set, zero if the current
+    /// result only carries the status of a `CALL` statement
+    pub(in crate::mysql_like::connection) fn field_count(&self) -> u32 {
+        unsafe { ffi::mysql_stmt_field_count(self.inner.stmt.as_ptr()) }
+    }
+
+    /// The metadata of the current result set
+    ///
+    /// Unlike [`Statement::metadata`] this is also valid for the result sets
+    /// of a `CALL` statement, which are only known after executing it.
+    pub(super) fn metadata(&self) -> QueryResult<StatementMetadata> {
+        self.inner.metadata()
+    }
+
+    /// Advances to the next result set of a statement returning several
+    /// result sets, like `CALL` does
+    ///
+    /// Returns `false` if there are no more results.
+    pub(in crate::mysql_like::connection) fn next_result(&mut self) -> QueryResult<bool> {
+        unsafe {
+            ffi::mysql_stmt_free_result(self.inner.stmt.as_ptr());
+        }
+        let status = unsafe { ffi::mysql_stmt_next_result(self.inner.stmt.as_ptr()) };
+        match status {
+            0 => {
+                unsafe {
+                    ffi::mysql_stmt_store_result(self.inner.stmt.as_ptr());
+                }
+                self.inner.did_an_error_occur()?;
+                Ok(true)
+            }
+            -1 => Ok(false),
+            // a positive status always indicates an error, even if the client
+            // library did not record an error message for it
+            _error => {
+                self.inner.did_an_error_occur()?;
+                Err(Error::DatabaseError(
+                    DatabaseErrorKind::Unknown,
+                    Box::new(String::from("Failed to advance to the next result set")),
+                ))
+            }
+        }
+    }
+
     pub(super) fn populate_row_buffers(&self, binds: &mut OutputBinds) -> QueryResult<Option<()>> {
         // We're about to call `mysql_stmt_fetch` we need to check if our binds are still valid
         if binds.are_invalid() {
@@ -7,6 +7,7 @@ use alloc::ffi::CString;
 use core::ffi::CStr;
 use std::collections::HashMap;
 
+use super::connect_options::MysqlConnectOptions;
 use crate::mysql_like::MysqlLikeBackend;
 use crate::result::{ConnectionError, ConnectionResult};
 
@@ -56,6 +57,7 @@ pub(super) struct ConnectionOptions {
     ssl_cert: Option<CString>,
     ssl_key: Option<CString>,
     local_infile: Option<bool>,
+    connect_options: MysqlConnectOptions,
 }
 
 impl ConnectionOptions {
@@ -127,6 +129,8 @@ impl ConnectionOptions {
             None => None,
         };
 
+        let connect_options = MysqlConnectOptions::from_query_pairs(&query_pairs)?;
+
         let host = match url.host() {
             Some(Host::Ipv6(host)) => Some(CString::new(host.to_string())?),
             Some(host) if host.to_string() == "localhost" && unix_socket.is_some() => None,
@@ -160,9 +164,18 @@ impl ConnectionOptions {
             ssl_cert,
             ssl_key,
             local_infile,
+            connect_options,
         })
     }
 
+    /// Overrides the options given in the URL with `options`
+    pub(super) fn merge_connect_options(
+        &mut self,
+        options: &MysqlConnectOptions,
+    ) -> ConnectionResult<()> {
+        self.connect_options.merge(options)
+    }
+
     pub(super) fn host(&self) -> Option<&CStr> {
         self.host.as_deref()
     }
@@ -210,6 +223,10 @@ impl ConnectionOptions {
     pub(super) fn local_infile(&self) -> Option<bool> {
         self.local_infile
     }
+
+    pub(super) fn connect_options(&self) -> &MysqlConnectOptions {
+        &self.connect_options
+    }
 }
 
 fn decode_into_cstring(s: &str) -> ConnectionResult<CString> {
@@ -488,4 +505,71 @@ mod tests {
         let url_str = format!("{SCHEME}://localhost?local_infile=1");
         url(&url_str).unwrap_err();
     }
+
+    #[diesel_test_helper::test]
+    fn timeouts() {
+        let url_str =
+            format!("{SCHEME}://localhost?connect_timeout=5&read_timeout=30&write_timeout=10");
+        let conn_opts = ConnectionOptions::parse::<DB>(&url_str).unwrap();
+        let connect_options = conn_opts.connect_options();
+        assert_eq!(connect_options.connect_timeout_secs(), Some(5));
+        assert_eq!(connect_options.read_timeout_secs(), Some(30));
+        assert_eq!(connect_options.write_timeout_secs(), Some(10));
+
+        let conn_opts = ConnectionOptions::parse::<DB>(&format!("{SCHEME}://localhost")).unwrap();
+        assert_eq!(conn_opts.connect_options().connect_timeout_secs(), None);
+
+        let url_str = format!("{SCHEME}://localhost?connect_timeout=-1");
+        assert!(ConnectionOptions::parse::<DB>(&url_str).is_err());
+    }
+
+    #[diesel_test_helper::test]
+    fn compression_and_init_command() {
+        let url_str = format!("{SCHEME}://localhost?compress=true&init_command=SET%20@a%20%3D%201");
+        let conn_opts = ConnectionOptions::parse::<DB>(&url_str).unwrap();
+        assert!(conn_opts.connect_options().compression());
+        assert_eq!(conn_opts.connect_options().init_commands(), ["SET @a = 1"]);
+
+        let url_str = format!("{SCHEME}://localhost?compress=yes");
+        assert!(ConnectionOptions::parse::<DB>(&url_str).is_err());
+    }
+
+    #[diesel_test_helper::test]
+    fn charset_and_collation() {
+        let url_str = format!("{SCHEME}://localhost?charset=utf8mb4&collation=utf8mb4_bin");
+        let conn_opts = ConnectionOptions::parse::<DB>(&url_str).unwrap();
+        assert_eq!(conn_opts.connect_options().charset_name(), "utf8mb4");
+        assert_eq!(
+            conn_opts.connect_options().collation_name(),
+            Some("utf8mb4_bin")
+        );
+
+        let url_str = format!("{SCHEME}://localhost?charset=latin1");
+        assert!(ConnectionOptions::parse::<DB>(&url_str).is_err());
+        let url_str = format!("{SCHEME}://localhost?collation=utf8mb4_bin';DROP");
+        assert!(ConnectionOptions::parse::<DB>(&url_str).is_err());
+    }
+
+    #[diesel_test_helper::test]
+    fn explicit_options_override_url_options() {
+        let url_str =
+            format!("{SCHEME}://localhost?connect_timeout=5&init_command=SET%20@a%20%3D%201");
+        let mut conn_opts = ConnectionOptions::parse::<DB>(&url_str).unwrap();
+        conn_opts
+            .merge_connect_options(
+                &MysqlConnectOptions::new()
+                    .connect_timeout(2)
+                    .init_command("SET @b = 2"),
+            )
+            .unwrap();
+        let connect_options = conn_opts.connect_options();
+        assert_eq!(connect_options.connect_timeout_secs(), Some(2));
+        assert_eq!(
+            connect_options.init_commands(),
+            ["SET @a = 1", "SET @b = 2"]
+        );
+
+        let err = conn_opts.merge_connect_options(&MysqlConnectOptions::new().charset("latin1"));
+        assert!(err.is_err());
+    }
 }
@@ -0,0 +1,162 @@
+//! MySQL and MariaDB specific expression methods.
+
+// also used to restrict table functions to JSON arguments
+pub(crate) use self::private::JsonOrNullableJson;
+pub(in crate::mysql_like) use self::private::{
+    MaybeNullableValue, SetOrNullableSet, SpatialOrNullableSpatial, TextOrNullableText,
+};
+use super::helper_types;
+use crate::expression::{AsExpression, Expression};
+use crate::sql_types::{SingleValue, Text};
+
+/// MySQL and MariaDB specific methods present on JSON expressions.
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+pub trait MysqlJsonExpressionMethods: Expression + Sized {
+    /// Extracts the value at the given JSON path from a JSON document.
+    ///
+    /// This is equivalent to the MySQL `->` operator. It is rendered as
+    /// `JSON_EXTRACT(doc, path)` as the operator is neither supported
+    /// by MariaDB nor by MySQL for expressions other than columns.
+    /// The result is `NULL` if the path does not exist in the document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = sql::<Json>(r#"'{"a": {"b": [1, 2, 3]}}'"#);
+    /// let result = diesel::select(document.retrieve_as_object_mysql("$.a.b[1]"))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(Some(json!(2)), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn retrieve_as_object_mysql<P>(self, path: P) -> helper_types::json_extract<Self, P>
+    where
+        Self::SqlType: JsonOrNullableJson + SingleValue,
+        P: AsExpression<Text>,
+    {
+        super::functions::json_extract(self, path)
+    }
+
+    /// Extracts the value at the given JSON path from a JSON document
+    /// and returns it as unquoted text.
+    ///
+    /// This is equivalent to the MySQL `->>` operator. It is rendered as
+    /// `JSON_UNQUOTE(JSON_EXTRACT(doc, path))` as the operator is neither supported
+    /// by MariaDB nor by MySQL for expressions other than columns.
+    /// The result is `NULL` if the path does not exist in the document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::sql_types::Json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = sql::<Json>(r#"'{"name": "Sean", "age": 42}'"#);
+    /// let name = diesel::select(document.retrieve_as_text_mysql("$.name"))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("Sean".to_owned()), name);
+    ///
+    /// let document = sql::<Json>(r#"'{"name": "Sean", "age": 42}'"#);
+    /// let email = diesel::select(document.retrieve_as_text_mysql("$.email"))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(None, email);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn retrieve_as_text_mysql<P>(
+        self,
+        path: P,
+    ) -> helper_types::json_unquote<helper_types::json_extract<Self, P>>
+    where
+        Self::SqlType: JsonOrNullableJson + SingleValue,
+        P: AsExpression<Text>,
+    {
+        super::functions::json_unquote(super::functions::json_extract(self, path))
+    }
+}
+
+#[doc(hidden)]
+impl<T> MysqlJsonExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: JsonOrNullableJson,
+{
+}
+
+pub(in crate::mysql_like) mod private {
+    use crate::mysql_like::sql_types::{Geometry, Linestring, Point, Polygon};
+    use crate::sql_types::{Json, MaybeNullableType, Nullable, SetSqlType, SingleValue, Text};
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither any of `diesel::sql_types::{{
+            Geometry, Point, Linestring, Polygon
+         }}` nor `diesel::sql_types::Nullable<Any of the above>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait SpatialOrNullableSpatial {}
+
+    impl<T> SpatialOrNullableSpatial for Nullable<T> where T: SpatialOrNullableSpatial {}
+    impl SpatialOrNullableSpatial for Geometry {}
+    impl SpatialOrNullableSpatial for Point {}
+    impl SpatialOrNullableSpatial for Linestring {}
+    impl SpatialOrNullableSpatial for Polygon {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither a sql type marked with `#[diesel(set_type)]` \
+                   nor `diesel::sql_types::Nullable<_>` of such a type",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait SetOrNullableSet {}
+
+    impl<T> SetOrNullableSet for T where T: SetSqlType {}
+    impl<T> SetOrNullableSet for Nullable<T> where T: SetSqlType {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Json` nor `diesel::sql_types::Nullable<Json>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait JsonOrNullableJson {}
+    impl JsonOrNullableJson for Json {}
+    impl JsonOrNullableJson for Nullable<Json> {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Text` nor `diesel::sql_types::Nullable<Text>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait TextOrNullableText {}
+    impl TextOrNullableText for Text {}
+    impl TextOrNullableText for Nullable<Text> {}
+
+    pub trait MaybeNullableValue<T>: SingleValue {
+        type Out: SingleValue;
+    }
+
+    impl<T, O> MaybeNullableValue<O> for T
+    where
+        T: SingleValue,
+        T::IsNull: MaybeNullableType<O>,
+        <T::IsNull as MaybeNullableType<O>>::Out: SingleValue,
+    {
+        type Out = <T::IsNull as MaybeNullableType<O>>::Out;
+    }
+}
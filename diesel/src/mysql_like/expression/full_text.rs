@@ -0,0 +1,179 @@
+//! Support for MySQL and MariaDB `FULLTEXT` searches
+
+use crate::expression::{AsExpression, Expression, ValidGrouping};
+use crate::mysql_like::MysqlLikeBackend;
+use crate::query_builder::{AstPass, ColumnList, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{DieselNumericOps, Double, Text};
+
+/// The search modifier of a [`match_against`](super::dsl::match_against()) expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum SearchMode {
+    /// Interpret the query as phrase in natural human language.
+    ///
+    /// This is the default mode of MySQL and MariaDB
+    #[default]
+    NaturalLanguage,
+    /// Interpret the query using the boolean full text search operators like `+`, `-` and `*`
+    Boolean,
+    /// Perform a natural language search and repeat it with the most relevant
+    /// words of the first result added to the query
+    QueryExpansion,
+}
+
+impl SearchMode {
+    fn modifier(self) -> &'static str {
+        match self {
+            SearchMode::NaturalLanguage => " IN NATURAL LANGUAGE MODE",
+            SearchMode::Boolean => " IN BOOLEAN MODE",
+            SearchMode::QueryExpansion => " WITH QUERY EXPANSION",
+        }
+    }
+}
+
+/// Creates a MySQL or MariaDB `MATCH (columns) AGAINST (query)` expression
+///
+/// `columns` is a single column or a tuple of columns of the same table,
+/// which must exactly match the columns of a `FULLTEXT` index.
+/// The expression returns the relevance score of a row for the given query.
+/// Rows which do not match the query have a score of `0`, so
+/// `match_against(…).gt(0.0)` filters the matching rows.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// table! {
+///     articles {
+///         id -> Integer,
+///         title -> Text,
+///         body -> Text,
+///     }
+/// }
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::dsl::match_against;
+/// #     use diesel::mysql_like::SearchMode;
+/// #     use self::articles::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query("CREATE TEMPORARY TABLE articles (
+/// #         id INTEGER PRIMARY KEY AUTO_INCREMENT,
+/// #         title TEXT NOT NULL,
+/// #         body TEXT NOT NULL,
+/// #         FULLTEXT (title, body)
+/// #     ) ENGINE = MyISAM").execute(connection)?;
+/// #     diesel::insert_into(articles)
+/// #         .values(&vec![
+/// #             (title.eq("About Rust"), body.eq("Rust is a systems programming language")),
+/// #             (title.eq("About Diesel"), body.eq("Diesel is a query builder written in Rust")),
+/// #             (title.eq("About SQL"), body.eq("SQL is a query language")),
+/// #         ])
+/// #         .execute(connection)?;
+/// let relevance = match_against((title, body), "+rust -diesel", SearchMode::Boolean);
+/// let matching_titles = articles
+///     .select(title)
+///     .filter(relevance.gt(0.0))
+///     .order_by(relevance.desc())
+///     .load::<String>(connection)?;
+/// assert_eq!(vec!["About Rust"], matching_titles);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn match_against<C, Q>(columns: C, query: Q, mode: SearchMode) -> MatchAgainst<C, Q::Expression>
+where
+    C: ColumnList + Expression,
+    Q: AsExpression<Text>,
+{
+    MatchAgainst {
+        columns,
+        query: query.as_expression(),
+        mode,
+    }
+}
+
+/// The expression returned by [`match_against`](super::dsl::match_against())
+#[derive(Debug, Clone, Copy, DieselNumericOps, ValidGrouping)]
+pub struct MatchAgainst<C, Q> {
+    columns: C,
+    query: Q,
+    mode: SearchMode,
+}
+
+impl<C, Q> Expression for MatchAgainst<C, Q>
+where
+    C: ColumnList + Expression,
+    Q: Expression<SqlType = Text>,
+{
+    type SqlType = Double;
+}
+
+impl_selectable_expression!(MatchAgainst<C, Q>);
+
+// the generated SQL depends on the search mode,
+// which is only known at runtime
+impl<C, Q> QueryId for MatchAgainst<C, Q> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<C, Q, DB> QueryFragment<DB> for MatchAgainst<C, Q>
+where
+    DB: MysqlLikeBackend,
+    C: QueryFragment<DB>,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("MATCH (");
+        self.columns.walk_ast(out.reborrow())?;
+        out.push_sql(") AGAINST (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(self.mode.modifier());
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod tests {
+    use super::*;
+    use crate::mysql::Mysql;
+    use crate::prelude::*;
+
+    table! {
+        articles {
+            id -> Integer,
+            title -> Text,
+            body -> Text,
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn match_against_renders_all_search_modes() {
+        let expected = [
+            (SearchMode::NaturalLanguage, "IN NATURAL LANGUAGE MODE"),
+            (SearchMode::Boolean, "IN BOOLEAN MODE"),
+            (SearchMode::QueryExpansion, "WITH QUERY EXPANSION"),
+        ];
+        for (mode, modifier) in expected {
+            let query = articles::table
+                .select(articles::id)
+                .filter(match_against((articles::title, articles::body), "rust", mode).gt(0.0));
+            let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+            assert_eq!(
+                sql,
+                format!(
+                    "SELECT `articles`.`id` FROM `articles` WHERE \
+                     (MATCH (`articles`.`title`, `articles`.`body`) AGAINST (? {modifier}) > ?) \
+                     -- binds: [\"rust\", 0.0]"
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,979 @@
+//! MySQL and MariaDB specific functions
+use crate::expression::functions::declare_sql_function;
+use crate::mysql_like::expression::expression_methods::JsonOrNullableJson;
+use crate::mysql_like::expression::expression_methods::MaybeNullableValue;
+use crate::mysql_like::expression::expression_methods::SetOrNullableSet;
+use crate::mysql_like::expression::expression_methods::SpatialOrNullableSpatial;
+use crate::mysql_like::expression::expression_methods::TextOrNullableText;
+use crate::mysql_like::sql_types::*;
+use crate::sql_types::helper::CombinedNullableValue;
+use crate::sql_types::*;
+
+#[declare_sql_function(generate_return_type_helpers = true)]
+#[backend_bounds(crate::mysql_like::MysqlLikeBackend)]
+extern "SQL" {
+    /// Constructs a geometry value from its
+    /// [Well-Known Text](https://dev.mysql.com/doc/refman/8.4/en/gis-data-formats.html#gis-wkt-format)
+    /// representation
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::st_geom_from_text;
+    /// #     use diesel::mysql_like::data_types::{MysqlGeometry, MysqlPoint};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let point = diesel::select(st_geom_from_text("POINT(1 2)"))
+    ///     .get_result::<MysqlGeometry>(connection)?;
+    /// assert_eq!(MysqlGeometry::Point(MysqlPoint { x: 1.0, y: 2.0 }), point);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_GeomFromText"]
+    fn st_geom_from_text(wkt: Text) -> Geometry;
+
+    /// Returns the
+    /// [Well-Known Text](https://dev.mysql.com/doc/refman/8.4/en/gis-data-formats.html#gis-wkt-format)
+    /// representation of a geometry value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_as_text, st_geom_from_text};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let text = diesel::select(st_as_text(st_geom_from_text("LINESTRING(0 0,1 1)")))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("LINESTRING(0 0,1 1)", text);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_AsText"]
+    fn st_as_text<G: SpatialOrNullableSpatial + MaybeNullableValue<Text>>(geometry: G) -> G::Out;
+
+    /// Returns whether `g1` completely contains `g2`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_contains, st_geom_from_text};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let square = "POLYGON((0 0,10 0,10 10,0 10,0 0))";
+    /// let inside = diesel::select(st_contains(
+    ///     st_geom_from_text(square),
+    ///     st_geom_from_text("POINT(5 5)"),
+    /// ))
+    /// .get_result::<bool>(connection)?;
+    /// assert!(inside);
+    ///
+    /// let outside = diesel::select(st_contains(
+    ///     st_geom_from_text(square),
+    ///     st_geom_from_text("POINT(15 5)"),
+    /// ))
+    /// .get_result::<bool>(connection)?;
+    /// assert!(!outside);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_Contains"]
+    fn st_contains<
+        G1: SpatialOrNullableSpatial + SingleValue,
+        G2: SpatialOrNullableSpatial + SingleValue + CombinedNullableValue<G1, Bool>,
+    >(
+        g1: G1,
+        g2: G2,
+    ) -> G2::Out;
+
+    /// Returns whether `g1` is completely within `g2`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_geom_from_text, st_within};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let within = diesel::select(st_within(
+    ///     st_geom_from_text("POINT(5 5)"),
+    ///     st_geom_from_text("POLYGON((0 0,10 0,10 10,0 10,0 0))"),
+    /// ))
+    /// .get_result::<bool>(connection)?;
+    /// assert!(within);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_Within"]
+    fn st_within<
+        G1: SpatialOrNullableSpatial + SingleValue,
+        G2: SpatialOrNullableSpatial + SingleValue + CombinedNullableValue<G1, Bool>,
+    >(
+        g1: G1,
+        g2: G2,
+    ) -> G2::Out;
+
+    /// Returns whether `g1` and `g2` spatially intersect
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_geom_from_text, st_intersects};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let intersects = diesel::select(st_intersects(
+    ///     st_geom_from_text("LINESTRING(0 0,10 10)"),
+    ///     st_geom_from_text("LINESTRING(0 10,10 0)"),
+    /// ))
+    /// .get_result::<bool>(connection)?;
+    /// assert!(intersects);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_Intersects"]
+    fn st_intersects<
+        G1: SpatialOrNullableSpatial + SingleValue,
+        G2: SpatialOrNullableSpatial + SingleValue + CombinedNullableValue<G1, Bool>,
+    >(
+        g1: G1,
+        g2: G2,
+    ) -> G2::Out;
+
+    /// Returns the distance between `g1` and `g2`
+    /// measured in the units of their spatial reference system
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_distance, st_geom_from_text};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let distance = diesel::select(st_distance(
+    ///     st_geom_from_text("POINT(0 0)"),
+    ///     st_geom_from_text("POINT(3 4)"),
+    /// ))
+    /// .get_result::<f64>(connection)?;
+    /// assert_eq!(5.0, distance);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_Distance"]
+    fn st_distance<
+        G1: SpatialOrNullableSpatial + SingleValue,
+        G2: SpatialOrNullableSpatial + SingleValue + CombinedNullableValue<G1, Double>,
+    >(
+        g1: G1,
+        g2: G2,
+    ) -> G2::Out;
+
+    /// Returns the minimum spherical distance in meters between two points
+    /// on a sphere with the mean radius of the earth
+    ///
+    /// The x coordinate of each point is interpreted as longitude,
+    /// the y coordinate as latitude.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_distance_sphere, st_geom_from_text};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let distance = diesel::select(st_distance_sphere(
+    ///     st_geom_from_text("POINT(0 0)"),
+    ///     st_geom_from_text("POINT(0 1)"),
+    /// ))
+    /// .get_result::<f64>(connection)?;
+    /// assert_eq!(111195, distance.round() as i64);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_Distance_Sphere"]
+    fn st_distance_sphere<
+        G1: SpatialOrNullableSpatial + SingleValue,
+        G2: SpatialOrNullableSpatial + SingleValue + CombinedNullableValue<G1, Double>,
+    >(
+        g1: G1,
+        g2: G2,
+    ) -> G2::Out;
+
+    /// Returns the x coordinate of a point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_geom_from_text, st_x};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let x = diesel::select(st_x(st_geom_from_text("POINT(1.5 2.5)")))
+    ///     .get_result::<f64>(connection)?;
+    /// assert_eq!(1.5, x);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_X"]
+    fn st_x<P: SpatialOrNullableSpatial + MaybeNullableValue<Double>>(point: P) -> P::Out;
+
+    /// Returns the y coordinate of a point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_geom_from_text, st_y};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let y = diesel::select(st_y(st_geom_from_text("POINT(1.5 2.5)")))
+    ///     .get_result::<f64>(connection)?;
+    /// assert_eq!(2.5, y);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_Y"]
+    fn st_y<P: SpatialOrNullableSpatial + MaybeNullableValue<Double>>(point: P) -> P::Out;
+
+    /// Returns the identifier of the spatial reference system of a geometry value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{st_geom_from_text, st_srid};
+    /// #     let connection = &mut establish_connection();
+    ///
+    /// let srid = diesel::select(st_srid(st_geom_from_text("POINT(1 1)")))
+    ///     .get_result::<u32>(connection)?;
+    /// assert_eq!(0, srid);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "ST_SRID"]
+    fn st_srid<G: SpatialOrNullableSpatial + MaybeNullableValue<Unsigned<Integer>>>(
+        geometry: G,
+    ) -> G::Out;
+
+    /// Returns the 1-based position of `needle` in the given `SET` value,
+    /// or 0 if `needle` is not a member of the set
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{find_in_set, sql};
+    /// #     use diesel::sql_types::SqlType;
+    /// #     let connection = &mut establish_connection();
+    /// #[derive(SqlType)]
+    /// #[diesel(mysql_type(name = "Set"))]
+    /// #[diesel(mariadb_type(name = "Set"))]
+    /// #[diesel(set_type)]
+    /// struct Permissions;
+    ///
+    /// let permissions = sql::<Permissions>("'read,write'");
+    /// let position = diesel::select(find_in_set("write", permissions))
+    ///     .get_result::<i32>(connection)?;
+    /// assert_eq!(2, position);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "FIND_IN_SET"]
+    fn find_in_set<S: SetOrNullableSet + MaybeNullableValue<Integer>>(
+        needle: Text,
+        set: S,
+    ) -> S::Out;
+
+    /// Extracts the value at the given JSON path from a JSON document
+    ///
+    /// Returns `NULL` if the path does not exist in the document.
+    /// See [`retrieve_as_object_mysql`](crate::expression_methods::MysqlJsonExpressionMethods::retrieve_as_object_mysql)
+    /// for the method form of this function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_extract;
+    /// #     use diesel::sql_types::{Json, Nullable};
+    /// #     use serde_json::{json, Value};
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": 2, "c": [4, 5, {"f": 7}]});
+    /// let result = diesel::select(json_extract::<Json, _, _>(document, "$.c[2]"))
+    ///     .get_result::<Option<Value>>(connection)?;
+    /// assert_eq!(Some(json!({"f": 7})), result);
+    ///
+    /// let document = json!({"a": 2});
+    /// let result = diesel::select(json_extract::<Json, _, _>(document, "$.b"))
+    ///     .get_result::<Option<Value>>(connection)?;
+    /// assert_eq!(None, result);
+    ///
+    /// let result = diesel::select(json_extract::<Nullable<Json>, _, _>(None::<Value>, "$.a"))
+    ///     .get_result::<Option<Value>>(connection)?;
+    /// assert_eq!(None, result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_EXTRACT"]
+    fn json_extract<J: JsonOrNullableJson + SingleValue>(json: J, path: Text) -> Nullable<Json>;
+
+    /// Unquotes a JSON value and returns the result as text
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{json_unquote, sql};
+    /// #     use diesel::sql_types::Json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_unquote(sql::<Json>(r#"'"abc"'"#)))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("abc", result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_UNQUOTE"]
+    fn json_unquote<J: JsonOrNullableJson + MaybeNullableValue<Text>>(json: J) -> J::Out;
+
+    /// Returns whether the `candidate` JSON document is contained in the `target` JSON document
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_contains;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let target = json!({"a": 1, "b": [2, 3]});
+    /// let contained = diesel::select(json_contains::<Json, Json, _, _>(target, json!({"b": [3]})))
+    ///     .get_result::<bool>(connection)?;
+    /// assert!(contained);
+    ///
+    /// let target = json!({"a": 1, "b": [2, 3]});
+    /// let contained = diesel::select(json_contains::<Json, Json, _, _>(target, json!({"a": 2})))
+    ///     .get_result::<bool>(connection)?;
+    /// assert!(!contained);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_CONTAINS"]
+    fn json_contains<
+        T: JsonOrNullableJson + SingleValue,
+        C: JsonOrNullableJson + SingleValue + CombinedNullableValue<T, Bool>,
+    >(
+        target: T,
+        candidate: C,
+    ) -> C::Out;
+
+    /// Returns whether the `candidate` JSON document is contained in the `target` JSON document
+    /// at the given path
+    ///
+    /// Returns `NULL` if the path does not exist in the `target` document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_contains_with_path;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let target = json!({"a": 1, "b": [2, 3]});
+    /// let contained = diesel::select(json_contains_with_path::<Json, Json, _, _, _>(
+    ///     target,
+    ///     json!(2),
+    ///     "$.b",
+    /// ))
+    /// .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(true), contained);
+    ///
+    /// let target = json!({"a": 1, "b": [2, 3]});
+    /// let contained = diesel::select(json_contains_with_path::<Json, Json, _, _, _>(
+    ///     target,
+    ///     json!(2),
+    ///     "$.c",
+    /// ))
+    /// .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(None, contained);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_CONTAINS"]
+    fn json_contains_with_path<
+        T: JsonOrNullableJson + SingleValue,
+        C: JsonOrNullableJson + SingleValue,
+    >(
+        target: T,
+        candidate: C,
+        path: Text,
+    ) -> Nullable<Bool>;
+
+    /// Returns whether the JSON document contains data at the given paths
+    ///
+    /// `one_or_all` must be either `'one'` to check whether at least one
+    /// of the paths exists or `'all'` to check whether all paths exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{json_contains_path_1, json_contains_path_2};
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": 1, "b": 2});
+    /// let result = diesel::select(json_contains_path_1::<Json, _, _, _>(document, "one", "$.a"))
+    ///     .get_result::<bool>(connection)?;
+    /// assert!(result);
+    ///
+    /// let document = json!({"a": 1, "b": 2});
+    /// let result = diesel::select(json_contains_path_2::<Json, _, _, _, _>(
+    ///     document, "all", "$.a", "$.c",
+    /// ))
+    /// .get_result::<bool>(connection)?;
+    /// assert!(!result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_CONTAINS_PATH"]
+    #[variadic(last_arguments = 1, skip_zero_argument_variant = true)]
+    fn json_contains_path<J: JsonOrNullableJson + MaybeNullableValue<Bool>>(
+        json: J,
+        one_or_all: Text,
+        path: Text,
+    ) -> J::Out;
+
+    /// Inserts or updates values in a JSON document and returns the result
+    ///
+    /// Existing values at the given paths are replaced, values for paths
+    /// which do not exist yet are added to the document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{json_set_1, json_set_2};
+    /// #     use diesel::sql_types::{Integer, Json, Text};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": 1});
+    /// let result = diesel::select(json_set_1::<Json, Integer, _, _, _>(document, "$.a", 2))
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 2}), result);
+    ///
+    /// let document = json!({"a": 1});
+    /// let result = diesel::select(json_set_2::<Json, Integer, Text, _, _, _, _, _>(
+    ///     document, "$.a", 2, "$.b", "x",
+    /// ))
+    /// .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 2, "b": "x"}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_SET"]
+    #[variadic(last_arguments = 2, skip_zero_argument_variant = true)]
+    fn json_set<J: JsonOrNullableJson + SingleValue, V: SingleValue>(
+        json: J,
+        path: Text,
+        value: V,
+    ) -> J;
+
+    /// Inserts values into a JSON document and returns the result
+    ///
+    /// Values at paths which already exist in the document are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_insert_2;
+    /// #     use diesel::sql_types::{Integer, Json};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": 1});
+    /// let result = diesel::select(json_insert_2::<Json, Integer, Integer, _, _, _, _, _>(
+    ///     document, "$.a", 2, "$.b", 3,
+    /// ))
+    /// .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 1, "b": 3}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_INSERT"]
+    #[variadic(last_arguments = 2, skip_zero_argument_variant = true)]
+    fn json_insert<J: JsonOrNullableJson + SingleValue, V: SingleValue>(
+        json: J,
+        path: Text,
+        value: V,
+    ) -> J;
+
+    /// Replaces existing values in a JSON document and returns the result
+    ///
+    /// Paths which do not exist in the document are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_replace_2;
+    /// #     use diesel::sql_types::{Integer, Json};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": 1});
+    /// let result = diesel::select(json_replace_2::<Json, Integer, Integer, _, _, _, _, _>(
+    ///     document, "$.a", 2, "$.b", 3,
+    /// ))
+    /// .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 2}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_REPLACE"]
+    #[variadic(last_arguments = 2, skip_zero_argument_variant = true)]
+    fn json_replace<J: JsonOrNullableJson + SingleValue, V: SingleValue>(
+        json: J,
+        path: Text,
+        value: V,
+    ) -> J;
+
+    /// Removes the data at the given paths from a JSON document and returns the result
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_remove_1;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": 1, "b": [2, 3]});
+    /// let result = diesel::select(json_remove_1::<Json, _, _>(document, "$.b[0]"))
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 1, "b": [3]}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_REMOVE"]
+    #[variadic(last_arguments = 1, skip_zero_argument_variant = true)]
+    fn json_remove<J: JsonOrNullableJson + SingleValue>(json: J, path: Text) -> J;
+
+    /// Builds a JSON array from the given values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{json_array_0, json_array_2};
+    /// #     use diesel::sql_types::{Integer, Nullable, Text};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_array_0())
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!([]), result);
+    ///
+    /// let result = diesel::select(json_array_2::<Integer, Nullable<Text>, _, _>(1, None::<String>))
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!([1, null]), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_ARRAY"]
+    #[variadic(1)]
+    fn json_array<V: SingleValue>(value: V) -> Json;
+
+    /// Builds a JSON object from the given key value pairs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_object_2;
+    /// #     use diesel::sql_types::{Integer, Text};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_object_2::<Integer, Text, _, _, _, _>("a", 1, "b", "x"))
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 1, "b": "x"}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_OBJECT"]
+    #[variadic(2)]
+    fn json_object<V: SingleValue>(key: Text, value: V) -> Json;
+
+    /// Returns the length of a JSON document
+    ///
+    /// The length of a scalar is 1, the length of an array is the number of
+    /// elements and the length of an object is the number of members.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_length;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_length::<Json, _>(json!([1, 2, {"a": 3}])))
+    ///     .get_result::<i32>(connection)?;
+    /// assert_eq!(3, result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_LENGTH"]
+    fn json_length<J: JsonOrNullableJson + MaybeNullableValue<Integer>>(json: J) -> J::Out;
+
+    /// Returns the length of the value at the given path of a JSON document
+    ///
+    /// Returns `NULL` if the path does not exist in the document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_length_with_path;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let document = json!({"a": [1, 2]});
+    /// let result = diesel::select(json_length_with_path::<Json, _, _>(document, "$.a"))
+    ///     .get_result::<Option<i32>>(connection)?;
+    /// assert_eq!(Some(2), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_LENGTH"]
+    fn json_length_with_path<J: JsonOrNullableJson + SingleValue>(
+        json: J,
+        path: Text,
+    ) -> Nullable<Integer>;
+
+    /// Returns the keys of the top level object of a JSON document as JSON array
+    ///
+    /// Returns `NULL` if the document is not an object.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_keys;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_keys::<Json, _>(json!({"a": 1, "b": 2})))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(Some(json!(["a", "b"])), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_KEYS"]
+    fn json_keys<J: JsonOrNullableJson + SingleValue>(json: J) -> Nullable<Json>;
+
+    /// Returns the type of a JSON value as text, e.g. `OBJECT`, `ARRAY` or `INTEGER`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_type;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_type::<Json, _>(json!({"a": 1})))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("OBJECT", result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_TYPE"]
+    fn json_type<J: JsonOrNullableJson + MaybeNullableValue<Text>>(json: J) -> J::Out;
+
+    /// Returns whether the given text is a valid JSON document
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_valid;
+    /// #     use diesel::sql_types::Text;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(json_valid::<Text, _>(r#"{"a": 1}"#))
+    ///     .get_result::<bool>(connection)?;
+    /// assert!(result);
+    ///
+    /// let result = diesel::select(json_valid::<Text, _>("{a: 1}"))
+    ///     .get_result::<bool>(connection)?;
+    /// assert!(!result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_VALID"]
+    fn json_valid<T: TextOrNullableText + MaybeNullableValue<Bool>>(text: T) -> T::Out;
+
+    /// Aggregates the given values into a JSON array
+    ///
+    /// Returns `NULL` if there are no rows to aggregate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_arrayagg;
+    /// #     use schema::animals::dsl::*;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = animals
+    ///     .select(json_arrayagg(legs))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(Some(json!([4, 8])), result);
+    ///
+    /// let result = animals
+    ///     .filter(legs.gt(10))
+    ///     .select(json_arrayagg(legs))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(None, result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_ARRAYAGG"]
+    #[aggregate]
+    fn json_arrayagg<E: SingleValue>(elements: E) -> Nullable<Json>;
+
+    /// Aggregates the given key value pairs into a JSON object
+    ///
+    /// Returns `NULL` if there are no rows to aggregate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_objectagg;
+    /// #     use schema::animals::dsl::*;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let result = animals
+    ///     .select(json_objectagg(species, legs))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(Some(json!({"dog": 4, "spider": 8})), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[sql_name = "JSON_OBJECTAGG"]
+    #[aggregate]
+    fn json_objectagg<V: SingleValue>(key: Text, value: V) -> Nullable<Json>;
+}
+
+pub(super) mod return_type_helpers_reexported {
+    #[allow(unused_imports)]
+    #[doc(inline)]
+    pub use super::return_type_helpers::*;
+}
@@ -0,0 +1,9 @@
+use crate::dsl::AsExprOf;
+use crate::sql_types::Text;
+
+/// The return type of [`match_against(columns, query, mode)`](super::dsl::match_against())
+pub type MatchAgainst<Columns, Query> =
+    super::full_text::MatchAgainst<Columns, AsExprOf<Query, Text>>;
+
+#[doc(inline)]
+pub use super::return_type_helpers::*;
@@ -0,0 +1,63 @@
+use alloc::string::String;
+
+use crate::Table;
+use crate::mysql_like::IndexHintKind;
+use crate::query_builder::IndexHints;
+
+/// The `with_index_hint` method
+///
+/// This is only implemented for the MySQL and MariaDB backends.
+/// Index hints tell the optimizer which indexes it should use or ignore
+/// while looking up rows of a table.
+///
+/// Calling this function on a table
+/// (`mytable.with_index_hint(IndexHintKind::Force, ["my_index"])`)
+/// will result in the SQL `mytable FORCE INDEX (my_index)`.
+/// The returned [`IndexHints`] can be used just like any table in diesel
+/// since it implements [Table](crate::Table). Calling `.with_index_hint(…)`
+/// on it again adds another hint for the same table.
+///
+/// Example:
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use schema::users;
+/// # use diesel::mysql_like::{IndexHintDsl, IndexHintKind};
+/// # fn main() {
+/// # let connection = &mut establish_connection();
+/// let names = users::table
+///     .with_index_hint(IndexHintKind::Force, ["PRIMARY"])
+///     .filter(users::id.eq(1))
+///     .select(users::name)
+///     .load::<String>(connection);
+/// assert_eq!(Ok(vec![String::from("Sean")]), names);
+/// # }
+/// ```
+///
+/// It can also be used in joins:
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use schema::{posts, users};
+/// # use diesel::mysql_like::{IndexHintDsl, IndexHintKind};
+/// # fn main() {
+/// # let connection = &mut establish_connection();
+/// # let _ =
+/// users::table
+///     .inner_join(posts::table.with_index_hint(IndexHintKind::Ignore, ["PRIMARY"]))
+///     .select((users::name, posts::title))
+///     .load::<(String, String)>(connection);
+/// # }
+/// ```
+pub trait IndexHintDsl: Table {
+    /// See the trait-level docs.
+    fn with_index_hint<I>(self, kind: IndexHintKind, indexes: I) -> IndexHints<Self>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        IndexHints::new(self).with_index_hint(kind, indexes)
+    }
+}
+
+impl<T: Table> IndexHintDsl for T {}
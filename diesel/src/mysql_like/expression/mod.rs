@@ -0,0 +1,29 @@
+//! MySQL and MariaDB related query builder extensions.
+//!
+//! Everything in this module is re-exported from database agnostic locations.
+//! You should rely on the re-exports rather than this module directly. It is
+//! kept separate purely for documentation purposes.
+
+pub(crate) mod expression_methods;
+pub(crate) mod full_text;
+pub mod functions;
+pub(crate) mod helper_types;
+pub(crate) mod index_hint_dsl;
+
+mod return_type_helpers {
+    #[allow(unused_imports)]
+    #[doc(inline)]
+    pub use super::functions::return_type_helpers_reexported::*;
+}
+
+/// MySQL and MariaDB specific expression DSL methods.
+///
+/// This module will be glob imported by
+/// [`diesel::dsl`](crate::dsl) when compiled with the `feature =
+/// "mysql"` or `feature = "mariadb"` flag.
+pub mod dsl {
+    #[doc(inline)]
+    pub use super::full_text::match_against;
+    #[doc(inline)]
+    pub use super::functions::*;
+}
@@ -2,9 +2,12 @@
 
 #[cfg(any(feature = "mysql", feature = "mariadb"))]
 mod connection;
+pub mod expression;
 mod types;
 
 pub(crate) mod query_builder;
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+mod transaction;
 mod value;
 
 use core::hash::Hash;
@@ -16,7 +19,18 @@ use crate::result::DatabaseErrorKind;
 use crate::sql_types::TypeMetadata;
 
 #[cfg(any(feature = "mysql", feature = "mariadb"))]
-pub use self::connection::MysqlLikeConnection;
+pub use self::connection::{
+    MysqlConnectOptions, MysqlLikeConnection, ProcedureResultSet, ProcedureResults,
+};
+pub use self::expression::full_text::SearchMode;
+pub use self::expression::index_hint_dsl::IndexHintDsl;
+pub use self::query_builder::index_hints::IndexHintKind;
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+pub use self::query_builder::load_data::{
+    ExecuteLoadDataDsl, InsertableWrapper, LoadDataQuery, LoadDataRaw, LoadDataTarget, load_data,
+};
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+pub use self::transaction::TransactionBuilder;
 pub use self::value::{MysqlValue, NumericRepresentation};
 
 /// Data structures for MySQL types which have no corresponding Rust type
@@ -28,14 +42,22 @@ pub mod data_types {
     pub use super::types::date_and_time::MysqlTime;
     #[doc(inline)]
     pub use super::types::date_and_time::MysqlTimestampType;
+    #[doc(inline)]
+    pub use super::types::spatial::{
+        MysqlGeometry, MysqlLineString, MysqlPoint, MysqlPolygon, MysqlWithSrid,
+    };
 }
 
 /// MySQL specific sql types
 pub mod sql_types {
+    #[doc(inline)]
+    pub use super::types::Bit;
     #[doc(inline)]
     pub use super::types::Datetime;
     #[doc(inline)]
     pub use super::types::Unsigned;
+    #[doc(inline)]
+    pub use super::types::spatial::{Geometry, Linestring, Point, Polygon};
 }
 
 /// A trait for backends which implement the MySQL wire protocol. This is implemented for both MySQL and MariaDB,
@@ -0,0 +1,277 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::expression::{Expression, ValidGrouping};
+use crate::mysql_like::MysqlLikeBackend;
+use crate::query_builder::{AsQuery, AstPass, FromClause, QueryFragment, QueryId, SelectStatement};
+use crate::query_source::private::PlainQuerySource;
+use crate::query_source::{QueryRelation, QuerySource, TableNotEqual};
+use crate::result::QueryResult;
+use crate::{JoinTo, SelectableExpression, Table};
+
+/// The kind of an index hint
+///
+/// See [`IndexHintDsl`](crate::mysql_like::IndexHintDsl) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IndexHintKind {
+    /// Only consider the given indexes, `USE INDEX (…)`
+    Use,
+    /// Only consider the given indexes and treat a table scan as very expensive,
+    /// `FORCE INDEX (…)`
+    Force,
+    /// Do not consider the given indexes, `IGNORE INDEX (…)`
+    Ignore,
+}
+
+impl IndexHintKind {
+    fn keyword(self) -> &'static str {
+        match self {
+            IndexHintKind::Use => " USE INDEX (",
+            IndexHintKind::Force => " FORCE INDEX (",
+            IndexHintKind::Ignore => " IGNORE INDEX (",
+        }
+    }
+}
+
+/// Represents a table with index hints
+///
+/// This type is constructed by
+/// [`IndexHintDsl::with_index_hint`](crate::mysql_like::IndexHintDsl::with_index_hint).
+/// The hints are rendered directly after the table name.
+#[derive(Debug, Clone)]
+pub struct IndexHints<S> {
+    source: S,
+    hints: Vec<(IndexHintKind, Vec<String>)>,
+}
+
+impl<S> IndexHints<S> {
+    pub(crate) fn new(source: S) -> Self {
+        IndexHints {
+            source,
+            hints: Vec::new(),
+        }
+    }
+
+    /// Adds another index hint for this table
+    ///
+    /// This allows to combine several hints, e.g.
+    /// `USE INDEX (a) IGNORE INDEX (b)`.
+    pub fn with_index_hint<I>(mut self, kind: IndexHintKind, indexes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.hints
+            .push((kind, indexes.into_iter().map(Into::into).collect()));
+        self
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T1, T2> TableNotEqual<T1> for IndexHints<T2>
+where
+    T1: PlainQuerySource,
+    T2: TableNotEqual<T1>,
+    Self: Table,
+{
+}
+
+#[diagnostic::do_not_recommend]
+impl<T1, T2> TableNotEqual<IndexHints<T1>> for T2
+where
+    T1: QueryRelation,
+    T2: PlainQuerySource + TableNotEqual<T1>,
+    IndexHints<T1>: Table,
+{
+}
+
+// the index names are only known at runtime
+impl<S> QueryId for IndexHints<S>
+where
+    S: QueryId,
+{
+    type QueryId = ();
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<S> QuerySource for IndexHints<S>
+where
+    S: Table + Clone,
+    <S as QuerySource>::DefaultSelection: ValidGrouping<()> + SelectableExpression<IndexHints<S>>,
+{
+    type FromClause = Self;
+    type DefaultSelection = <S as QuerySource>::DefaultSelection;
+
+    fn from_clause(&self) -> Self::FromClause {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        self.source.default_selection()
+    }
+}
+
+impl<S, DB> QueryFragment<DB> for IndexHints<S>
+where
+    DB: MysqlLikeBackend,
+    S: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        self.source.walk_ast(out.reborrow())?;
+        for (kind, indexes) in &self.hints {
+            out.push_sql(kind.keyword());
+            for (idx, index) in indexes.iter().enumerate() {
+                if idx != 0 {
+                    out.push_sql(", ");
+                }
+                out.push_identifier(index)?;
+            }
+            out.push_sql(")");
+        }
+        Ok(())
+    }
+}
+
+impl<S> AsQuery for IndexHints<S>
+where
+    S: Table + Clone,
+    <S as QuerySource>::DefaultSelection: ValidGrouping<()> + SelectableExpression<IndexHints<S>>,
+{
+    type SqlType = <<Self as QuerySource>::DefaultSelection as Expression>::SqlType;
+    type Query = SelectStatement<FromClause<Self>>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<S, T> JoinTo<T> for IndexHints<S>
+where
+    S: JoinTo<T>,
+    T: Table,
+    S: Table,
+{
+    type FromClause = <S as JoinTo<T>>::FromClause;
+    type OnClause = <S as JoinTo<T>>::OnClause;
+
+    fn join_target(rhs: T) -> (Self::FromClause, Self::OnClause) {
+        <S as JoinTo<T>>::join_target(rhs)
+    }
+}
+
+impl<S> Table for IndexHints<S>
+where
+    S: Table + Clone + AsQuery,
+    <S as Table>::PrimaryKey: SelectableExpression<IndexHints<S>>,
+    <S as Table>::AllColumns: SelectableExpression<IndexHints<S>>,
+    <S as QuerySource>::DefaultSelection: ValidGrouping<()> + SelectableExpression<IndexHints<S>>,
+{
+    type PrimaryKey = <S as Table>::PrimaryKey;
+    type AllColumns = <S as Table>::AllColumns;
+
+    fn primary_key(&self) -> Self::PrimaryKey {
+        self.source.primary_key()
+    }
+
+    fn all_columns() -> Self::AllColumns {
+        S::all_columns()
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod tests {
+    use super::*;
+    use crate::mysql::Mysql;
+    use crate::mysql_like::IndexHintDsl;
+    use crate::prelude::*;
+
+    table! {
+        users {
+            id -> Integer,
+            name -> VarChar,
+        }
+    }
+
+    table! {
+        posts {
+            id -> Integer,
+            user_id -> Integer,
+            title -> VarChar,
+        }
+    }
+
+    joinable!(posts -> users (user_id));
+    allow_tables_to_appear_in_same_query!(users, posts);
+
+    #[diesel_test_helper::test]
+    fn index_hints_are_rendered_after_the_table() {
+        let query = users::table
+            .with_index_hint(IndexHintKind::Force, ["users_name_idx"])
+            .with_index_hint(IndexHintKind::Ignore, ["PRIMARY", "other_idx"])
+            .select(users::name);
+        let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT `users`.`name` FROM `users` \
+             FORCE INDEX (`users_name_idx`) IGNORE INDEX (`PRIMARY`, `other_idx`) \
+             -- binds: []"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn index_hints_can_be_combined_with_filter_and_order() {
+        let query = users::table
+            .with_index_hint(IndexHintKind::Use, ["PRIMARY"])
+            .filter(users::id.eq(1))
+            .order(users::name)
+            .limit(1);
+        let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT `users`.`id`, `users`.`name` FROM `users` USE INDEX (`PRIMARY`) \
+             WHERE (`users`.`id` = ?) ORDER BY `users`.`name` LIMIT ? -- binds: [1, 1]"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn index_hints_can_be_used_in_joins() {
+        let query = users::table
+            .with_index_hint(IndexHintKind::Force, ["PRIMARY"])
+            .inner_join(posts::table)
+            .select((users::name, posts::title));
+        let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT `users`.`name`, `posts`.`title` FROM (`users` FORCE INDEX (`PRIMARY`) \
+             INNER JOIN `posts` ON (`posts`.`user_id` = `users`.`id`)) -- binds: []"
+        );
+
+        let query = posts::table
+            .inner_join(users::table.with_index_hint(IndexHintKind::Ignore, ["users_name_idx"]))
+            .filter(users::name.eq("Sean"))
+            .select(posts::title);
+        let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT `posts`.`title` FROM (`posts` INNER JOIN `users` \
+             IGNORE INDEX (`users_name_idx`) ON (`posts`.`user_id` = `users`.`id`)) \
+             WHERE (`users`.`name` = ?) -- binds: [\"Sean\"]"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn index_hints_can_be_boxed() {
+        let query = users::table
+            .with_index_hint(IndexHintKind::Use, ["users_name_idx"])
+            .into_boxed::<Mysql>()
+            .filter(users::name.eq("Sean"))
+            .select(users::id);
+        let sql = crate::debug_query(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT `users`.`id` FROM `users` USE INDEX (`users_name_idx`) \
+             WHERE (`users`.`name` = ?) -- binds: [\"Sean\"]"
+        );
+    }
+}
@@ -0,0 +1,1044 @@
+//! `LOAD DATA LOCAL INFILE` statements for MySQL and MariaDB.
+
+use core::fmt::Write as _;
+use core::marker::PhantomData;
+
+use crate::Insertable;
+use crate::QueryResult;
+use crate::connection::Connection;
+use crate::expression::bound::Bound;
+use crate::insertable::ColumnInsertValue;
+use crate::mysql_like::connection::MysqlLikeConnection;
+use crate::mysql_like::{MysqlLikeBackend, MysqlType, MysqlValue};
+use crate::query_builder::bind_collector::ByteWrapper;
+use crate::query_builder::nodes::StaticQueryFragment;
+use crate::query_builder::{
+    AstPass, BatchInsert, ColumnList, QueryFragment, QueryId, ValuesClause,
+};
+use crate::serialize::{IsNull, Output, ToSql};
+use crate::sql_types::HasSqlType;
+use crate::{Column, Table};
+
+/// A expression that could be used as target for `LOAD DATA` statements
+///
+/// This trait is implemented for any table type and for tuples of columns from the same table
+pub trait LoadDataTarget<DB: MysqlLikeBackend> {
+    /// The table targeted by the statement
+    type Table: Table;
+
+    #[doc(hidden)]
+    fn walk_table(pass: AstPass<'_, '_, DB>) -> QueryResult<()>;
+
+    #[doc(hidden)]
+    fn walk_columns(pass: AstPass<'_, '_, DB>) -> QueryResult<()>;
+}
+
+impl<T, DB> LoadDataTarget<DB> for T
+where
+    DB: MysqlLikeBackend,
+    T: Table + StaticQueryFragment,
+    T::AllColumns: ColumnList,
+    T::Component: QueryFragment<DB>,
+{
+    type Table = Self;
+
+    fn walk_table(mut pass: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        T::STATIC_COMPONENT.walk_ast(pass.reborrow())
+    }
+
+    fn walk_columns(mut pass: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        T::all_columns().walk_ast(pass.reborrow())
+    }
+}
+
+macro_rules! load_data_target_for_columns {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<T, DB, $($ST,)*> LoadDataTarget<DB> for ($($ST,)*)
+            where
+                DB: MysqlLikeBackend,
+                $($ST: Column<Table = T> + Default,)*
+                T: Table + StaticQueryFragment,
+                T::Component: QueryFragment<DB>,
+                Self: ColumnList,
+            {
+                type Table = T;
+
+                fn walk_table(mut pass: AstPass<'_, '_, DB>) -> QueryResult<()> {
+                    T::STATIC_COMPONENT.walk_ast(pass.reborrow())
+                }
+
+                fn walk_columns(mut pass: AstPass<'_, '_, DB>) -> QueryResult<()> {
+                    <Self as ColumnList>::walk_ast(&($($ST::default(),)*), pass.reborrow())
+                }
+            }
+        )*
+    }
+}
+
+crate::for_each_tuple!(load_data_target_for_columns);
+
+#[derive(Debug, Default)]
+struct LoadDataOptions {
+    fields_terminated_by: Option<String>,
+    fields_enclosed_by: Option<char>,
+    fields_escaped_by: Option<char>,
+    lines_terminated_by: Option<String>,
+    ignore_lines: Option<u64>,
+}
+
+impl LoadDataOptions {
+    fn walk_ast<DB: MysqlLikeBackend>(&self, mut pass: AstPass<'_, '_, DB>) {
+        if self.fields_terminated_by.is_some()
+            || self.fields_enclosed_by.is_some()
+            || self.fields_escaped_by.is_some()
+        {
+            pass.push_sql(" FIELDS");
+            if let Some(ref terminated_by) = self.fields_terminated_by {
+                pass.push_sql(" TERMINATED BY ");
+                push_string_literal(pass.reborrow(), terminated_by);
+            }
+            if let Some(enclosed_by) = self.fields_enclosed_by {
+                pass.push_sql(" ENCLOSED BY ");
+                push_string_literal(pass.reborrow(), enclosed_by.encode_utf8(&mut [0; 4]));
+            }
+            if let Some(escaped_by) = self.fields_escaped_by {
+                pass.push_sql(" ESCAPED BY ");
+                push_string_literal(pass.reborrow(), escaped_by.encode_utf8(&mut [0; 4]));
+            }
+        }
+        if let Some(ref terminated_by) = self.lines_terminated_by {
+            pass.push_sql(" LINES TERMINATED BY ");
+            push_string_literal(pass.reborrow(), terminated_by);
+        }
+        if let Some(ignore_lines) = self.ignore_lines {
+            pass.push_sql(&format!(" IGNORE {ignore_lines} LINES"));
+        }
+    }
+}
+
+// we cannot use binds here so we need to make sure
+// quotes and backslashes in the input are handled correctly
+fn push_string_literal<DB: MysqlLikeBackend>(mut pass: AstPass<'_, '_, DB>, value: &str) {
+    pass.push_sql("'");
+    pass.push_sql(&value.replace('\\', "\\\\").replace('\'', "''"));
+    pass.push_sql("'");
+}
+
+#[doc(hidden)]
+pub trait LoadDataExpression<T, DB: MysqlLikeBackend> {
+    type Error: From<crate::result::Error> + core::error::Error;
+
+    fn walk_target<'b>(&'b self, pass: AstPass<'_, 'b, DB>) -> QueryResult<()>;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+/// The data source of a [`LoadDataQuery`] built with [`LoadDataQuery::from_raw`]
+#[derive(Debug)]
+pub struct LoadDataRaw<C, R> {
+    options: LoadDataOptions,
+    reader: R,
+    p: PhantomData<C>,
+}
+
+impl<C, R, DB> LoadDataExpression<C::Table, DB> for LoadDataRaw<C, R>
+where
+    DB: MysqlLikeBackend,
+    C: LoadDataTarget<DB>,
+    R: std::io::Read,
+{
+    type Error = crate::result::Error;
+
+    fn walk_target<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        C::walk_table(pass.reborrow())?;
+        self.options.walk_ast(pass.reborrow());
+        pass.push_sql(" (");
+        C::walk_columns(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.reader
+            .read(buf)
+            .map_err(|e| crate::result::Error::SerializationError(Box::new(e)))
+    }
+}
+
+#[doc(hidden)]
+pub trait LoadDataInsertableHelper<DB: MysqlLikeBackend> {
+    type Target: LoadDataTarget<DB>;
+
+    fn write_row(&self, buffer: &mut Vec<u8>, out: &mut Vec<u8>) -> QueryResult<()>;
+}
+
+macro_rules! impl_load_data_insertable_helper_for_values_clause {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<__T, __DB, $($ST,)* $($T,)* $($TT,)*> LoadDataInsertableHelper<__DB> for ValuesClause<
+                ($(ColumnInsertValue<$ST, Bound<$T, $TT>>,)*),
+            __T>
+                where
+                __DB: MysqlLikeBackend,
+                __T: Table,
+                $($ST: Column<Table = __T>,)*
+                ($($ST,)*): LoadDataTarget<__DB>,
+                $(__DB: HasSqlType<$T>,)*
+                $($TT: ToSql<$T, __DB>,)*
+            {
+                type Target = ($($ST,)*);
+
+                fn write_row(&self, buffer: &mut Vec<u8>, out: &mut Vec<u8>) -> QueryResult<()> {
+                    $(
+                        write_field::<__DB, $T, $TT>(&self.values.$idx.expr.item, buffer, out)?;
+                    )*
+                    // replace the last field separator with the line terminator
+                    out.pop();
+                    out.push(b'\n');
+                    Ok(())
+                }
+            }
+
+            impl<'a, __T, __DB, $($ST,)* $($T,)* $($TT,)*> LoadDataInsertableHelper<__DB> for ValuesClause<
+                ($(ColumnInsertValue<$ST, &'a Bound<$T, $TT>>,)*),
+            __T>
+                where
+                __DB: MysqlLikeBackend,
+                __T: Table,
+                $($ST: Column<Table = __T>,)*
+                ($($ST,)*): LoadDataTarget<__DB>,
+                $(__DB: HasSqlType<$T>,)*
+                $($TT: ToSql<$T, __DB>,)*
+            {
+                type Target = ($($ST,)*);
+
+                fn write_row(&self, buffer: &mut Vec<u8>, out: &mut Vec<u8>) -> QueryResult<()> {
+                    $(
+                        write_field::<__DB, $T, $TT>(&self.values.$idx.expr.item, buffer, out)?;
+                    )*
+                    // replace the last field separator with the line terminator
+                    out.pop();
+                    out.push(b'\n');
+                    Ok(())
+                }
+            }
+        )*
+    }
+}
+
+crate::for_each_tuple!(impl_load_data_insertable_helper_for_values_clause);
+
+/// Serializes `item` with its `ToSql` implementation and writes the textual
+/// representation expected by `LOAD DATA` followed by a tab to `out`
+fn write_field<DB, ST, U>(item: &U, buffer: &mut Vec<u8>, out: &mut Vec<u8>) -> QueryResult<()>
+where
+    DB: MysqlLikeBackend + HasSqlType<ST>,
+    U: ToSql<ST, DB>,
+{
+    buffer.clear();
+    let is_null = ToSql::<ST, DB>::to_sql(item, &mut Output::new(ByteWrapper(buffer), &mut ()))
+        .map_err(crate::result::Error::SerializationError)?;
+    if is_null == IsNull::Yes {
+        out.extend_from_slice(b"\\N");
+    } else {
+        let tpe = <DB as HasSqlType<ST>>::metadata(&mut ());
+        write_text_value(MysqlValue::new_internal(buffer, tpe), out)
+            .map_err(crate::result::Error::SerializationError)?;
+    }
+    out.push(b'\t');
+    Ok(())
+}
+
+/// `ToSql` produces the binary protocol representation of a value, while
+/// `LOAD DATA` expects text
+fn write_text_value(value: MysqlValue<'_>, out: &mut Vec<u8>) -> crate::serialize::Result {
+    fn fixed<const N: usize>(bytes: &[u8]) -> Result<[u8; N], core::array::TryFromSliceError> {
+        bytes.try_into()
+    }
+
+    let bytes = value.as_bytes();
+    let mut text = String::new();
+    match value.value_type() {
+        MysqlType::Tiny => write!(text, "{}", i8::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::UnsignedTiny => write!(text, "{}", u8::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::Short => write!(text, "{}", i16::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::UnsignedShort => write!(text, "{}", u16::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::Long => write!(text, "{}", i32::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::UnsignedLong => write!(text, "{}", u32::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::LongLong => write!(text, "{}", i64::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::UnsignedLongLong => write!(text, "{}", u64::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::Float => write!(text, "{}", f32::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::Double => write!(text, "{}", f64::from_ne_bytes(fixed(bytes)?))?,
+        MysqlType::Date | MysqlType::Time | MysqlType::DateTime | MysqlType::Timestamp => {
+            let time = value.time_value()?;
+            if value.value_type() != MysqlType::Time {
+                write!(text, "{:04}-{:02}-{:02}", time.year, time.month, time.day)?;
+            }
+            if value.value_type() == MysqlType::DateTime
+                || value.value_type() == MysqlType::Timestamp
+            {
+                text.push(' ');
+            }
+            if value.value_type() == MysqlType::Time {
+                // `TIME` values may be negative and span more than one day
+                let sign = if time.neg { "-" } else { "" };
+                let hour = time.day * 24 + time.hour;
+                write!(
+                    text,
+                    "{sign}{hour:02}:{:02}:{:02}.{:06}",
+                    time.minute, time.second, time.second_part
+                )?;
+            } else if value.value_type() != MysqlType::Date {
+                write!(
+                    text,
+                    "{:02}:{:02}:{:02}.{:06}",
+                    time.hour, time.minute, time.second, time.second_part
+                )?;
+            }
+        }
+        _ => {
+            escape_into(bytes, out);
+            return Ok(IsNull::No);
+        }
+    }
+    escape_into(text.as_bytes(), out);
+    Ok(IsNull::No)
+}
+
+fn escape_into(bytes: &[u8], out: &mut Vec<u8>) {
+    for &b in bytes {
+        match b {
+            b'\\' => out.extend_from_slice(b"\\\\"),
+            b'\t' => out.extend_from_slice(b"\\t"),
+            b'\n' => out.extend_from_slice(b"\\n"),
+            b'\0' => out.extend_from_slice(b"\\0"),
+            b => out.push(b),
+        }
+    }
+}
+
+/// The data source of a [`LoadDataQuery`] built with [`LoadDataQuery::from_insertable`]
+#[derive(Debug)]
+pub struct InsertableWrapper<V> {
+    rows: alloc::vec::IntoIter<V>,
+    buffer: Vec<u8>,
+    pending: Vec<u8>,
+    position: usize,
+}
+
+impl<T, V, DB> LoadDataExpression<T, DB> for InsertableWrapper<V>
+where
+    DB: MysqlLikeBackend,
+    V: LoadDataInsertableHelper<DB>,
+{
+    type Error = crate::result::Error;
+
+    fn walk_target<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        V::Target::walk_table(pass.reborrow())?;
+        // the default format, with a character set that does not convert
+        // the serialized values
+        pass.push_sql(" CHARACTER SET binary (");
+        V::Target::walk_columns(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        // rows are serialized one by one as the server asks for more data
+        // so that the serialized data set is never held in memory as a whole
+        while self.position == self.pending.len() {
+            let Some(row) = self.rows.next() else {
+                return Ok(0);
+            };
+            self.pending.clear();
+            self.position = 0;
+            row.write_row(&mut self.buffer, &mut self.pending)?;
+        }
+        let len = usize::min(buf.len(), self.pending.len() - self.position);
+        buf[..len].copy_from_slice(&self.pending[self.position..self.position + len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// Adapts a [`LoadDataExpression`] to the `std::io::Read` interface used to
+/// answer the file request of the server
+pub(crate) struct LoadDataReader<'a, S, T, DB>
+where
+    DB: MysqlLikeBackend,
+    S: LoadDataExpression<T, DB>,
+{
+    source: &'a mut S,
+    pub(crate) error: Option<S::Error>,
+    p: PhantomData<(T, DB)>,
+}
+
+impl<'a, S, T, DB> LoadDataReader<'a, S, T, DB>
+where
+    DB: MysqlLikeBackend,
+    S: LoadDataExpression<T, DB>,
+{
+    pub(crate) fn new(source: &'a mut S) -> Self {
+        Self {
+            source,
+            error: None,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<S, T, DB> std::io::Read for LoadDataReader<'_, S, T, DB>
+where
+    DB: MysqlLikeBackend,
+    S: LoadDataExpression<T, DB>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.source.read(buf).map_err(|e| {
+            let message = e.to_string();
+            self.error = Some(e);
+            std::io::Error::other(message)
+        })
+    }
+}
+
+pub(crate) struct InternalLoadDataQuery<'a, S, T> {
+    source: &'a S,
+    p: PhantomData<T>,
+}
+
+impl<'a, S, T> InternalLoadDataQuery<'a, S, T> {
+    pub(crate) fn new(source: &'a S) -> Self {
+        Self {
+            source,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<S, T> QueryId for InternalLoadDataQuery<'_, S, T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<S, T, DB> QueryFragment<DB> for InternalLoadDataQuery<'_, S, T>
+where
+    DB: MysqlLikeBackend,
+    S: LoadDataExpression<T, DB>,
+{
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.unsafe_to_cache_prepared();
+        self.source.walk_target(pass.reborrow())
+    }
+}
+
+/// The structure returned by [`load_data`]
+///
+/// The [`from_raw`] and the [`from_insertable`] methods allow
+/// to configure the data loaded into the database
+///
+/// The `with_*` methods allow to configure the format of the data
+/// provided via [`from_raw`].
+///
+/// [`from_raw`]: LoadDataQuery::from_raw
+/// [`from_insertable`]: LoadDataQuery::from_insertable
+#[derive(Debug)]
+#[must_use = "`LOAD DATA` statements are only executed when calling `.execute()`."]
+pub struct LoadDataQuery<T, Action> {
+    table: PhantomData<T>,
+    action: Action,
+    replace: bool,
+}
+
+impl<T> LoadDataQuery<T, NotSet>
+where
+    T: Table,
+{
+    /// Load data into the database by directly providing the data in the corresponding format
+    ///
+    /// `target` specifies the column selection that is the target of the `LOAD DATA` statement.
+    /// `reader` provides the data, which is read while the statement executes. The format
+    /// expected from the reader depends on the options provided via the `with_*` methods,
+    /// it defaults to tab separated fields and one line per row.
+    #[allow(clippy::wrong_self_convention)] // the sql struct is named that way
+    pub fn from_raw<C, R>(self, _target: C, reader: R) -> LoadDataQuery<T, LoadDataRaw<C, R>>
+    where
+        R: std::io::Read,
+    {
+        LoadDataQuery {
+            table: PhantomData,
+            action: LoadDataRaw {
+                options: LoadDataOptions::default(),
+                reader,
+                p: PhantomData,
+            },
+            replace: self.replace,
+        }
+    }
+
+    /// Load a set of insertable values into the database.
+    ///
+    /// The `insertable` argument is expected to be a `Vec<I>`, `&[I]` or similar, where `I`
+    /// needs to implement `Insertable<T>`. If you use the [`#[derive(Insertable)]`](derive@crate::prelude::Insertable)
+    /// derive macro make sure to also set the `#[diesel(treat_none_as_default_value = false)]` option
+    /// to disable the default value handling otherwise implemented by `#[derive(Insertable)]`.
+    ///
+    /// The values are serialized row by row while the statement executes. This
+    /// internally configures the correct set of settings and does not allow to
+    /// set other options
+    #[allow(clippy::wrong_self_convention)] // the sql struct is named that way
+    pub fn from_insertable<I, V, QId, const STATIC_QUERY_ID: bool>(
+        self,
+        insertable: I,
+    ) -> LoadDataQuery<T, InsertableWrapper<V>>
+    where
+        I: Insertable<T, Values = BatchInsert<Vec<V>, T, QId, STATIC_QUERY_ID>>,
+    {
+        LoadDataQuery {
+            table: PhantomData,
+            action: InsertableWrapper {
+                rows: insertable.values().values.into_iter(),
+                buffer: Vec::new(),
+                pending: Vec::new(),
+                position: 0,
+            },
+            replace: self.replace,
+        }
+    }
+}
+
+impl<T, A> LoadDataQuery<T, A> {
+    /// Replace existing rows that have the same value for a primary key or
+    /// unique index as a loaded row
+    ///
+    /// By default such loaded rows are skipped.
+    pub fn replace_duplicates(mut self) -> Self {
+        self.replace = true;
+        self
+    }
+}
+
+impl<T, C, R> LoadDataQuery<T, LoadDataRaw<C, R>> {
+    /// The string that separates the fields of a row
+    ///
+    /// See the [MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/load-data.html#load-data-field-line-handling)
+    /// for more details.
+    ///
+    /// Diesel will automatically escape the provided value for the default
+    /// `sql_mode`. If you enabled `NO_BACKSLASH_ESCAPES` you need to take
+    /// care of escaping the value on your own
+    pub fn with_fields_terminated_by(mut self, terminated_by: impl Into<String>) -> Self {
+        self.action.options.fields_terminated_by = Some(terminated_by.into());
+        self
+    }
+
+    /// The character that encloses field values
+    ///
+    /// See the [MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/load-data.html#load-data-field-line-handling)
+    /// for more details.
+    ///
+    /// Diesel will automatically escape the provided value for the default
+    /// `sql_mode`. If you enabled `NO_BACKSLASH_ESCAPES` you need to take
+    /// care of escaping the value on your own
+    pub fn with_fields_enclosed_by(mut self, enclosed_by: char) -> Self {
+        self.action.options.fields_enclosed_by = Some(enclosed_by);
+        self
+    }
+
+    /// The character that escapes special characters in field values
+    ///
+    /// See the [MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/load-data.html#load-data-field-line-handling)
+    /// for more details.
+    ///
+    /// Diesel will automatically escape the provided value for the default
+    /// `sql_mode`. If you enabled `NO_BACKSLASH_ESCAPES` you need to take
+    /// care of escaping the value on your own
+    pub fn with_fields_escaped_by(mut self, escaped_by: char) -> Self {
+        self.action.options.fields_escaped_by = Some(escaped_by);
+        self
+    }
+
+    /// The string that separates the rows
+    ///
+    /// See the [MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/load-data.html#load-data-field-line-handling)
+    /// for more details.
+    ///
+    /// Diesel will automatically escape the provided value for the default
+    /// `sql_mode`. If you enabled `NO_BACKSLASH_ESCAPES` you need to take
+    /// care of escaping the value on your own
+    pub fn with_lines_terminated_by(mut self, terminated_by: impl Into<String>) -> Self {
+        self.action.options.lines_terminated_by = Some(terminated_by.into());
+        self
+    }
+
+    /// The number of lines at the start of the data to skip,
+    /// for example a header
+    pub fn with_ignore_lines(mut self, lines: u64) -> Self {
+        self.action.options.ignore_lines = Some(lines);
+        self
+    }
+}
+
+impl<T, A, DB> LoadDataExpression<T, DB> for LoadDataQuery<T, A>
+where
+    DB: MysqlLikeBackend,
+    A: LoadDataExpression<T, DB>,
+{
+    type Error = A::Error;
+
+    fn walk_target<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        // The file name is never opened, the data is
+        // provided by the local infile handler of the connection
+        pass.push_sql("LOAD DATA LOCAL INFILE 'diesel'");
+        if self.replace {
+            pass.push_sql(" REPLACE");
+        }
+        pass.push_sql(" INTO TABLE ");
+        self.action.walk_target(pass.reborrow())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.action.read(buf)
+    }
+}
+
+/// A custom execute function tailored for `LOAD DATA` statements
+///
+/// This trait can be used to execute `LOAD DATA` queries constructed
+/// via [`load_data`]
+pub trait ExecuteLoadDataDsl<C>
+where
+    C: Connection,
+{
+    /// The error type returned by the execute function
+    type Error: core::error::Error;
+
+    /// See the trait documentation for details
+    fn execute(self, conn: &mut C) -> Result<usize, Self::Error>;
+}
+
+impl<T, A, DB> ExecuteLoadDataDsl<MysqlLikeConnection<DB>> for LoadDataQuery<T, A>
+where
+    DB: MysqlLikeBackend,
+    A: LoadDataExpression<T, DB>,
+{
+    type Error = A::Error;
+
+    fn execute(self, conn: &mut MysqlLikeConnection<DB>) -> Result<usize, A::Error> {
+        conn.load_data::<_, T>(self)
+    }
+}
+
+#[cfg(feature = "r2d2")]
+impl<T, A, C> ExecuteLoadDataDsl<crate::r2d2::PooledConnection<crate::r2d2::ConnectionManager<C>>>
+    for LoadDataQuery<T, A>
+where
+    C: crate::r2d2::R2D2Connection + 'static,
+    Self: ExecuteLoadDataDsl<C>,
+{
+    type Error = <Self as ExecuteLoadDataDsl<C>>::Error;
+
+    fn execute(
+        self,
+        conn: &mut crate::r2d2::PooledConnection<crate::r2d2::ConnectionManager<C>>,
+    ) -> Result<usize, Self::Error> {
+        self.execute(&mut **conn)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NotSet;
+
+/// Creates a `LOAD DATA LOCAL INFILE` statement
+///
+/// This function constructs a `LOAD DATA` statement which loads data
+/// from the client into a table. It's designed to move larger
+/// amounts of data into the database, and is much faster than
+/// inserting the same data with batched `INSERT` statements.
+/// The data is streamed to the server while the statement executes,
+/// no temporary file is involved.
+///
+/// This function accepts a target table as argument.
+///
+/// There are two ways to construct a `LOAD DATA` statement with
+/// diesel:
+///
+/// * By providing a `Vec<I>` where `I` implements `Insertable` for the
+///   given table
+/// * By providing a target selection (column list or table name)
+///   and a [`std::io::Read`] implementation that provides the data
+///
+/// The first variant serializes the provided values with their `ToSql`
+/// implementations and automatically sets the right options.
+/// Use [`LoadDataQuery::from_insertable`] for this.
+///
+/// The second variant allows you to control the format of the loaded
+/// data via the `LoadDataQuery::with_*` methods. It can be setup via
+/// the [`LoadDataQuery::from_raw`] function. See
+/// [the MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/load-data.html)
+/// for more details about the expected formats.
+///
+/// The connection needs to be established with `local_infile=true` as part
+/// of the connection URL, and the server needs to allow loading local data
+/// via the `local_infile` system variable.
+///
+/// # Examples
+///
+/// ## Via [`LoadDataQuery::from_insertable`]
+///
+/// ```rust,no_run
+/// # include!("../../doctest_setup.rs");
+/// # use crate::schema::users;
+/// use diesel::mysql_like::{ExecuteLoadDataDsl, load_data};
+///
+/// #[derive(Insertable)]
+/// #[diesel(table_name = users)]
+/// #[diesel(treat_none_as_default_value = false)]
+/// struct NewUser {
+///     name: &'static str,
+/// }
+///
+/// # fn run_test() -> QueryResult<()> {
+/// # let mut url = database_url_for_env();
+/// # url.push_str(if url.contains('?') { "&local_infile=true" } else { "?local_infile=true" });
+/// # let connection = &mut DbConnection::establish(&url).unwrap();
+/// # create_tables_with_data(connection);
+///
+/// let data = vec![
+///     NewUser {
+///         name: "Diva Plavalaguna",
+///     },
+///     NewUser {
+///         name: "Father Vito Cornelius",
+///     },
+/// ];
+///
+/// let count = load_data(users::table)
+///     .from_insertable(&data)
+///     .execute(connection)?;
+///
+/// assert_eq!(count, 2);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #    run_test().unwrap();
+/// # }
+/// ```
+///
+/// ## Via [`LoadDataQuery::from_raw`]
+///
+/// ```rust,no_run
+/// # include!("../../doctest_setup.rs");
+/// # fn run_test() -> QueryResult<()> {
+/// # use crate::schema::users;
+/// use diesel::mysql_like::{ExecuteLoadDataDsl, load_data};
+/// # let mut url = database_url_for_env();
+/// # url.push_str(if url.contains('?') { "&local_infile=true" } else { "?local_infile=true" });
+/// # let connection = &mut DbConnection::establish(&url).unwrap();
+/// # create_tables_with_data(connection);
+/// let data = "3,Diva Plavalaguna\n4,Father Vito Cornelius\n";
+/// let count = load_data(users::table)
+///     .from_raw(users::table, data.as_bytes())
+///     .with_fields_terminated_by(",")
+///     .execute(connection)?;
+///
+/// assert_eq!(count, 2);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #    run_test().unwrap();
+/// # }
+/// ```
+pub fn load_data<T>(_table: T) -> LoadDataQuery<T, NotSet>
+where
+    T: Table,
+{
+    LoadDataQuery {
+        table: PhantomData,
+        action: NotSet,
+        replace: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate dotenvy;
+
+    use super::*;
+    use crate::backend::Backend;
+    use crate::connection::SimpleConnection;
+    use crate::mysql_like::data_types::{MysqlTime, MysqlTimestampType};
+    use crate::query_builder::QueryBuilder;
+    use crate::sql_types::Time;
+    use crate::{ExpressionMethods, QueryDsl, RunQueryDsl};
+
+    crate::table! {
+        load_data_test {
+            id -> Integer,
+            name -> Text,
+            score -> Nullable<Double>,
+        }
+    }
+
+    fn connection<DB: MysqlLikeBackend>(env_var: &str) -> MysqlLikeConnection<DB> {
+        dotenvy::dotenv().ok();
+        let mut database_url = dotenvy::var(env_var)
+            .or_else(|_| dotenvy::var("DATABASE_URL"))
+            .expect("DATABASE_URL must be set in order to run unit tests");
+        database_url.push_str(if database_url.contains('?') {
+            "&local_infile=true"
+        } else {
+            "?local_infile=true"
+        });
+        let mut conn = MysqlLikeConnection::establish(&database_url).unwrap();
+        conn.batch_execute(
+            "CREATE TEMPORARY TABLE load_data_test(\
+                 id INTEGER PRIMARY KEY AUTO_INCREMENT, \
+                 name TEXT NOT NULL, \
+                 score DOUBLE\
+             ) CHARACTER SET utf8mb4;",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn to_sql<DB, S>(query: &LoadDataQuery<load_data_test::table, S>) -> String
+    where
+        DB: MysqlLikeBackend,
+        S: LoadDataExpression<load_data_test::table, DB>,
+    {
+        let mut query_builder = <DB as Backend>::QueryBuilder::default();
+        InternalLoadDataQuery::<_, load_data_test::table>::new(query)
+            .to_sql(&mut query_builder, &DB::default())
+            .unwrap();
+        query_builder.finish()
+    }
+
+    fn generates_correct_sql<DB: MysqlLikeBackend>() {
+        let query = load_data(load_data_test::table).from_insertable(vec![(
+            load_data_test::name.eq("Sean"),
+            load_data_test::score.eq(Some(1.5)),
+        )]);
+        assert_eq!(
+            to_sql::<DB, _>(&query),
+            "LOAD DATA LOCAL INFILE 'diesel' INTO TABLE `load_data_test` \
+             CHARACTER SET binary (`name`, `score`)"
+        );
+
+        let query = load_data(load_data_test::table)
+            .from_raw((load_data_test::id, load_data_test::name), "".as_bytes())
+            .with_fields_terminated_by(",")
+            .with_fields_enclosed_by('\'')
+            .with_fields_escaped_by('\\')
+            .with_lines_terminated_by("\r\n")
+            .with_ignore_lines(1)
+            .replace_duplicates();
+        assert_eq!(
+            to_sql::<DB, _>(&query),
+            "LOAD DATA LOCAL INFILE 'diesel' REPLACE INTO TABLE `load_data_test` \
+             FIELDS TERMINATED BY ',' ENCLOSED BY '''' ESCAPED BY '\\\\' \
+             LINES TERMINATED BY '\r\n' IGNORE 1 LINES (`id`, `name`)"
+        );
+    }
+
+    // The queries used to check the loaded data require a concrete backend,
+    // so these tests are instantiated once per connection type
+    macro_rules! load_data_connection_tests {
+        ($backend:ty, $env_var:literal) => {
+            // `LOAD DATA LOCAL INFILE` requires the `local_infile` system variable
+            // to be enabled on the test server. The tests using a connection are
+            // skipped otherwise, as changing it requires additional privileges and
+            // affects every other client of the server.
+            fn connection() -> Option<MysqlLikeConnection<$backend>> {
+                let mut conn = super::connection::<$backend>($env_var);
+                let local_infile = crate::select(crate::dsl::sql::<crate::sql_types::BigInt>(
+                    "@@GLOBAL.local_infile",
+                ))
+                .get_result::<i64>(&mut conn)
+                .unwrap();
+                if local_infile == 0 {
+                    eprintln!("Skipping test as `local_infile` is disabled on the database server");
+                    return None;
+                }
+                Some(conn)
+            }
+
+            #[diesel_test_helper::test]
+            fn load_data_from_insertable() {
+                let Some(conn) = &mut connection() else {
+                    return;
+                };
+                let data = vec![
+                    (
+                        load_data_test::name.eq("Sean"),
+                        load_data_test::score.eq(Some(1.5)),
+                    ),
+                    (
+                        load_data_test::name.eq("Tess\twith\nspecial\\characters"),
+                        load_data_test::score.eq(None::<f64>),
+                    ),
+                ];
+
+                let count = load_data(load_data_test::table)
+                    .from_insertable(data)
+                    .execute(conn)
+                    .unwrap();
+                assert_eq!(count, 2);
+
+                let loaded = load_data_test::table
+                    .select((load_data_test::name, load_data_test::score))
+                    .order(load_data_test::id)
+                    .load::<(String, Option<f64>)>(conn)
+                    .unwrap();
+                assert_eq!(
+                    loaded,
+                    vec![
+                        (String::from("Sean"), Some(1.5)),
+                        (String::from("Tess\twith\nspecial\\characters"), None),
+                    ]
+                );
+            }
+
+            #[diesel_test_helper::test]
+            fn load_data_from_raw() {
+                let Some(conn) = &mut connection() else {
+                    return;
+                };
+                let data = "id,name\n1,Sean\n2,Tess\n";
+
+                let count = load_data(load_data_test::table)
+                    .from_raw((load_data_test::id, load_data_test::name), data.as_bytes())
+                    .with_fields_terminated_by(",")
+                    .with_ignore_lines(1)
+                    .execute(conn)
+                    .unwrap();
+                assert_eq!(count, 2);
+
+                load_data(load_data_test::table)
+                    .from_raw(
+                        (load_data_test::id, load_data_test::name),
+                        "2,Jim\n".as_bytes(),
+                    )
+                    .with_fields_terminated_by(",")
+                    .replace_duplicates()
+                    .execute(conn)
+                    .unwrap();
+
+                let loaded = load_data_test::table
+                    .select((load_data_test::id, load_data_test::name))
+                    .order(load_data_test::id)
+                    .load::<(i32, String)>(conn)
+                    .unwrap();
+                assert_eq!(
+                    loaded,
+                    vec![(1, String::from("Sean")), (2, String::from("Jim"))]
+                );
+            }
+
+            #[diesel_test_helper::test]
+            fn load_data_reports_reader_errors() {
+                struct FailingReader;
+
+                impl std::io::Read for FailingReader {
+                    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                        Err(std::io::Error::other("the reader failed"))
+                    }
+                }
+
+                let Some(conn) = &mut connection() else {
+                    return;
+                };
+                let result = load_data(load_data_test::table)
+                    .from_raw(load_data_test::table, FailingReader)
+                    .execute(conn);
+                match result {
+                    Err(crate::result::Error::SerializationError(e)) => {
+                        assert_eq!(e.to_string(), "the reader failed")
+                    }
+                    res => panic!("Unexpected result: {res:?}"),
+                }
+
+                // the connection is still usable afterwards
+                let count = load_data_test::table
+                    .count()
+                    .get_result::<i64>(conn)
+                    .unwrap();
+                assert_eq!(count, 0);
+            }
+        };
+    }
+
+    fn writes_time_values<DB>()
+    where
+        DB: MysqlLikeBackend + HasSqlType<Time>,
+    {
+        let time = |day, hour, neg| {
+            MysqlTime::new(
+                0,
+                0,
+                day,
+                hour,
+                34,
+                56,
+                789,
+                neg,
+                MysqlTimestampType::MYSQL_TIMESTAMP_TIME,
+                0,
+            )
+        };
+        let mut buffer = Vec::new();
+        let mut out = Vec::new();
+        write_field::<DB, Time, _>(&time(0, 12, false), &mut buffer, &mut out).unwrap();
+        write_field::<DB, Time, _>(&time(0, 12, true), &mut buffer, &mut out).unwrap();
+        write_field::<DB, Time, _>(&time(1, 2, true), &mut buffer, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "12:34:56.000789\t-12:34:56.000789\t-26:34:56.000789\t"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    mod mysql {
+        use super::*;
+        use crate::mysql::Mysql;
+
+        #[test]
+        fn load_data_generates_correct_sql() {
+            super::generates_correct_sql::<Mysql>();
+        }
+
+        #[test]
+        fn load_data_writes_time_values() {
+            super::writes_time_values::<Mysql>();
+        }
+
+        load_data_connection_tests!(Mysql, "MYSQL_UNIT_TEST_DATABASE_URL");
+    }
+
+    #[cfg(feature = "mariadb")]
+    mod mariadb {
+        use super::*;
+        use crate::mariadb::Mariadb;
+
+        #[test]
+        fn load_data_generates_correct_sql() {
+            super::generates_correct_sql::<Mariadb>();
+        }
+
+        #[test]
+        fn load_data_writes_time_values() {
+            super::writes_time_values::<Mariadb>();
+        }
+
+        load_data_connection_tests!(Mariadb, "MARIADB_UNIT_TEST_DATABASE_URL");
+    }
+}
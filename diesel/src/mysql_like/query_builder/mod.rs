@@ -8,9 +8,14 @@ use crate::result::QueryResult;
 pub use self::query_fragment_impls::DuplicatedKeys;
 
 mod batch_update;
+pub(crate) mod index_hints;
 #[cfg(any(feature = "mysql", feature = "mariadb"))]
 mod insert_returning_id;
 mod limit_offset;
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+pub(crate) mod load_data;
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+pub(crate) mod procedure_call;
 mod query_fragment_impls;
 
 /// The MySQL-Like query builder
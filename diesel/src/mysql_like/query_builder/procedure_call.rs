@@ -0,0 +1,80 @@
+use crate::mysql_like::MysqlLikeBackend;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+
+/// A `CALL procedure(args)` statement
+///
+/// Constructed by
+/// [`MysqlLikeConnection::call_procedure`](crate::mysql_like::MysqlLikeConnection::call_procedure).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ProcedureCall<'a, A> {
+    name: &'a str,
+    args: A,
+}
+
+impl<'a, A> ProcedureCall<'a, A> {
+    pub(crate) fn new(name: &'a str, args: A) -> Self {
+        ProcedureCall { name, args }
+    }
+}
+
+impl<A> QueryId for ProcedureCall<'_, A> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<A, DB> QueryFragment<DB> for ProcedureCall<'_, A>
+where
+    DB: MysqlLikeBackend,
+    A: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        // the result sets returned by a procedure may change
+        // whenever the procedure is redefined
+        out.unsafe_to_cache_prepared();
+        out.push_sql("CALL ");
+        for (idx, part) in self.name.split('.').enumerate() {
+            if idx != 0 {
+                out.push_sql(".");
+            }
+            out.push_identifier(part)?;
+        }
+        out.push_sql("(");
+        self.args.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod tests {
+    use super::*;
+    use crate::expression::IntoSql;
+    use crate::mysql::Mysql;
+    use crate::sql_types::{Integer, Nullable, Text};
+
+    #[diesel_test_helper::test]
+    fn procedure_calls_bind_their_arguments() {
+        let query = ProcedureCall::new(
+            "app.user_stats",
+            (
+                1.into_sql::<Integer>(),
+                "Sean".into_sql::<Text>(),
+                None::<i32>.into_sql::<Nullable<Integer>>(),
+            ),
+        );
+        let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "CALL `app`.`user_stats`(?, ?, ?) -- binds: [1, \"Sean\", None]"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn procedure_calls_without_arguments() {
+        let query = ProcedureCall::new("cleanup", ());
+        let sql = crate::debug_query::<Mysql, _>(&query).to_string();
+        assert_eq!(sql, "CALL `cleanup`() -- binds: []");
+    }
+}
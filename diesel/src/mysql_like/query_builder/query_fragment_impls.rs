@@ -1,6 +1,7 @@
 use crate::expression::operators::Concat;
 use crate::mysql_like::MysqlLikeBackend;
 use crate::query_builder::insert_statement::DefaultValues;
+use crate::query_builder::limit_offset_clause::BoxedLimitOffsetClause;
 use crate::query_builder::locking_clause::{ForShare, ForUpdate, NoModifier, NoWait, SkipLocked};
 use crate::query_builder::nodes::StaticQueryFragment;
 use crate::query_builder::upsert::into_conflict_clause::OnConflictSelectWrapper;
@@ -8,7 +9,9 @@ use crate::query_builder::upsert::on_conflict_actions::{DoNothing, DoUpdate};
 use crate::query_builder::upsert::on_conflict_clause::OnConflictValues;
 use crate::query_builder::upsert::on_conflict_target::{ConflictTarget, OnConflictTarget};
 use crate::query_builder::where_clause::NoWhereClause;
-use crate::query_builder::{AstPass, QueryFragment};
+use crate::query_builder::{
+    AstPass, BoxedSelectStatement, OptimizerHint, QueryFragment, SelectStatement,
+};
 use crate::result::QueryResult;
 use crate::{Column, Table};
 
@@ -241,3 +244,42 @@ macro_rules! do_nothing_for_composite_keys {
 }
 
 crate::for_each_tuple!(do_nothing_for_composite_keys);
+
+// MySQL and MariaDB only accept optimizer hints directly after the `SELECT` keyword
+#[diagnostic::do_not_recommend]
+impl<F, S, D, W, O, LOf, G, H, LC, DB> QueryFragment<DB>
+    for OptimizerHint<SelectStatement<F, S, D, W, O, LOf, G, H, LC>>
+where
+    DB: MysqlLikeBackend,
+    S: QueryFragment<DB>,
+    F: QueryFragment<DB>,
+    D: QueryFragment<DB>,
+    W: QueryFragment<DB>,
+    O: QueryFragment<DB>,
+    LOf: QueryFragment<DB>,
+    G: QueryFragment<DB>,
+    H: QueryFragment<DB>,
+    LC: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("SELECT ");
+        self.walk_hint(out.reborrow())?;
+        self.query.walk_clauses(out)
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<'a, ST, QS, DB, GB> QueryFragment<DB>
+    for OptimizerHint<BoxedSelectStatement<'a, ST, QS, DB, GB>>
+where
+    DB: MysqlLikeBackend,
+    QS: QueryFragment<DB>,
+    BoxedLimitOffsetClause<'a, DB>: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("SELECT ");
+        self.walk_hint(out.reborrow())?;
+        self.query
+            .walk_clauses(out, |where_clause, out| where_clause.walk_ast(out))
+    }
+}
@@ -0,0 +1,505 @@
+use crate::backend::Backend;
+use crate::connection::{AnsiTransactionManager, Connection, TransactionManager};
+use crate::mysql_like::MysqlLikeBackend;
+use crate::query_builder::{AstPass, QueryBuilder, QueryFragment};
+use crate::result::{Error, QueryResult};
+
+/// Used to build a transaction, specifying additional details.
+///
+/// This struct is returned by [`.build_transaction`].
+/// See the documentation for methods on this struct for usage examples.
+/// See [the MySQL documentation for `START TRANSACTION`][mysql-docs] and
+/// [`SET TRANSACTION`][mysql-set-docs] for details on the behavior of each option.
+///
+/// [`.build_transaction`]: crate::mysql_like::MysqlLikeConnection::build_transaction()
+/// [mysql-docs]: https://dev.mysql.com/doc/refman/8.4/en/commit.html
+/// [mysql-set-docs]: https://dev.mysql.com/doc/refman/8.4/en/set-transaction.html
+#[allow(missing_debug_implementations)] // False positive. Connection isn't Debug.
+#[must_use = "Transaction builder does nothing unless you call `run` on it"]
+pub struct TransactionBuilder<'a, C> {
+    connection: &'a mut C,
+    isolation_level: Option<IsolationLevel>,
+    read_mode: Option<ReadMode>,
+    consistent_snapshot: bool,
+}
+
+impl<'a, C, DB> TransactionBuilder<'a, C>
+where
+    C: Connection<Backend = DB, TransactionManager = AnsiTransactionManager>,
+    DB: MysqlLikeBackend,
+{
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self {
+            connection,
+            isolation_level: None,
+            read_mode: None,
+            consistent_snapshot: false,
+        }
+    }
+
+    /// Makes the transaction `READ ONLY`
+    ///
+    /// Writes to tables other than temporary tables fail with
+    /// [`DatabaseErrorKind::ReadOnlyTransaction`](crate::result::DatabaseErrorKind::ReadOnlyTransaction).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut connection_no_data();
+    /// #     create_tables_with_data(conn);
+    /// conn.build_transaction()
+    ///     .read_only()
+    ///     .run::<_, diesel::result::Error, _>(|conn| {
+    ///         let names = users.select(name).load::<String>(conn)?;
+    ///         assert_eq!(vec!["Sean", "Tess"], names);
+    ///         Ok(())
+    ///     })
+    /// # }
+    /// ```
+    pub fn read_only(mut self) -> Self {
+        self.read_mode = Some(ReadMode::ReadOnly);
+        self
+    }
+
+    /// Makes the transaction `READ WRITE`
+    ///
+    /// This is the default, unless you've changed the
+    /// `transaction_read_only` system variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction().read_write().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn read_write(mut self) -> Self {
+        self.read_mode = Some(ReadMode::ReadWrite);
+        self
+    }
+
+    /// Starts the transaction `WITH CONSISTENT SNAPSHOT`
+    ///
+    /// This takes the snapshot used by all consistent reads of the
+    /// transaction right away, instead of with the first read. It only has an
+    /// effect for storage engines supporting it, like InnoDB, and the
+    /// `REPEATABLE READ` isolation level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .repeatable_read()
+    ///     .with_consistent_snapshot()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn with_consistent_snapshot(mut self) -> Self {
+        self.consistent_snapshot = true;
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL READ UNCOMMITTED`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .read_uncommitted()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn read_uncommitted(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::ReadUncommitted);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL READ COMMITTED`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction().read_committed().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn read_committed(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::ReadCommitted);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL REPEATABLE READ`
+    ///
+    /// This is the default, unless you've changed the
+    /// `transaction_isolation` system variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .repeatable_read()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn repeatable_read(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::RepeatableRead);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL SERIALIZABLE`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction().serializable().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn serializable(mut self) -> Self {
+        self.isolation_level = Some(IsolationLevel::Serializable);
+        self
+    }
+
+    /// Runs the given function inside of the transaction
+    /// with the parameters given to this builder.
+    ///
+    /// This function executes the provided closure `f` inside a database
+    /// transaction. If there is already an open transaction for the current
+    /// connection it will return an error. The connection is committed if
+    /// the closure returns `Ok(_)`, it will be rolled back if it returns `Err(_)`.
+    /// For both cases the original result value will be returned from this function.
+    ///
+    /// Deadlocks and lock wait timeouts are reported as
+    /// [`DatabaseErrorKind::SerializationFailure`] and
+    /// [`DatabaseErrorKind::LockWaitTimeout`], in which case the whole
+    /// transaction can be retried.
+    ///
+    /// [`DatabaseErrorKind::SerializationFailure`]: crate::result::DatabaseErrorKind::SerializationFailure
+    /// [`DatabaseErrorKind::LockWaitTimeout`]: crate::result::DatabaseErrorKind::LockWaitTimeout
+    pub fn run<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut C) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let mut query_builder = <DB as Backend>::QueryBuilder::default();
+        QueryFragment::<DB>::to_sql(&*self, &mut query_builder, &DB::default())?;
+        let sql = query_builder.finish();
+
+        AnsiTransactionManager::begin_transaction_sql(&mut *self.connection, &sql)?;
+        match f(&mut *self.connection) {
+            Ok(value) => {
+                AnsiTransactionManager::commit_transaction(&mut *self.connection)?;
+                Ok(value)
+            }
+            Err(user_error) => {
+                match AnsiTransactionManager::rollback_transaction(&mut *self.connection) {
+                    Ok(()) => Err(user_error),
+                    Err(Error::BrokenTransactionManager) => {
+                        // In this case we are probably more interested by the
+                        // original error, which likely caused this
+                        Err(user_error)
+                    }
+                    Err(rollback_error) => Err(rollback_error.into()),
+                }
+            }
+        }
+    }
+}
+
+impl<C, DB> QueryFragment<DB> for TransactionBuilder<'_, C>
+where
+    DB: MysqlLikeBackend,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        // The isolation level cannot be part of `START TRANSACTION`,
+        // `SET TRANSACTION` applies it to the next transaction only
+        if let Some(ref isolation_level) = self.isolation_level {
+            out.push_sql("SET TRANSACTION");
+            isolation_level.walk_ast(out.reborrow())?;
+            out.push_sql("; ");
+        }
+        out.push_sql("START TRANSACTION");
+        let mut separator = " ";
+        if self.consistent_snapshot {
+            out.push_sql(separator);
+            out.push_sql("WITH CONSISTENT SNAPSHOT");
+            separator = ", ";
+        }
+        if let Some(ref read_mode) = self.read_mode {
+            out.push_sql(separator);
+            read_mode.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl<DB: MysqlLikeBackend> QueryFragment<DB> for IsolationLevel {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql(" ISOLATION LEVEL ");
+        match *self {
+            IsolationLevel::ReadUncommitted => out.push_sql("READ UNCOMMITTED"),
+            IsolationLevel::ReadCommitted => out.push_sql("READ COMMITTED"),
+            IsolationLevel::RepeatableRead => out.push_sql("REPEATABLE READ"),
+            IsolationLevel::Serializable => out.push_sql("SERIALIZABLE"),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ReadMode {
+    ReadOnly,
+    ReadWrite,
+}
+
+impl<DB: MysqlLikeBackend> QueryFragment<DB> for ReadMode {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        match *self {
+            ReadMode::ReadOnly => out.push_sql("READ ONLY"),
+            ReadMode::ReadWrite => out.push_sql("READ WRITE"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate dotenvy;
+
+    use super::*;
+    use crate::connection::SimpleConnection;
+    use crate::mysql_like::MysqlLikeConnection;
+    use crate::query_dsl::RunQueryDsl;
+    use crate::result::DatabaseErrorKind;
+
+    fn connection<DB: MysqlLikeBackend>(env_var: &str) -> MysqlLikeConnection<DB> {
+        dotenvy::dotenv().ok();
+        let database_url = dotenvy::var(env_var)
+            .or_else(|_| dotenvy::var("DATABASE_URL"))
+            .expect("DATABASE_URL must be set in order to run unit tests");
+        MysqlLikeConnection::establish(&database_url).unwrap()
+    }
+
+    fn generates_correct_sql<DB: MysqlLikeBackend>(conn: &mut MysqlLikeConnection<DB>) {
+        macro_rules! assert_sql {
+            ($query:expr, $sql:expr) => {
+                let mut query_builder = <DB as Backend>::QueryBuilder::default();
+                QueryFragment::<DB>::to_sql(&$query, &mut query_builder, &DB::default()).unwrap();
+                let sql = query_builder.finish();
+                assert_eq!(sql, $sql);
+            };
+        }
+
+        assert_sql!(conn.build_transaction(), "START TRANSACTION");
+        assert_sql!(
+            conn.build_transaction().read_only(),
+            "START TRANSACTION READ ONLY"
+        );
+        assert_sql!(
+            conn.build_transaction().read_write(),
+            "START TRANSACTION READ WRITE"
+        );
+        assert_sql!(
+            conn.build_transaction().with_consistent_snapshot(),
+            "START TRANSACTION WITH CONSISTENT SNAPSHOT"
+        );
+        assert_sql!(
+            conn.build_transaction().read_uncommitted(),
+            "SET TRANSACTION ISOLATION LEVEL READ UNCOMMITTED; START TRANSACTION"
+        );
+        assert_sql!(
+            conn.build_transaction().read_committed(),
+            "SET TRANSACTION ISOLATION LEVEL READ COMMITTED; START TRANSACTION"
+        );
+        assert_sql!(
+            conn.build_transaction().repeatable_read(),
+            "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ; START TRANSACTION"
+        );
+        assert_sql!(
+            conn.build_transaction().serializable(),
+            "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; START TRANSACTION"
+        );
+        assert_sql!(
+            conn.build_transaction()
+                .repeatable_read()
+                .read_only()
+                .with_consistent_snapshot(),
+            "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ; \
+             START TRANSACTION WITH CONSISTENT SNAPSHOT, READ ONLY"
+        );
+    }
+
+    fn read_only_transaction_rejects_writes<DB: MysqlLikeBackend>(
+        conn: &mut MysqlLikeConnection<DB>,
+    ) {
+        conn.batch_execute(
+            "DROP TABLE IF EXISTS read_only_transaction_test; \
+             CREATE TABLE read_only_transaction_test(id INTEGER PRIMARY KEY);",
+        )
+        .unwrap();
+
+        let result = conn.build_transaction().read_only().run(|conn| {
+            crate::sql_query("INSERT INTO read_only_transaction_test(id) VALUES (1)").execute(conn)
+        });
+        assert!(matches!(
+            result,
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::ReadOnlyTransaction,
+                _
+            ))
+        ));
+        let depth = <AnsiTransactionManager as TransactionManager<MysqlLikeConnection<DB>>>::transaction_manager_status_mut(conn)
+            .transaction_depth()
+            .unwrap();
+        assert_eq!(depth, None);
+
+        conn.batch_execute("DROP TABLE read_only_transaction_test")
+            .unwrap();
+    }
+
+    fn lock_wait_timeout_is_reported<DB: MysqlLikeBackend>(
+        conn1: &mut MysqlLikeConnection<DB>,
+        conn2: &mut MysqlLikeConnection<DB>,
+    ) {
+        conn1
+            .batch_execute(
+                "DROP TABLE IF EXISTS lock_wait_timeout_test; \
+                 CREATE TABLE lock_wait_timeout_test(id INTEGER PRIMARY KEY, value INTEGER NOT NULL) \
+                 ENGINE = InnoDB; \
+                 INSERT INTO lock_wait_timeout_test(id, value) VALUES (1, 1);",
+            )
+            .unwrap();
+        conn2
+            .batch_execute("SET SESSION innodb_lock_wait_timeout = 1")
+            .unwrap();
+
+        let result = conn1
+            .build_transaction()
+            .run::<_, Error, _>(|conn1| {
+                crate::sql_query("UPDATE lock_wait_timeout_test SET value = 2 WHERE id = 1")
+                    .execute(conn1)?;
+                Ok(conn2.build_transaction().run(|conn2| {
+                    crate::sql_query("UPDATE lock_wait_timeout_test SET value = 3 WHERE id = 1")
+                        .execute(conn2)
+                }))
+            })
+            .unwrap();
+        assert!(matches!(
+            result,
+            Err(Error::DatabaseError(DatabaseErrorKind::LockWaitTimeout, _))
+        ));
+
+        conn1
+            .batch_execute("DROP TABLE lock_wait_timeout_test")
+            .unwrap();
+    }
+
+    #[cfg(feature = "mysql")]
+    mod mysql {
+        use crate::mysql::{Mysql, MysqlConnection};
+
+        fn connection() -> MysqlConnection {
+            super::connection::<Mysql>("MYSQL_UNIT_TEST_DATABASE_URL")
+        }
+
+        #[diesel_test_helper::test]
+        fn transaction_builder_generates_correct_sql() {
+            super::generates_correct_sql(&mut connection());
+        }
+
+        #[diesel_test_helper::test]
+        fn read_only_transaction_rejects_writes() {
+            super::read_only_transaction_rejects_writes(&mut connection());
+        }
+
+        #[diesel_test_helper::test]
+        fn lock_wait_timeout_is_reported() {
+            super::lock_wait_timeout_is_reported(&mut connection(), &mut connection());
+        }
+    }
+
+    #[cfg(feature = "mariadb")]
+    mod mariadb {
+        use crate::mariadb::{Mariadb, MariadbConnection};
+
+        fn connection() -> MariadbConnection {
+            super::connection::<Mariadb>("MARIADB_UNIT_TEST_DATABASE_URL")
+        }
+
+        #[diesel_test_helper::test]
+        fn transaction_builder_generates_correct_sql() {
+            super::generates_correct_sql(&mut connection());
+        }
+
+        #[diesel_test_helper::test]
+        fn read_only_transaction_rejects_writes() {
+            super::read_only_transaction_rejects_writes(&mut connection());
+        }
+
+        #[diesel_test_helper::test]
+        fn lock_wait_timeout_is_reported() {
+            super::lock_wait_timeout_is_reported(&mut connection(), &mut connection());
+        }
+    }
+}
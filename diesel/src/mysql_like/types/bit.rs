@@ -0,0 +1,201 @@
+//! Support for the `BIT(n)` type of MySQL and MariaDB
+//!
+//! The server returns bit values as big endian byte strings with
+//! `ceil(n / 8)` bytes. As libmysqlclient does not accept bit input buffers,
+//! bind parameters of this type are transmitted as unsigned 64 bit integers,
+//! which the server converts to the corresponding bit value.
+
+use byteorder::{NativeEndian, WriteBytesExt};
+
+use crate::deserialize::{self, FromSql, FromSqlRef};
+use crate::mysql_like::{MysqlLikeBackend, MysqlType, MysqlValue};
+use crate::query_builder::QueryId;
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::{BigInt, SqlType};
+
+/// The MySQL `BIT(n)` type, which stores bit fields with up to 64 bits.
+///
+/// This type is exported as `diesel::mysql::sql_types::Bit` and
+/// `diesel::mariadb::sql_types::Bit`. [`diesel::sql_types`] keeps `Bit` as
+/// an alias for `Binary` for existing schemas, so `table!` definitions need
+/// to import this type explicitly. `diesel print-schema` does this for
+/// `BIT(n)` columns.
+///
+/// [`diesel::sql_types`]: crate::sql_types
+/// ### [`ToSql`] impls
+///
+/// - [`u64`]
+/// - [`bool`]
+/// - [`Vec<u8>`] and `[u8]` holding the bits as big endian byte string
+///
+/// ### [`FromSql`] impls
+///
+/// - [`u64`]
+/// - [`bool`], which is `true` if any bit is set
+/// - [`Vec<u8>`] holding the bits as big endian byte string
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(mysql_type(name = "Bit"))]
+#[diesel(mariadb_type(name = "Bit"))]
+pub struct Bit;
+
+fn bit_value<DB: MysqlLikeBackend>(value: MysqlValue<'_>) -> deserialize::Result<u64> {
+    match value.value_type() {
+        MysqlType::Bit | MysqlType::Blob | MysqlType::String => bytes_to_u64(value.as_bytes())
+            .map_err(|_| "Received more than 64 bits for a `BIT` value".into()),
+        // the value is a plain integer if the server did not
+        // return a bit column, e.g. for `SELECT ?`
+        _ => {
+            let signed: i64 = FromSql::<BigInt, DB>::from_sql(value)?;
+            #[allow(clippy::cast_sign_loss)] // that's what we want
+            Ok(signed as u64)
+        }
+    }
+}
+
+fn bytes_to_u64(bytes: &[u8]) -> Result<u64, ()> {
+    let leading_zeros = bytes.iter().take_while(|b| **b == 0).count();
+    let bytes = &bytes[leading_zeros..];
+    if bytes.len() > 8 {
+        return Err(());
+    }
+    Ok(bytes.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+impl<DB: MysqlLikeBackend> FromSql<Bit, DB> for u64 {
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+        bit_value::<DB>(value)
+    }
+}
+
+impl<DB: MysqlLikeBackend> FromSql<Bit, DB> for bool {
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+        bit_value::<DB>(value).map(|v| v != 0)
+    }
+}
+
+impl<'a, DB: MysqlLikeBackend> FromSqlRef<'a, Bit, DB> for &'a [u8] {
+    fn from_sql(value: &'a mut MysqlValue<'_>) -> deserialize::Result<Self> {
+        match value.value_type() {
+            MysqlType::Bit | MysqlType::Blob | MysqlType::String => Ok(value.as_bytes()),
+            tpe => Err(format!(
+                "Cannot read a `BIT` value of type {tpe:?} as bytes, use `u64` instead"
+            )
+            .into()),
+        }
+    }
+}
+
+impl<DB: MysqlLikeBackend> ToSql<Bit, DB> for u64 {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        out.write_u64::<NativeEndian>(*self)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB: MysqlLikeBackend> ToSql<Bit, DB> for bool {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        out.write_u64::<NativeEndian>(u64::from(*self))?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB: MysqlLikeBackend> ToSql<Bit, DB> for [u8] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let value =
+            bytes_to_u64(self).map_err(|_| "A `BIT` value cannot hold more than 64 bits")?;
+        out.write_u64::<NativeEndian>(value)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB: MysqlLikeBackend> ToSql<Bit, DB> for Vec<u8> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <[u8] as ToSql<Bit, DB>>::to_sql(self, out)
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod tests {
+    use super::*;
+    use crate::mysql::Mysql;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    fn to_sql<T: ToSql<Bit, Mysql> + ?Sized>(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<Bit, Mysql>::to_sql(value, &mut bytes).unwrap();
+        buffer
+    }
+
+    #[diesel_test_helper::test]
+    fn bit_values_are_read_as_big_endian_bytes() {
+        let value = MysqlValue::new_internal(&[0x01, 0x02], MysqlType::Bit);
+        let value: u64 = FromSql::<Bit, Mysql>::from_sql(value).unwrap();
+        assert_eq!(value, 0x0102);
+
+        let value = MysqlValue::new_internal(&[0x00], MysqlType::Bit);
+        let value: bool = FromSql::<Bit, Mysql>::from_sql(value).unwrap();
+        assert!(!value);
+
+        let value = MysqlValue::new_internal(&[0x00, 0x80], MysqlType::Bit);
+        let value: Vec<u8> = FromSql::<Bit, Mysql>::from_sql(value).unwrap();
+        assert_eq!(value, vec![0x00, 0x80]);
+
+        let value = MysqlValue::new_internal(&[0xff; 9], MysqlType::Bit);
+        assert!(<u64 as FromSql<Bit, Mysql>>::from_sql(value).is_err());
+    }
+
+    #[diesel_test_helper::test]
+    fn bit_values_are_read_from_integers() {
+        let bytes = 5_i64.to_ne_bytes();
+        let value = MysqlValue::new_internal(&bytes, MysqlType::LongLong);
+        let value: u64 = FromSql::<Bit, Mysql>::from_sql(value).unwrap();
+        assert_eq!(value, 5);
+    }
+
+    #[diesel_test_helper::test]
+    fn bit_values_are_written_as_integers() {
+        assert_eq!(to_sql(&0x0102_u64), 0x0102_u64.to_ne_bytes());
+        assert_eq!(to_sql(&true), 1_u64.to_ne_bytes());
+        assert_eq!(to_sql(&vec![0x01_u8, 0x02]), 0x0102_u64.to_ne_bytes());
+        assert_eq!(to_sql(&[0x00_u8; 12][..]), 0_u64.to_ne_bytes());
+    }
+
+    #[diesel_test_helper::test]
+    fn bit_values_are_understood_by_the_server() {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::select;
+        use crate::test_helpers::connection;
+
+        let connection = &mut connection();
+        crate::sql_query(
+            "CREATE TEMPORARY TABLE bits (id INTEGER PRIMARY KEY, value BIT(12) NOT NULL)",
+        )
+        .execute(connection)
+        .unwrap();
+        crate::sql_query("INSERT INTO bits VALUES (1, ?), (2, ?)")
+            .bind::<Bit, _>(0xabc_u64)
+            .bind::<Bit, _>(vec![0x00_u8, 0x01])
+            .execute(connection)
+            .unwrap();
+
+        let value = select(sql::<Bit>("(SELECT value FROM bits WHERE id = 1)"))
+            .get_result::<u64>(connection)
+            .unwrap();
+        assert_eq!(value, 0xabc);
+
+        let value = select(sql::<Bit>("(SELECT value FROM bits WHERE id = 1)"))
+            .get_result::<Vec<u8>>(connection)
+            .unwrap();
+        assert_eq!(value, vec![0x0a, 0xbc]);
+
+        let value = select(sql::<Bit>("(SELECT value FROM bits WHERE id = 2)"))
+            .get_result::<bool>(connection)
+            .unwrap();
+        assert!(value);
+    }
+}
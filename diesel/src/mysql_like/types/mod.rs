@@ -1,11 +1,14 @@
 //! MySQL and Mariadb shared types
 
+mod bit;
 pub(super) mod date_and_time;
 mod enum_;
 #[cfg(feature = "serde_json")]
 mod json;
 mod numeric;
 mod primitives;
+mod set;
+pub(super) mod spatial;
 
 use crate::deserialize::{self, FromSql};
 use crate::mysql_like::MysqlLikeBackend;
@@ -17,6 +20,8 @@ use crate::sql_types::*;
 use crate::sql_types::{self};
 use byteorder::{NativeEndian, WriteBytesExt};
 
+pub use self::bit::Bit;
+
 impl<DB: MysqlLikeBackend> ToSql<TinyInt, DB> for i8 {
     fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
         out.write_i8(*self).map(|_| IsNull::No).map_err(Into::into)
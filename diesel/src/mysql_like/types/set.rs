@@ -0,0 +1,158 @@
+//! Support for the `SET` type of MySQL and MariaDB
+//!
+//! A set value is transmitted as comma separated list of its members.
+//! Each member is (de)serialized on its own, which allows to
+//! use an enum deriving [`Enum`](crate::types::Enum) for the members
+//! of the set.
+
+use alloc::collections::BTreeSet;
+use std::io::Write;
+
+use crate::deserialize::{self, FromSql};
+use crate::expression::AsExpression;
+use crate::expression::bound::Bound;
+use crate::mysql_like::{MysqlLikeBackend, MysqlType, MysqlValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::{Nullable, SetSqlType};
+
+#[allow(dead_code)]
+mod foreign_impls {
+    use super::*;
+    use crate::deserialize::FromSqlRow;
+
+    #[derive(FromSqlRow)]
+    #[diesel(foreign_derive)]
+    struct BTreeSetProxy<T>(BTreeSet<T>);
+}
+
+impl<T, ST, DB> FromSql<ST, DB> for BTreeSet<T>
+where
+    ST: SetSqlType,
+    DB: MysqlLikeBackend,
+    T: FromSql<ST, DB> + Ord,
+{
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let bytes = value.as_bytes();
+        if bytes.is_empty() {
+            return Ok(BTreeSet::new());
+        }
+        bytes
+            .split(|b| *b == b',')
+            .map(|member| T::from_sql(MysqlValue::new_internal(member, MysqlType::String)))
+            .collect()
+    }
+}
+
+impl<T, ST, DB> ToSql<ST, DB> for BTreeSet<T>
+where
+    ST: SetSqlType,
+    DB: MysqlLikeBackend,
+    T: ToSql<ST, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        for (idx, member) in self.iter().enumerate() {
+            if idx != 0 {
+                out.write_all(b",")?;
+            }
+            member.to_sql(&mut out.reborrow())?;
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl<T, ST> AsExpression<ST> for BTreeSet<T>
+where
+    ST: SetSqlType,
+{
+    type Expression = Bound<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<T, ST> AsExpression<ST> for &BTreeSet<T>
+where
+    ST: SetSqlType,
+{
+    type Expression = Bound<ST, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<T, ST> AsExpression<Nullable<ST>> for BTreeSet<T>
+where
+    ST: SetSqlType,
+{
+    type Expression = Bound<Nullable<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<T, ST> AsExpression<Nullable<ST>> for &BTreeSet<T>
+where
+    ST: SetSqlType,
+{
+    type Expression = Bound<Nullable<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod tests {
+    use super::*;
+    use crate::mysql::Mysql;
+    use crate::query_builder::QueryId;
+    use crate::query_builder::bind_collector::ByteWrapper;
+    use crate::sql_types::{SqlType, Text};
+
+    #[derive(Debug, Clone, Copy, QueryId, SqlType)]
+    #[diesel(mysql_type(name = "Set"))]
+    struct Members;
+
+    impl SetSqlType for Members {}
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Member(String);
+
+    impl FromSql<Members, Mysql> for Member {
+        fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+            <String as FromSql<Text, Mysql>>::from_sql(value).map(Member)
+        }
+    }
+
+    impl ToSql<Members, Mysql> for Member {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+            <str as ToSql<Text, Mysql>>::to_sql(&self.0, out)
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn set_members_are_split_at_commas() {
+        let value = MysqlValue::new_internal(b"b,a", MysqlType::Set);
+        let set: BTreeSet<Member> = FromSql::<Members, Mysql>::from_sql(value).unwrap();
+        assert_eq!(
+            set,
+            BTreeSet::from([Member("a".into()), Member("b".into())])
+        );
+
+        let value = MysqlValue::new_internal(b"", MysqlType::Set);
+        let set: BTreeSet<Member> = FromSql::<Members, Mysql>::from_sql(value).unwrap();
+        assert!(set.is_empty());
+    }
+
+    #[diesel_test_helper::test]
+    fn set_members_are_joined_with_commas() {
+        let set = BTreeSet::from([Member("b".into()), Member("a".into())]);
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<Members, Mysql>::to_sql(&set, &mut bytes).unwrap();
+        assert_eq!(buffer, b"a,b");
+    }
+}
@@ -0,0 +1,632 @@
+//! Support for the spatial types of MySQL and MariaDB
+//!
+//! Spatial values are transmitted in the internal storage format of the
+//! server: a 4 byte little endian SRID followed by the
+//! [WKB](https://dev.mysql.com/doc/refman/8.4/en/gis-data-formats.html#gis-wkb-format)
+//! representation of the geometry.
+
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::io::Write;
+
+use crate::deserialize::{self, FromSql, FromSqlRow};
+use crate::expression::AsExpression;
+use crate::mysql_like::{MysqlLikeBackend, MysqlValue};
+use crate::query_builder::QueryId;
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::SqlType;
+
+/// The MySQL `GEOMETRY` type, which can store a spatial value of any kind.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`MysqlGeometry`]
+/// - [`MysqlPoint`]
+/// - [`MysqlLineString`]
+/// - [`MysqlPolygon`]
+/// - [`MysqlWithSrid<T>`] for any of the types above
+///
+/// ### [`FromSql`] impls
+///
+/// - [`MysqlGeometry`]
+/// - [`MysqlPoint`], [`MysqlLineString`] and [`MysqlPolygon`],
+///   which fail to deserialize values of any other kind
+/// - [`MysqlWithSrid<T>`] for any of the types above
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`MysqlGeometry`]: crate::mysql_like::data_types::MysqlGeometry
+/// [`MysqlPoint`]: crate::mysql_like::data_types::MysqlPoint
+/// [`MysqlLineString`]: crate::mysql_like::data_types::MysqlLineString
+/// [`MysqlPolygon`]: crate::mysql_like::data_types::MysqlPolygon
+/// [`MysqlWithSrid<T>`]: crate::mysql_like::data_types::MysqlWithSrid
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(mysql_type(name = "Blob"))]
+#[diesel(mariadb_type(name = "Blob"))]
+pub struct Geometry;
+
+/// The MySQL `POINT` type.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`MysqlPoint`]
+/// - [`MysqlWithSrid<MysqlPoint>`]
+///
+/// ### [`FromSql`] impls
+///
+/// - [`MysqlPoint`]
+/// - [`MysqlWithSrid<MysqlPoint>`]
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`MysqlPoint`]: crate::mysql_like::data_types::MysqlPoint
+/// [`MysqlWithSrid<MysqlPoint>`]: crate::mysql_like::data_types::MysqlWithSrid
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(mysql_type(name = "Blob"))]
+#[diesel(mariadb_type(name = "Blob"))]
+pub struct Point;
+
+/// The MySQL `LINESTRING` type.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`MysqlLineString`]
+/// - [`MysqlWithSrid<MysqlLineString>`]
+///
+/// ### [`FromSql`] impls
+///
+/// - [`MysqlLineString`]
+/// - [`MysqlWithSrid<MysqlLineString>`]
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`MysqlLineString`]: crate::mysql_like::data_types::MysqlLineString
+/// [`MysqlWithSrid<MysqlLineString>`]: crate::mysql_like::data_types::MysqlWithSrid
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(mysql_type(name = "Blob"))]
+#[diesel(mariadb_type(name = "Blob"))]
+pub struct Linestring;
+
+/// The MySQL `POLYGON` type.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`MysqlPolygon`]
+/// - [`MysqlWithSrid<MysqlPolygon>`]
+///
+/// ### [`FromSql`] impls
+///
+/// - [`MysqlPolygon`]
+/// - [`MysqlWithSrid<MysqlPolygon>`]
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`MysqlPolygon`]: crate::mysql_like::data_types::MysqlPolygon
+/// [`MysqlWithSrid<MysqlPolygon>`]: crate::mysql_like::data_types::MysqlWithSrid
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(mysql_type(name = "Blob"))]
+#[diesel(mariadb_type(name = "Blob"))]
+pub struct Polygon;
+
+/// A single location in a two dimensional coordinate space
+#[derive(Debug, Clone, Copy, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Point)]
+#[diesel(sql_type = Geometry)]
+pub struct MysqlPoint {
+    /// The x coordinate, or the longitude for geographic
+    /// spatial reference systems
+    pub x: f64,
+    /// The y coordinate, or the latitude for geographic
+    /// spatial reference systems
+    pub y: f64,
+}
+
+/// A curve with linear interpolation between the given points
+#[derive(Debug, Clone, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Linestring)]
+#[diesel(sql_type = Geometry)]
+pub struct MysqlLineString(pub Vec<MysqlPoint>);
+
+/// A planar surface consisting of one exterior boundary
+/// followed by zero or more interior boundaries
+///
+/// Each boundary is a closed [`MysqlLineString`]
+#[derive(Debug, Clone, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Polygon)]
+#[diesel(sql_type = Geometry)]
+pub struct MysqlPolygon(pub Vec<MysqlLineString>);
+
+/// Any spatial value that can be stored in a `GEOMETRY` column
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Geometry)]
+pub enum MysqlGeometry {
+    /// A `POINT` value
+    Point(MysqlPoint),
+    /// A `LINESTRING` value
+    LineString(MysqlLineString),
+    /// A `POLYGON` value
+    Polygon(MysqlPolygon),
+    /// A `MULTIPOINT` value
+    MultiPoint(Vec<MysqlPoint>),
+    /// A `MULTILINESTRING` value
+    MultiLineString(Vec<MysqlLineString>),
+    /// A `MULTIPOLYGON` value
+    MultiPolygon(Vec<MysqlPolygon>),
+    /// A `GEOMETRYCOLLECTION` value
+    GeometryCollection(Vec<MysqlGeometry>),
+}
+
+/// A spatial value together with the identifier of its spatial reference system
+///
+/// The plain spatial types ignore the SRID of loaded values
+/// and are sent with the SRID `0`, the cartesian plane. Use this type
+/// for columns which are restricted to a different spatial reference system,
+/// for example `4326` (WGS 84).
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Geometry)]
+#[diesel(sql_type = Point)]
+#[diesel(sql_type = Linestring)]
+#[diesel(sql_type = Polygon)]
+pub struct MysqlWithSrid<T> {
+    /// The identifier of the spatial reference system
+    pub srid: u32,
+    /// The spatial value
+    pub value: T,
+}
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+#[derive(Clone, Copy)]
+enum ByteOrder {
+    Big,
+    Little,
+}
+
+fn read_u32(bytes: &mut &[u8], order: ByteOrder) -> deserialize::Result<u32> {
+    let value = match order {
+        ByteOrder::Big => bytes.read_u32::<BigEndian>(),
+        ByteOrder::Little => bytes.read_u32::<LittleEndian>(),
+    };
+    value.map_err(|_| "Unexpected end of a WKB value".into())
+}
+
+fn read_f64(bytes: &mut &[u8], order: ByteOrder) -> deserialize::Result<f64> {
+    let value = match order {
+        ByteOrder::Big => bytes.read_f64::<BigEndian>(),
+        ByteOrder::Little => bytes.read_f64::<LittleEndian>(),
+    };
+    value.map_err(|_| "Unexpected end of a WKB value".into())
+}
+
+fn read_header(bytes: &mut &[u8]) -> deserialize::Result<(ByteOrder, u32)> {
+    let order = match bytes.read_u8() {
+        Ok(0) => ByteOrder::Big,
+        Ok(1) => ByteOrder::Little,
+        Ok(b) => return Err(format!("Invalid WKB byte order {b}").into()),
+        Err(_) => return Err("Unexpected end of a WKB value".into()),
+    };
+    let tpe = read_u32(bytes, order)?;
+    Ok((order, tpe))
+}
+
+fn read_point(bytes: &mut &[u8], order: ByteOrder) -> deserialize::Result<MysqlPoint> {
+    let x = read_f64(bytes, order)?;
+    let y = read_f64(bytes, order)?;
+    Ok(MysqlPoint { x, y })
+}
+
+fn read_line_string(bytes: &mut &[u8], order: ByteOrder) -> deserialize::Result<MysqlLineString> {
+    // the count is not trusted for preallocation as it comes from the wire
+    let count = read_u32(bytes, order)?;
+    (0..count)
+        .map(|_| read_point(bytes, order))
+        .collect::<deserialize::Result<_>>()
+        .map(MysqlLineString)
+}
+
+fn read_polygon(bytes: &mut &[u8], order: ByteOrder) -> deserialize::Result<MysqlPolygon> {
+    let count = read_u32(bytes, order)?;
+    (0..count)
+        .map(|_| read_line_string(bytes, order))
+        .collect::<deserialize::Result<_>>()
+        .map(MysqlPolygon)
+}
+
+fn read_collection<T>(
+    bytes: &mut &[u8],
+    order: ByteOrder,
+    mut read_element: impl FnMut(&mut &[u8]) -> deserialize::Result<T>,
+) -> deserialize::Result<Vec<T>> {
+    let count = read_u32(bytes, order)?;
+    (0..count).map(|_| read_element(bytes)).collect()
+}
+
+/// Reads a geometry that is nested in a multi geometry,
+/// which needs to be of the given kind
+fn read_nested<T>(
+    bytes: &mut &[u8],
+    expected: u32,
+    read: impl FnOnce(&mut &[u8], ByteOrder) -> deserialize::Result<T>,
+) -> deserialize::Result<T> {
+    let (order, tpe) = read_header(bytes)?;
+    if tpe != expected {
+        return Err(format!("Unexpected WKB geometry type {tpe}, expected {expected}").into());
+    }
+    read(bytes, order)
+}
+
+fn read_geometry(bytes: &mut &[u8]) -> deserialize::Result<MysqlGeometry> {
+    let (order, tpe) = read_header(bytes)?;
+    let geometry = match tpe {
+        WKB_POINT => MysqlGeometry::Point(read_point(bytes, order)?),
+        WKB_LINESTRING => MysqlGeometry::LineString(read_line_string(bytes, order)?),
+        WKB_POLYGON => MysqlGeometry::Polygon(read_polygon(bytes, order)?),
+        WKB_MULTIPOINT => MysqlGeometry::MultiPoint(read_collection(bytes, order, |b| {
+            read_nested(b, WKB_POINT, read_point)
+        })?),
+        WKB_MULTILINESTRING => {
+            MysqlGeometry::MultiLineString(read_collection(bytes, order, |b| {
+                read_nested(b, WKB_LINESTRING, read_line_string)
+            })?)
+        }
+        WKB_MULTIPOLYGON => MysqlGeometry::MultiPolygon(read_collection(bytes, order, |b| {
+            read_nested(b, WKB_POLYGON, read_polygon)
+        })?),
+        WKB_GEOMETRYCOLLECTION => {
+            MysqlGeometry::GeometryCollection(read_collection(bytes, order, read_geometry)?)
+        }
+        tpe => return Err(format!("Unknown WKB geometry type {tpe}").into()),
+    };
+    Ok(geometry)
+}
+
+/// Splits a value in the internal storage format into the SRID and the geometry
+fn read_spatial_value(value: MysqlValue<'_>) -> deserialize::Result<(u32, MysqlGeometry)> {
+    let mut bytes = value.as_bytes();
+    let srid = bytes
+        .read_u32::<LittleEndian>()
+        .map_err(|_| "Received less than 4 bytes while decoding a spatial value")?;
+    let geometry = read_geometry(&mut bytes)?;
+    if !bytes.is_empty() {
+        return Err("Received trailing bytes while decoding a spatial value".into());
+    }
+    Ok((srid, geometry))
+}
+
+fn write_header<W: Write>(out: &mut W, tpe: u32) -> std::io::Result<()> {
+    out.write_u8(1)?;
+    out.write_u32::<LittleEndian>(tpe)
+}
+
+fn write_count<W: Write>(out: &mut W, count: usize) -> serialize::Result {
+    let count = u32::try_from(count).map_err(|_| "Too many elements for a spatial value")?;
+    out.write_u32::<LittleEndian>(count)?;
+    Ok(IsNull::No)
+}
+
+fn write_collection<W: Write, T>(
+    out: &mut W,
+    tpe: u32,
+    elements: &[T],
+    write_element: impl Fn(&T, &mut W) -> serialize::Result,
+) -> serialize::Result {
+    write_header(out, tpe)?;
+    write_count(out, elements.len())?;
+    for element in elements {
+        write_element(element, out)?;
+    }
+    Ok(IsNull::No)
+}
+
+impl MysqlPoint {
+    fn write_coordinates<W: Write>(&self, out: &mut W) -> std::io::Result<()> {
+        out.write_f64::<LittleEndian>(self.x)?;
+        out.write_f64::<LittleEndian>(self.y)
+    }
+
+    fn write_wkb<W: Write>(&self, out: &mut W) -> serialize::Result {
+        write_header(out, WKB_POINT)?;
+        self.write_coordinates(out)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl MysqlLineString {
+    fn write_points<W: Write>(&self, out: &mut W) -> serialize::Result {
+        write_count(out, self.0.len())?;
+        for point in &self.0 {
+            point.write_coordinates(out)?;
+        }
+        Ok(IsNull::No)
+    }
+
+    fn write_wkb<W: Write>(&self, out: &mut W) -> serialize::Result {
+        write_header(out, WKB_LINESTRING)?;
+        self.write_points(out)
+    }
+}
+
+impl MysqlPolygon {
+    fn write_rings<W: Write>(&self, out: &mut W) -> serialize::Result {
+        write_count(out, self.0.len())?;
+        for ring in &self.0 {
+            ring.write_points(out)?;
+        }
+        Ok(IsNull::No)
+    }
+
+    fn write_wkb<W: Write>(&self, out: &mut W) -> serialize::Result {
+        write_header(out, WKB_POLYGON)?;
+        self.write_rings(out)
+    }
+}
+
+impl MysqlGeometry {
+    fn write_wkb<W: Write>(&self, out: &mut W) -> serialize::Result {
+        match self {
+            MysqlGeometry::Point(point) => point.write_wkb(out),
+            MysqlGeometry::LineString(line_string) => line_string.write_wkb(out),
+            MysqlGeometry::Polygon(polygon) => polygon.write_wkb(out),
+            MysqlGeometry::MultiPoint(points) => {
+                write_collection(out, WKB_MULTIPOINT, points, MysqlPoint::write_wkb)
+            }
+            MysqlGeometry::MultiLineString(line_strings) => write_collection(
+                out,
+                WKB_MULTILINESTRING,
+                line_strings,
+                MysqlLineString::write_wkb,
+            ),
+            MysqlGeometry::MultiPolygon(polygons) => {
+                write_collection(out, WKB_MULTIPOLYGON, polygons, MysqlPolygon::write_wkb)
+            }
+            MysqlGeometry::GeometryCollection(geometries) => write_collection(
+                out,
+                WKB_GEOMETRYCOLLECTION,
+                geometries,
+                MysqlGeometry::write_wkb,
+            ),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            MysqlGeometry::Point(_) => "POINT",
+            MysqlGeometry::LineString(_) => "LINESTRING",
+            MysqlGeometry::Polygon(_) => "POLYGON",
+            MysqlGeometry::MultiPoint(_) => "MULTIPOINT",
+            MysqlGeometry::MultiLineString(_) => "MULTILINESTRING",
+            MysqlGeometry::MultiPolygon(_) => "MULTIPOLYGON",
+            MysqlGeometry::GeometryCollection(_) => "GEOMETRYCOLLECTION",
+        }
+    }
+}
+
+impl From<MysqlPoint> for MysqlGeometry {
+    fn from(point: MysqlPoint) -> Self {
+        MysqlGeometry::Point(point)
+    }
+}
+
+impl From<MysqlLineString> for MysqlGeometry {
+    fn from(line_string: MysqlLineString) -> Self {
+        MysqlGeometry::LineString(line_string)
+    }
+}
+
+impl From<MysqlPolygon> for MysqlGeometry {
+    fn from(polygon: MysqlPolygon) -> Self {
+        MysqlGeometry::Polygon(polygon)
+    }
+}
+
+fn unexpected_kind<T>(expected: &str, geometry: &MysqlGeometry) -> deserialize::Result<T> {
+    Err(format!(
+        "Expected a spatial value of type {expected}, but got a {}",
+        geometry.kind()
+    )
+    .into())
+}
+
+macro_rules! spatial_impls {
+    (@sql_types $ty:ty: $($sql_type:ty),+) => {
+        $(
+            impl<DB: MysqlLikeBackend> ToSql<$sql_type, DB> for $ty {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    out.write_u32::<LittleEndian>(0)?;
+                    self.write_wkb(out)
+                }
+            }
+
+            impl<DB: MysqlLikeBackend> FromSql<$sql_type, DB> for $ty {
+                fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+                    let (_srid, geometry) = read_spatial_value(value)?;
+                    Self::from_geometry(geometry)
+                }
+            }
+
+            impl<DB: MysqlLikeBackend> ToSql<$sql_type, DB> for MysqlWithSrid<$ty> {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    out.write_u32::<LittleEndian>(self.srid)?;
+                    self.value.write_wkb(out)
+                }
+            }
+
+            impl<DB: MysqlLikeBackend> FromSql<$sql_type, DB> for MysqlWithSrid<$ty> {
+                fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+                    let (srid, geometry) = read_spatial_value(value)?;
+                    Ok(MysqlWithSrid {
+                        srid,
+                        value: <$ty>::from_geometry(geometry)?,
+                    })
+                }
+            }
+        )+
+    };
+    ($ty:ty, $variant:ident, $kind:literal: $($sql_type:ty),+) => {
+        impl $ty {
+            fn from_geometry(geometry: MysqlGeometry) -> deserialize::Result<Self> {
+                match geometry {
+                    MysqlGeometry::$variant(value) => Ok(value),
+                    geometry => unexpected_kind($kind, &geometry),
+                }
+            }
+        }
+
+        spatial_impls!(@sql_types $ty: $($sql_type),+);
+    };
+}
+
+spatial_impls!(MysqlPoint, Point, "POINT": Point, Geometry);
+spatial_impls!(MysqlLineString, LineString, "LINESTRING": Linestring, Geometry);
+spatial_impls!(MysqlPolygon, Polygon, "POLYGON": Polygon, Geometry);
+
+impl MysqlGeometry {
+    fn from_geometry(geometry: MysqlGeometry) -> deserialize::Result<Self> {
+        Ok(geometry)
+    }
+}
+
+spatial_impls!(@sql_types MysqlGeometry: Geometry);
+
+#[cfg(all(test, feature = "mysql"))]
+mod tests {
+    use super::*;
+    use crate::mysql::{Mysql, MysqlType};
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    fn to_bytes<T: ToSql<ST, Mysql>, ST>(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<ST, Mysql>::to_sql(value, &mut bytes).unwrap();
+        buffer
+    }
+
+    fn from_bytes<T: FromSql<ST, Mysql>, ST>(bytes: &[u8]) -> deserialize::Result<T> {
+        FromSql::<ST, Mysql>::from_sql(MysqlValue::new_internal(bytes, MysqlType::Blob))
+    }
+
+    #[diesel_test_helper::test]
+    fn point_to_sql() {
+        let point = MysqlPoint { x: 1.0, y: -2.5 };
+        let mut expected = vec![0, 0, 0, 0, 1, 1, 0, 0, 0];
+        expected.extend_from_slice(&1.0f64.to_le_bytes());
+        expected.extend_from_slice(&(-2.5f64).to_le_bytes());
+        assert_eq!(to_bytes::<_, Point>(&point), expected);
+        assert_eq!(to_bytes::<_, Geometry>(&point), expected);
+
+        let with_srid = MysqlWithSrid {
+            srid: 4326,
+            value: point,
+        };
+        expected[..4].copy_from_slice(&4326u32.to_le_bytes());
+        assert_eq!(to_bytes::<_, Point>(&with_srid), expected);
+    }
+
+    #[diesel_test_helper::test]
+    fn big_endian_point_from_sql() {
+        let mut bytes = vec![0xE6, 0x10, 0, 0, 0, 0, 0, 0, 1];
+        bytes.extend_from_slice(&1.5f64.to_be_bytes());
+        bytes.extend_from_slice(&2.0f64.to_be_bytes());
+
+        let point: MysqlPoint = from_bytes::<_, Point>(&bytes).unwrap();
+        assert_eq!(point, MysqlPoint { x: 1.5, y: 2.0 });
+
+        let with_srid: MysqlWithSrid<MysqlPoint> = from_bytes::<_, Point>(&bytes).unwrap();
+        assert_eq!(with_srid.srid, 4326);
+        assert_eq!(with_srid.value, point);
+    }
+
+    #[diesel_test_helper::test]
+    fn geometry_roundtrip() {
+        let square = MysqlLineString(vec![
+            MysqlPoint { x: 0.0, y: 0.0 },
+            MysqlPoint { x: 10.0, y: 0.0 },
+            MysqlPoint { x: 10.0, y: 10.0 },
+            MysqlPoint { x: 0.0, y: 10.0 },
+            MysqlPoint { x: 0.0, y: 0.0 },
+        ]);
+        let geometries = vec![
+            MysqlGeometry::Point(MysqlPoint { x: 1.0, y: 1.0 }),
+            MysqlGeometry::LineString(square.clone()),
+            MysqlGeometry::Polygon(MysqlPolygon(vec![square.clone()])),
+            MysqlGeometry::MultiPoint(vec![
+                MysqlPoint { x: 1.0, y: 1.0 },
+                MysqlPoint { x: 2.0, y: 2.0 },
+            ]),
+            MysqlGeometry::MultiLineString(vec![square.clone(), square.clone()]),
+            MysqlGeometry::MultiPolygon(vec![MysqlPolygon(vec![square.clone()])]),
+            MysqlGeometry::GeometryCollection(vec![
+                MysqlGeometry::Point(MysqlPoint { x: 1.0, y: 1.0 }),
+                MysqlGeometry::LineString(square),
+            ]),
+        ];
+
+        for geometry in geometries {
+            let bytes = to_bytes::<_, Geometry>(&geometry);
+            let loaded: MysqlGeometry = from_bytes::<_, Geometry>(&bytes).unwrap();
+            assert_eq!(loaded, geometry);
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn unexpected_geometry_kind_from_sql() {
+        let bytes = to_bytes::<_, Geometry>(&MysqlLineString(Vec::new()));
+        let point = from_bytes::<MysqlPoint, Geometry>(&bytes);
+        assert_eq!(
+            point.unwrap_err().to_string(),
+            "Expected a spatial value of type POINT, but got a LINESTRING"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn truncated_geometry_from_sql() {
+        let bytes = to_bytes::<_, Point>(&MysqlPoint { x: 1.0, y: 1.0 });
+        let point = from_bytes::<MysqlPoint, Point>(&bytes[..bytes.len() - 1]);
+        assert_eq!(
+            point.unwrap_err().to_string(),
+            "Unexpected end of a WKB value"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn spatial_values_are_understood_by_the_server() {
+        use crate::dsl::{st_as_text, st_geom_from_text, st_srid};
+        use crate::prelude::*;
+        use crate::select;
+        use crate::test_helpers::connection;
+
+        let connection = &mut connection();
+        let polygon = MysqlPolygon(vec![MysqlLineString(vec![
+            MysqlPoint { x: 0.0, y: 0.0 },
+            MysqlPoint { x: 10.0, y: 0.0 },
+            MysqlPoint { x: 10.0, y: 10.0 },
+            MysqlPoint { x: 0.0, y: 0.0 },
+        ])]);
+
+        let text = select(st_as_text(polygon.clone().into_sql::<Polygon>()))
+            .get_result::<String>(connection)
+            .unwrap();
+        assert_eq!(text, "POLYGON((0 0,10 0,10 10,0 0))");
+
+        let loaded = select(st_geom_from_text("POLYGON((0 0,10 0,10 10,0 0))"))
+            .get_result::<MysqlGeometry>(connection)
+            .unwrap();
+        assert_eq!(loaded, MysqlGeometry::Polygon(polygon));
+
+        let srid = select(st_srid(
+            MysqlWithSrid {
+                srid: 4326,
+                value: MysqlPoint { x: 1.0, y: 1.0 },
+            }
+            .into_sql::<Point>(),
+        ))
+        .get_result::<u32>(connection)
+        .unwrap();
+        assert_eq!(srid, 4326);
+    }
+}
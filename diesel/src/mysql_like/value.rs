@@ -21,11 +21,6 @@ impl<'a> MysqlValue<'a> {
         Self::new_internal(raw, tpe)
     }
 
-    #[cfg(any(
-        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes",
-        feature = "mysql",
-        feature = "mariadb"
-    ))]
     pub(crate) fn new_internal(raw: &'a [u8], tpe: MysqlType) -> Self {
         Self { raw, tpe }
     }
@@ -1,10 +1,13 @@
 //! PostgreSQL specific expression methods
 
 pub(in crate::pg) use self::private::{
-    ArrayOrNullableArray, CombinedAllNullableValue, InetOrCidr, IntegerOrNullableInteger,
-    JsonOrNullableJson, JsonRemoveIndex, JsonbOrNullableJsonb, MaybeNullableValue,
-    MultirangeOrNullableMultirange, MultirangeOrRangeMaybeNullable, RangeOrMultirange,
-    RangeOrNullableRange, RecordOrNullableRecord, TextArrayOrNullableTextArray, TextOrNullableText,
+    ArrayOrNullableArray, BitStringOrNullableBitString, CombinedAllNullableValue, GeometricArea,
+    GeometricOrNullableGeometric, HstoreOrNullableHstore, InetOrCidr, IntegerOrNullableInteger,
+    IntersectableGeometric, JsonOrNullableJson, JsonRemoveIndex, JsonbOrNullableJsonb,
+    LtreeOrNullableLtree, MaybeNullableValue, MultirangeOrNullableMultirange,
+    MultirangeOrRangeMaybeNullable, RangeOrMultirange, RangeOrNullableRange,
+    RecordOrNullableRecord, TextArrayOrNullableTextArray, TextOrNullableText,
+    TsQueryOrNullableTsQuery, TsVectorOrNullableTsVector, VectorOrNullableVector,
 };
 use super::date_and_time::{AtTimeZone, DateTimeLike};
 use super::operators::*;
@@ -18,8 +21,11 @@ use crate::expression_methods::json_expression_methods::JsonIndex;
 use crate::expression_methods::json_expression_methods::private::JsonOrNullableJsonOrJsonbOrNullableJsonb;
 use crate::pg::expression::expression_methods::private::BinaryOrNullableBinary;
 use crate::pg::expression::operators::RetrieveAsObjectJson;
+use crate::pg::sql_types::Point;
 use crate::sql_types::Nullable;
-use crate::sql_types::{Array, Inet, Integer, Range, SqlType, Text, VarChar};
+use crate::sql_types::{
+    Array, Inet, Integer, Lquery, Ltxtquery, Range, SqlType, Text, TsQuery, VarChar,
+};
 
 /// PostgreSQL specific methods which are present on all expressions.
 #[cfg(feature = "postgres_backend")]
@@ -3686,307 +3692,779 @@ where
 {
 }
 
-/// PostgreSQL specific methods present on JSON and JSONB expressions.
+/// PostgreSQL specific methods present on `hstore` expressions
 #[cfg(feature = "postgres_backend")]
-pub trait PgAnyJsonExpressionMethods: AnyJsonExpressionMethods + Expression + Sized {
+pub trait PgHstoreExpressionMethods: Expression + Sized {
     /// Creates a PostgreSQL `->` expression.
     ///
-    /// This operator extracts the value associated with the given key, that is provided on the
-    /// Right Hand Side of the operator.
+    /// This operator returns the value stored for the given key, or `NULL`
+    /// if the key is not present.
     ///
-    /// Extracts n'th element of JSON array (array elements are indexed from zero, but negative integers count from the end).
-    /// Extracts JSON object field with the given key.
     /// # Example
     ///
     /// ```rust
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
-    /// #    contacts {
-    /// #        id -> Integer,
-    /// #        name -> VarChar,
-    /// #        address -> Jsonb,
-    /// #    }
+    /// #     products {
+    /// #         id -> Integer,
+    /// #         attributes -> Hstore,
+    /// #     }
     /// # }
     /// #
     /// # fn main() {
     /// #     run_test().unwrap();
     /// # }
-    ///
-    /// # #[cfg(feature = "serde_json")]
+    /// #
     /// # fn run_test() -> QueryResult<()> {
-    /// #     use self::contacts::dsl::*;
+    /// #     use self::products::dsl::*;
+    /// #     use std::collections::HashMap;
     /// #     let conn = &mut establish_connection();
-    /// #     diesel::sql_query("DROP TABLE IF EXISTS contacts").execute(conn).unwrap();
-    /// #     diesel::sql_query("CREATE TABLE contacts (
-    /// #         id SERIAL PRIMARY KEY,
-    /// #         name VARCHAR NOT NULL,
-    /// #         address JSONB NOT NULL
-    /// #     )").execute(conn)
-    /// #        .unwrap();
-    /// #
-    /// let santas_address: serde_json::Value = serde_json::json!({
-    ///     "street": "Article Circle Expressway 1",
-    ///     "city": "North Pole",
-    ///     "postcode": "99705",
-    ///     "state": "Alaska"
-    /// });
-    /// diesel::insert_into(contacts)
-    ///     .values((name.eq("Claus"), address.eq(&santas_address)))
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS hstore").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS products").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE products (id SERIAL PRIMARY KEY, attributes HSTORE NOT NULL)").execute(conn).unwrap();
+    /// let shirt = HashMap::from([
+    ///     ("color".to_owned(), Some("red".to_owned())),
+    ///     ("size".to_owned(), Some("M".to_owned())),
+    ///     ("discount".to_owned(), None),
+    /// ]);
+    /// diesel::insert_into(products)
+    ///     .values(attributes.eq(&shirt))
     ///     .execute(conn)?;
     ///
-    /// let santas_postcode = contacts.select(address.retrieve_as_object("postcode")).get_result::<serde_json::Value>(conn)?;
-    /// assert_eq!(santas_postcode, serde_json::json!("99705"));
-    ///
+    /// let color = products
+    ///     .select(attributes.retrieve_value("color"))
+    ///     .get_result::<Option<String>>(conn)?;
+    /// assert_eq!(Some("red".to_owned()), color);
     ///
-    /// let robert_downey_jr_addresses: serde_json::Value = serde_json::json!([
-    ///     {
-    ///         "street": "Somewhere In La 251",
-    ///         "city": "Los Angeles",
-    ///         "postcode": "12231223",
-    ///         "state": "California"
-    ///     },
-    ///     {
-    ///         "street": "Somewhere In Ny 251",
-    ///         "city": "New York",
-    ///         "postcode": "3213212",
-    ///         "state": "New York"
-    ///     }
-    /// ]);
+    /// let discount = products
+    ///     .select(attributes.retrieve_value("discount"))
+    ///     .get_result::<Option<String>>(conn)?;
+    /// assert_eq!(None, discount);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn retrieve_value<T>(self, key: T) -> dsl::RetrieveValueHstore<Self, T>
+    where
+        T: AsExpression<Text>,
+    {
+        Grouped(RetrieveValueHstore::new(self, key.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `?` expression.
     ///
-    /// diesel::insert_into(contacts)
-    ///     .values((name.eq("Robert Downey Jr."), address.eq(&robert_downey_jr_addresses)))
-    ///     .execute(conn)?;
+    /// This operator returns whether the given key is present, even if its
+    /// value is `NULL`.
     ///
-    /// let roberts_second_address_in_db = contacts
-    ///                             .filter(name.eq("Robert Downey Jr."))
-    ///                             .select(address.retrieve_as_object(1))
-    ///                             .get_result::<serde_json::Value>(conn)?;
+    /// # Example
     ///
-    /// let roberts_second_address = serde_json::json!({
-    ///         "street": "Somewhere In Ny 251",
-    ///         "city": "New York",
-    ///         "postcode": "3213212",
-    ///         "state": "New York"
-    /// });
-    /// assert_eq!(roberts_second_address, roberts_second_address_in_db);
-    /// #     Ok(())
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     products {
+    /// #         id -> Integer,
+    /// #         attributes -> Hstore,
+    /// #     }
     /// # }
-    /// # #[cfg(not(feature = "serde_json"))]
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
     /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::products::dsl::*;
+    /// #     use std::collections::HashMap;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS hstore").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS products").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE products (id SERIAL PRIMARY KEY, attributes HSTORE NOT NULL)").execute(conn).unwrap();
+    /// let shirt = HashMap::from([
+    ///     ("color".to_owned(), Some("red".to_owned())),
+    ///     ("size".to_owned(), Some("M".to_owned())),
+    ///     ("discount".to_owned(), None),
+    /// ]);
+    /// diesel::insert_into(products)
+    ///     .values(attributes.eq(&shirt))
+    ///     .execute(conn)?;
+    ///
+    /// let has_discount = products
+    ///     .select(attributes.has_key("discount"))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(has_discount);
+    ///
+    /// let has_material = products
+    ///     .select(attributes.has_key("material"))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(!has_material);
     /// #     Ok(())
     /// # }
     /// ```
-    fn retrieve_as_object<T>(
-        self,
-        other: T,
-    ) -> crate::pg::expression::helper_types::RetrieveAsObject<Self, T>
+    fn has_key<T>(self, key: T) -> dsl::HasKey<Self, T>
     where
-        T: JsonIndex,
-        <T::Expression as Expression>::SqlType: SqlType,
+        T: AsExpression<VarChar>,
     {
-        Grouped(RetrieveAsObjectJson::new(
-            self,
-            other.into_json_index_expression(),
-        ))
+        Grouped(HasKeyJsonb::new(self, key.as_expression()))
     }
 
-    /// Creates a PostgreSQL `#>` expression.
+    /// Creates a PostgreSQL `?&` expression.
     ///
-    /// This operator extracts the value associated with the given key, that is provided on the
-    /// Right Hand Side of the operator.
+    /// This operator returns whether all of the given keys are present.
     ///
-    /// Extracts n'th element of JSON array (array elements are indexed from zero, but negative integers count from the end).
-    /// Extracts JSON object field with the given key.
     /// # Example
     ///
     /// ```rust
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
-    /// #    contacts {
-    /// #        id -> Integer,
-    /// #        name -> VarChar,
-    /// #        address -> Jsonb,
-    /// #    }
+    /// #     products {
+    /// #         id -> Integer,
+    /// #         attributes -> Hstore,
+    /// #     }
     /// # }
     /// #
     /// # fn main() {
     /// #     run_test().unwrap();
     /// # }
-    ///
-    /// # #[cfg(feature = "serde_json")]
+    /// #
     /// # fn run_test() -> QueryResult<()> {
-    /// #     use self::contacts::dsl::*;
+    /// #     use self::products::dsl::*;
+    /// #     use std::collections::HashMap;
     /// #     let conn = &mut establish_connection();
-    /// #     diesel::sql_query("DROP TABLE IF EXISTS contacts").execute(conn).unwrap();
-    /// #     diesel::sql_query("CREATE TABLE contacts (
-    /// #         id SERIAL PRIMARY KEY,
-    /// #         name VARCHAR NOT NULL,
-    /// #         address JSONB NOT NULL
-    /// #     )").execute(conn)
-    /// #        .unwrap();
-    /// #
-    /// let robert_downey_jr_addresses: serde_json::Value = serde_json::json!([
-    ///     {
-    ///         "street": "Somewhere In La 251",
-    ///         "city": "Los Angeles",
-    ///         "postcode": "12231223",
-    ///         "state": "California"
-    ///     },
-    ///     {
-    ///         "street": "Somewhere In Ny 251",
-    ///         "city": "New York",
-    ///         "postcode": "3213212",
-    ///         "state": "New York"
-    ///     }
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS hstore").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS products").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE products (id SERIAL PRIMARY KEY, attributes HSTORE NOT NULL)").execute(conn).unwrap();
+    /// let shirt = HashMap::from([
+    ///     ("color".to_owned(), Some("red".to_owned())),
+    ///     ("size".to_owned(), Some("M".to_owned())),
+    ///     ("discount".to_owned(), None),
     /// ]);
-    ///
-    /// diesel::insert_into(contacts)
-    ///     .values((name.eq("Robert Downey Jr."), address.eq(&robert_downey_jr_addresses)))
+    /// diesel::insert_into(products)
+    ///     .values(attributes.eq(&shirt))
     ///     .execute(conn)?;
     ///
-    /// let roberts_second_street_in_db = contacts
-    ///                             .filter(name.eq("Robert Downey Jr."))
-    ///                             .select(address.retrieve_by_path_as_object(vec!["1", "street"]))
-    ///                             .get_result::<serde_json::Value>(conn)?;
+    /// let has_keys = products
+    ///     .select(attributes.has_all_keys(vec!["color", "size"]))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(has_keys);
     ///
-    /// assert_eq!(roberts_second_street_in_db, serde_json::json!("Somewhere In Ny 251"));
-    /// #     Ok(())
-    /// # }
-    /// # #[cfg(not(feature = "serde_json"))]
-    /// # fn run_test() -> QueryResult<()> {
+    /// let has_keys = products
+    ///     .select(attributes.has_all_keys(vec!["color", "material"]))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(!has_keys);
     /// #     Ok(())
     /// # }
     /// ```
-    fn retrieve_by_path_as_object<T>(
-        self,
-        other: T,
-    ) -> dsl::RetrieveByPathAsObjectJson<Self, T::Expression>
+    fn has_all_keys<T>(self, keys: T) -> dsl::HasAllKeys<Self, T>
     where
-        T: AsExpression<Array<Text>>,
+        T: AsExpression<Array<VarChar>>,
     {
-        Grouped(RetrieveByPathAsObjectJson::new(self, other.as_expression()))
+        Grouped(HasAllKeysJsonb::new(self, keys.as_expression()))
     }
 
-    /// Creates a PostgreSQL `#>>` expression.
+    /// Creates a PostgreSQL `@>` expression.
     ///
-    /// This operator extracts the value associated with the given key, that is provided on the
-    /// Right Hand Side of the operator.
+    /// This operator returns whether the left hand side contains all
+    /// key/value pairs of the right hand side.
     ///
-    /// Extracts n'th element of JSON array (array elements are indexed from zero, but negative integers count from the end).
-    /// Extracts JSON object field as Text with the given key.
     /// # Example
     ///
     /// ```rust
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
-    /// #    contacts {
-    /// #        id -> Integer,
-    /// #        name -> VarChar,
-    /// #        address -> Jsonb,
-    /// #    }
+    /// #     products {
+    /// #         id -> Integer,
+    /// #         attributes -> Hstore,
+    /// #     }
     /// # }
     /// #
     /// # fn main() {
     /// #     run_test().unwrap();
     /// # }
-    ///
-    /// # #[cfg(feature = "serde_json")]
+    /// #
     /// # fn run_test() -> QueryResult<()> {
-    /// #     use self::contacts::dsl::*;
+    /// #     use self::products::dsl::*;
+    /// #     use std::collections::HashMap;
     /// #     let conn = &mut establish_connection();
-    /// #     diesel::sql_query("DROP TABLE IF EXISTS contacts").execute(conn).unwrap();
-    /// #     diesel::sql_query("CREATE TABLE contacts (
-    /// #         id SERIAL PRIMARY KEY,
-    /// #         name VARCHAR NOT NULL,
-    /// #         address JSONB NOT NULL
-    /// #     )").execute(conn)
-    /// #        .unwrap();
-    /// #
-    /// let robert_downey_jr_addresses: serde_json::Value = serde_json::json!([
-    ///     {
-    ///         "street": "Somewhere In La 251",
-    ///         "city": "Los Angeles",
-    ///         "postcode": "12231223",
-    ///         "state": "California"
-    ///     },
-    ///     {
-    ///         "street": "Somewhere In Ny 251",
-    ///         "city": "New York",
-    ///         "postcode": "3213212",
-    ///         "state": "New York"
-    ///     }
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS hstore").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS products").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE products (id SERIAL PRIMARY KEY, attributes HSTORE NOT NULL)").execute(conn).unwrap();
+    /// let shirt = HashMap::from([
+    ///     ("color".to_owned(), Some("red".to_owned())),
+    ///     ("size".to_owned(), Some("M".to_owned())),
+    ///     ("discount".to_owned(), None),
     /// ]);
-    ///
-    /// diesel::insert_into(contacts)
-    ///     .values((name.eq("Robert Downey Jr."), address.eq(&robert_downey_jr_addresses)))
+    /// diesel::insert_into(products)
+    ///     .values(attributes.eq(&shirt))
     ///     .execute(conn)?;
     ///
-    /// let roberts_second_street_in_db = contacts
-    ///                             .filter(name.eq("Robert Downey Jr."))
-    ///                             .select(address.retrieve_by_path_as_text(vec!["1", "street"]))
-    ///                             .get_result::<String>(conn)?;
-    ///
-    /// assert_eq!(roberts_second_street_in_db, "Somewhere In Ny 251");
+    /// let red = HashMap::from([("color".to_owned(), Some("red".to_owned()))]);
+    /// let red_products = products
+    ///     .select(id)
+    ///     .filter(attributes.contains(&red))
+    ///     .load::<i32>(conn)?;
+    /// assert_eq!(vec![1], red_products);
     ///
-    /// #     Ok(())
-    /// # }
-    /// # #[cfg(not(feature = "serde_json"))]
-    /// # fn run_test() -> QueryResult<()> {
+    /// let blue = HashMap::from([("color".to_owned(), Some("blue".to_owned()))]);
+    /// let blue_products = products
+    ///     .select(id)
+    ///     .filter(attributes.contains(&blue))
+    ///     .load::<i32>(conn)?;
+    /// assert!(blue_products.is_empty());
     /// #     Ok(())
     /// # }
     /// ```
-    fn retrieve_by_path_as_text<T>(
-        self,
-        other: T,
-    ) -> dsl::RetrieveByPathAsTextJson<Self, T::Expression>
+    fn contains<T>(self, other: T) -> dsl::Contains<Self, T>
     where
-        T: AsExpression<Array<Text>>,
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
     {
-        Grouped(RetrieveByPathAsTextJson::new(self, other.as_expression()))
+        Grouped(Contains::new(self, other.as_expression()))
     }
 }
 
-#[doc(hidden)]
-impl<T> PgAnyJsonExpressionMethods for T
+impl<T> PgHstoreExpressionMethods for T
 where
     T: Expression,
-    T::SqlType: JsonOrNullableJsonOrJsonbOrNullableJsonb,
+    T::SqlType: HstoreOrNullableHstore,
 {
 }
 
-/// PostgreSQL specific methods present on Binary expressions.
+/// PostgreSQL specific methods present on geometric expressions like
+/// `point`, `box` or `polygon`
 #[cfg(feature = "postgres_backend")]
-pub trait PgBinaryExpressionMethods: Expression + Sized {
-    /// Concatenates two PostgreSQL byte arrays using the `||` operator.
+pub trait PgGeometricExpressionMethods: Expression + Sized {
+    /// Creates a PostgreSQL `<->` expression.
+    ///
+    /// This operator returns the distance between two geometric values.
     ///
     /// # Example
     ///
     /// ```rust
     /// # include!("../../doctest_setup.rs");
     /// #
-    /// # table! {
-    /// #     users {
-    /// #         id -> Integer,
-    /// #         name -> Binary,
-    /// #         hair_color -> Nullable<Binary>,
-    /// #     }
-    /// # }
-    /// #
     /// # fn main() {
-    /// #     use self::users::dsl::*;
-    /// #     use diesel::insert_into;
-    /// #
-    /// #     let connection = &mut connection_no_data();
-    /// #     diesel::sql_query("CREATE TABLE users (
-    /// #         id INTEGER PRIMARY KEY,
-    /// #         name BYTEA NOT NULL,
-    /// #         hair_color BYTEA
-    /// #     )").execute(connection).unwrap();
+    /// #     run_test().unwrap();
+    /// # }
     /// #
-    /// #     insert_into(users)
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::{PgPoint};
+    /// #     use diesel::pg::sql_types::*;
+    /// #     let conn = &mut establish_connection();
+    /// let origin = PgPoint { x: 0.0, y: 0.0 };
+    /// let distance = diesel::select(
+    ///     origin
+    ///         .into_sql::<Point>()
+    ///         .distance(PgPoint { x: 3.0, y: 4.0 }),
+    /// )
+    /// .get_result::<f64>(conn)?;
+    /// assert_eq!(5.0, distance);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn distance<T>(self, other: T) -> dsl::Distance<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(Distance::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `@>` expression.
+    ///
+    /// This operator returns whether the left hand side contains the right
+    /// hand side. It is available for `box`, `polygon` and `circle`
+    /// expressions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::{PgBox, PgPoint};
+    /// #     use diesel::pg::sql_types::*;
+    /// #     let conn = &mut establish_connection();
+    /// let unit = PgBox {
+    ///     high: PgPoint { x: 1.0, y: 1.0 },
+    ///     low: PgPoint { x: 0.0, y: 0.0 },
+    /// };
+    /// let large = PgBox {
+    ///     high: PgPoint { x: 2.0, y: 2.0 },
+    ///     low: PgPoint { x: -2.0, y: -2.0 },
+    /// };
+    /// let contains = diesel::select(large.into_sql::<GeometricBox>().contains(unit))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(contains);
+    ///
+    /// let contains = diesel::select(unit.into_sql::<GeometricBox>().contains(large))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(!contains);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn contains<T>(self, other: T) -> dsl::Contains<Self, T>
+    where
+        Self::SqlType: GeometricArea,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(Contains::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `@>` expression with a `point` on the right
+    /// hand side.
+    ///
+    /// This operator returns whether the point lies within the area. It is
+    /// available for `box`, `polygon` and `circle` expressions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::{PgCircle, PgPoint};
+    /// #     use diesel::pg::sql_types::*;
+    /// #     let conn = &mut establish_connection();
+    /// let circle = PgCircle {
+    ///     center: PgPoint { x: 0.0, y: 0.0 },
+    ///     radius: 2.0,
+    /// };
+    /// let contains = diesel::select(
+    ///     circle
+    ///         .into_sql::<Circle>()
+    ///         .contains_point(PgPoint { x: 1.0, y: 1.0 }),
+    /// )
+    /// .get_result::<bool>(conn)?;
+    /// assert!(contains);
+    ///
+    /// let contains = diesel::select(
+    ///     circle
+    ///         .into_sql::<Circle>()
+    ///         .contains_point(PgPoint { x: 2.0, y: 2.0 }),
+    /// )
+    /// .get_result::<bool>(conn)?;
+    /// assert!(!contains);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn contains_point<T>(self, point: T) -> dsl::ContainsPoint<Self, T>
+    where
+        Self::SqlType: GeometricArea,
+        T: AsExpression<Point>,
+    {
+        Grouped(Contains::new(self, point.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `&&` expression.
+    ///
+    /// This operator returns whether two areas have any point in common. It
+    /// is available for `box`, `polygon` and `circle` expressions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::{PgPoint, PgPolygon};
+    /// #     use diesel::pg::sql_types::*;
+    /// #     let conn = &mut establish_connection();
+    /// let triangle = PgPolygon(vec![
+    ///     PgPoint { x: 0.0, y: 0.0 },
+    ///     PgPoint { x: 2.0, y: 0.0 },
+    ///     PgPoint { x: 0.0, y: 2.0 },
+    /// ]);
+    /// let square = PgPolygon(vec![
+    ///     PgPoint { x: 1.0, y: 0.0 },
+    ///     PgPoint { x: 3.0, y: 0.0 },
+    ///     PgPoint { x: 3.0, y: 2.0 },
+    ///     PgPoint { x: 1.0, y: 2.0 },
+    /// ]);
+    /// let overlaps = diesel::select(triangle.into_sql::<Polygon>().overlaps_with(square))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(overlaps);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn overlaps_with<T>(self, other: T) -> dsl::OverlapsWith<Self, T>
+    where
+        Self::SqlType: GeometricArea,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(OverlapsWith::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `?#` expression.
+    ///
+    /// This operator returns whether two geometric values intersect. It is
+    /// available for `line`, `lseg`, `box` and `path` expressions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::{PgLineSegment, PgPoint};
+    /// #     use diesel::pg::sql_types::*;
+    /// #     let conn = &mut establish_connection();
+    /// let diagonal = PgLineSegment {
+    ///     start: PgPoint { x: 0.0, y: 0.0 },
+    ///     end: PgPoint { x: 2.0, y: 2.0 },
+    /// };
+    /// let crossing = PgLineSegment {
+    ///     start: PgPoint { x: 0.0, y: 2.0 },
+    ///     end: PgPoint { x: 2.0, y: 0.0 },
+    /// };
+    /// let parallel = PgLineSegment {
+    ///     start: PgPoint { x: 1.0, y: 0.0 },
+    ///     end: PgPoint { x: 3.0, y: 2.0 },
+    /// };
+    /// let intersects = diesel::select(diagonal.into_sql::<LineSegment>().intersects(crossing))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(intersects);
+    ///
+    /// let intersects = diesel::select(diagonal.into_sql::<LineSegment>().intersects(parallel))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(!intersects);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn intersects<T>(self, other: T) -> dsl::Intersects<Self, T>
+    where
+        Self::SqlType: IntersectableGeometric,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(Intersects::new(self, other.as_expression()))
+    }
+}
+
+impl<T> PgGeometricExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: GeometricOrNullableGeometric,
+{
+}
+
+/// PostgreSQL specific methods present on JSON and JSONB expressions.
+#[cfg(feature = "postgres_backend")]
+pub trait PgAnyJsonExpressionMethods: AnyJsonExpressionMethods + Expression + Sized {
+    /// Creates a PostgreSQL `->` expression.
+    ///
+    /// This operator extracts the value associated with the given key, that is provided on the
+    /// Right Hand Side of the operator.
+    ///
+    /// Extracts n'th element of JSON array (array elements are indexed from zero, but negative integers count from the end).
+    /// Extracts JSON object field with the given key.
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #    contacts {
+    /// #        id -> Integer,
+    /// #        name -> VarChar,
+    /// #        address -> Jsonb,
+    /// #    }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    ///
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::contacts::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS contacts").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE contacts (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         address JSONB NOT NULL
+    /// #     )").execute(conn)
+    /// #        .unwrap();
+    /// #
+    /// let santas_address: serde_json::Value = serde_json::json!({
+    ///     "street": "Article Circle Expressway 1",
+    ///     "city": "North Pole",
+    ///     "postcode": "99705",
+    ///     "state": "Alaska"
+    /// });
+    /// diesel::insert_into(contacts)
+    ///     .values((name.eq("Claus"), address.eq(&santas_address)))
+    ///     .execute(conn)?;
+    ///
+    /// let santas_postcode = contacts.select(address.retrieve_as_object("postcode")).get_result::<serde_json::Value>(conn)?;
+    /// assert_eq!(santas_postcode, serde_json::json!("99705"));
+    ///
+    ///
+    /// let robert_downey_jr_addresses: serde_json::Value = serde_json::json!([
+    ///     {
+    ///         "street": "Somewhere In La 251",
+    ///         "city": "Los Angeles",
+    ///         "postcode": "12231223",
+    ///         "state": "California"
+    ///     },
+    ///     {
+    ///         "street": "Somewhere In Ny 251",
+    ///         "city": "New York",
+    ///         "postcode": "3213212",
+    ///         "state": "New York"
+    ///     }
+    /// ]);
+    ///
+    /// diesel::insert_into(contacts)
+    ///     .values((name.eq("Robert Downey Jr."), address.eq(&robert_downey_jr_addresses)))
+    ///     .execute(conn)?;
+    ///
+    /// let roberts_second_address_in_db = contacts
+    ///                             .filter(name.eq("Robert Downey Jr."))
+    ///                             .select(address.retrieve_as_object(1))
+    ///                             .get_result::<serde_json::Value>(conn)?;
+    ///
+    /// let roberts_second_address = serde_json::json!({
+    ///         "street": "Somewhere In Ny 251",
+    ///         "city": "New York",
+    ///         "postcode": "3213212",
+    ///         "state": "New York"
+    /// });
+    /// assert_eq!(roberts_second_address, roberts_second_address_in_db);
+    /// #     Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "serde_json"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn retrieve_as_object<T>(
+        self,
+        other: T,
+    ) -> crate::pg::expression::helper_types::RetrieveAsObject<Self, T>
+    where
+        T: JsonIndex,
+        <T::Expression as Expression>::SqlType: SqlType,
+    {
+        Grouped(RetrieveAsObjectJson::new(
+            self,
+            other.into_json_index_expression(),
+        ))
+    }
+
+    /// Creates a PostgreSQL `#>` expression.
+    ///
+    /// This operator extracts the value associated with the given key, that is provided on the
+    /// Right Hand Side of the operator.
+    ///
+    /// Extracts n'th element of JSON array (array elements are indexed from zero, but negative integers count from the end).
+    /// Extracts JSON object field with the given key.
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #    contacts {
+    /// #        id -> Integer,
+    /// #        name -> VarChar,
+    /// #        address -> Jsonb,
+    /// #    }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    ///
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::contacts::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS contacts").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE contacts (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         address JSONB NOT NULL
+    /// #     )").execute(conn)
+    /// #        .unwrap();
+    /// #
+    /// let robert_downey_jr_addresses: serde_json::Value = serde_json::json!([
+    ///     {
+    ///         "street": "Somewhere In La 251",
+    ///         "city": "Los Angeles",
+    ///         "postcode": "12231223",
+    ///         "state": "California"
+    ///     },
+    ///     {
+    ///         "street": "Somewhere In Ny 251",
+    ///         "city": "New York",
+    ///         "postcode": "3213212",
+    ///         "state": "New York"
+    ///     }
+    /// ]);
+    ///
+    /// diesel::insert_into(contacts)
+    ///     .values((name.eq("Robert Downey Jr."), address.eq(&robert_downey_jr_addresses)))
+    ///     .execute(conn)?;
+    ///
+    /// let roberts_second_street_in_db = contacts
+    ///                             .filter(name.eq("Robert Downey Jr."))
+    ///                             .select(address.retrieve_by_path_as_object(vec!["1", "street"]))
+    ///                             .get_result::<serde_json::Value>(conn)?;
+    ///
+    /// assert_eq!(roberts_second_street_in_db, serde_json::json!("Somewhere In Ny 251"));
+    /// #     Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "serde_json"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn retrieve_by_path_as_object<T>(
+        self,
+        other: T,
+    ) -> dsl::RetrieveByPathAsObjectJson<Self, T::Expression>
+    where
+        T: AsExpression<Array<Text>>,
+    {
+        Grouped(RetrieveByPathAsObjectJson::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `#>>` expression.
+    ///
+    /// This operator extracts the value associated with the given key, that is provided on the
+    /// Right Hand Side of the operator.
+    ///
+    /// Extracts n'th element of JSON array (array elements are indexed from zero, but negative integers count from the end).
+    /// Extracts JSON object field as Text with the given key.
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #    contacts {
+    /// #        id -> Integer,
+    /// #        name -> VarChar,
+    /// #        address -> Jsonb,
+    /// #    }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    ///
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::contacts::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS contacts").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE contacts (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         address JSONB NOT NULL
+    /// #     )").execute(conn)
+    /// #        .unwrap();
+    /// #
+    /// let robert_downey_jr_addresses: serde_json::Value = serde_json::json!([
+    ///     {
+    ///         "street": "Somewhere In La 251",
+    ///         "city": "Los Angeles",
+    ///         "postcode": "12231223",
+    ///         "state": "California"
+    ///     },
+    ///     {
+    ///         "street": "Somewhere In Ny 251",
+    ///         "city": "New York",
+    ///         "postcode": "3213212",
+    ///         "state": "New York"
+    ///     }
+    /// ]);
+    ///
+    /// diesel::insert_into(contacts)
+    ///     .values((name.eq("Robert Downey Jr."), address.eq(&robert_downey_jr_addresses)))
+    ///     .execute(conn)?;
+    ///
+    /// let roberts_second_street_in_db = contacts
+    ///                             .filter(name.eq("Robert Downey Jr."))
+    ///                             .select(address.retrieve_by_path_as_text(vec!["1", "street"]))
+    ///                             .get_result::<String>(conn)?;
+    ///
+    /// assert_eq!(roberts_second_street_in_db, "Somewhere In Ny 251");
+    ///
+    /// #     Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "serde_json"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn retrieve_by_path_as_text<T>(
+        self,
+        other: T,
+    ) -> dsl::RetrieveByPathAsTextJson<Self, T::Expression>
+    where
+        T: AsExpression<Array<Text>>,
+    {
+        Grouped(RetrieveByPathAsTextJson::new(self, other.as_expression()))
+    }
+}
+
+#[doc(hidden)]
+impl<T> PgAnyJsonExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: JsonOrNullableJsonOrJsonbOrNullableJsonb,
+{
+}
+
+/// PostgreSQL specific methods present on Binary expressions.
+#[cfg(feature = "postgres_backend")]
+pub trait PgBinaryExpressionMethods: Expression + Sized {
+    /// Concatenates two PostgreSQL byte arrays using the `||` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> Binary,
+    /// #         hair_color -> Nullable<Binary>,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     use diesel::insert_into;
+    /// #
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE TABLE users (
+    /// #         id INTEGER PRIMARY KEY,
+    /// #         name BYTEA NOT NULL,
+    /// #         hair_color BYTEA
+    /// #     )").execute(connection).unwrap();
+    /// #
+    /// #     insert_into(users)
     /// #         .values(&vec![
     /// #             (id.eq(1), name.eq("Sean".as_bytes()), Some(hair_color.eq(Some("Green".as_bytes())))),
     /// #             (id.eq(2), name.eq("Tess".as_bytes()), None),
@@ -3994,20 +4472,681 @@ pub trait PgBinaryExpressionMethods: Expression + Sized {
     /// #         .execute(connection)
     /// #         .unwrap();
     /// #
-    /// let names = users.select(name.concat(" the Greatest".as_bytes())).load(connection);
-    /// let expected_names = vec![
-    ///     b"Sean the Greatest".to_vec(),
-    ///     b"Tess the Greatest".to_vec()
-    /// ];
-    /// assert_eq!(Ok(expected_names), names);
+    /// let names = users.select(name.concat(" the Greatest".as_bytes())).load(connection);
+    /// let expected_names = vec![
+    ///     b"Sean the Greatest".to_vec(),
+    ///     b"Tess the Greatest".to_vec()
+    /// ];
+    /// assert_eq!(Ok(expected_names), names);
+    ///
+    /// // If the value is nullable, the output will be nullable
+    /// let names = users.select(hair_color.concat("ish".as_bytes())).load(connection);
+    /// let expected_names = vec![
+    ///     Some(b"Greenish".to_vec()),
+    ///     None,
+    /// ];
+    /// assert_eq!(Ok(expected_names), names);
+    /// # }
+    /// ```
+    fn concat<T>(self, other: T) -> dsl::Concat<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(Concat::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL binary `LIKE` expression.
+    ///
+    /// This method is case sensitive. There is no case-insensitive
+    /// equivalent as of PostgreSQL 14.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> Binary,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     use diesel::insert_into;
+    /// #
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE TABLE users (
+    /// #         id INTEGER PRIMARY KEY,
+    /// #         name BYTEA NOT NULL
+    /// #     )").execute(connection).unwrap();
+    /// #
+    /// #     insert_into(users)
+    /// #         .values(&vec![
+    /// #             (id.eq(1), name.eq("Sean".as_bytes())),
+    /// #             (id.eq(2), name.eq("Tess".as_bytes()))
+    /// #         ])
+    /// #         .execute(connection)
+    /// #         .unwrap();
+    /// #
+    /// let starts_with_s = users
+    ///     .select(name)
+    ///     .filter(name.like(b"S%".to_vec()))
+    ///     .load(connection);
+    /// assert_eq!(Ok(vec![b"Sean".to_vec()]), starts_with_s);
+    /// # }
+    /// ```
+    fn like<T>(self, other: T) -> dsl::Like<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(Like::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL binary `LIKE` expression.
+    ///
+    /// This method is case sensitive. There is no case-insensitive
+    /// equivalent as of PostgreSQL 14.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> Binary,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     use diesel::insert_into;
+    /// #
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE TABLE users (
+    /// #         id INTEGER PRIMARY KEY,
+    /// #         name BYTEA NOT NULL
+    /// #     )").execute(connection).unwrap();
+    /// #
+    /// #     insert_into(users)
+    /// #         .values(&vec![
+    /// #             (id.eq(1), name.eq("Sean".as_bytes())),
+    /// #             (id.eq(2), name.eq("Tess".as_bytes()))
+    /// #         ])
+    /// #         .execute(connection)
+    /// #         .unwrap();
+    /// #
+    /// let starts_with_s = users
+    ///     .select(name)
+    ///     .filter(name.not_like(b"S%".to_vec()))
+    ///     .load(connection);
+    /// assert_eq!(Ok(vec![b"Tess".to_vec()]), starts_with_s);
+    /// # }
+    /// ```
+    fn not_like<T>(self, other: T) -> dsl::NotLike<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(NotLike::new(self, other.as_expression()))
+    }
+}
+
+#[doc(hidden)]
+impl<T> PgBinaryExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: BinaryOrNullableBinary,
+{
+}
+
+/// PostgreSQL specific methods present on `tsvector` expressions
+#[cfg(feature = "postgres_backend")]
+pub trait PgTsVectorExpressionMethods: Expression + Sized {
+    /// Creates a PostgreSQL `@@` expression.
+    ///
+    /// This operator returns whether the document matches the given
+    /// text search query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     posts {
+    /// #         id -> Integer,
+    /// #         body -> Text,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::posts::dsl::*;
+    /// #     use diesel::dsl::{to_tsvector, websearch_to_tsquery};
+    /// #     use diesel::sql_types::Text;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS posts").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE posts (id SERIAL PRIMARY KEY, body TEXT NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(posts)
+    ///     .values(vec![body.eq("The fat cat sat on the mat"),
+    ///                  body.eq("The fat rat ate the cheese")])
+    ///     .execute(conn)?;
+    ///
+    /// let found = posts.select(id)
+    ///     .filter(to_tsvector::<Text, _>(body).matches(websearch_to_tsquery::<Text, _>("fat rat")))
+    ///     .load::<i32>(conn)?;
+    /// assert_eq!(vec![2], found);
+    ///
+    /// let found = posts.select(id)
+    ///     .filter(to_tsvector::<Text, _>(body).matches(websearch_to_tsquery::<Text, _>("fat -rat")))
+    ///     .load::<i32>(conn)?;
+    /// assert_eq!(vec![1], found);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn matches<T>(self, query: T) -> dsl::Matches<Self, T>
+    where
+        T: AsExpression<TsQuery>,
+    {
+        Grouped(TsMatch::new(self, query.as_expression()))
+    }
+}
+
+impl<T> PgTsVectorExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: TsVectorOrNullableTsVector,
+{
+}
+
+/// PostgreSQL specific methods present on `bit` and `bit varying` expressions
+#[cfg(feature = "postgres_backend")]
+pub trait PgBitStringExpressionMethods: Expression + Sized {
+    /// Creates a PostgreSQL `&` expression.
+    ///
+    /// This operator computes the bitwise AND between two bit strings
+    /// of the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::pg::sql_types::Bit;
+    /// #     let conn = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1100_0000], 4);
+    /// let result = diesel::select(bits.into_sql::<Bit>().bit_and(PgBitString::from_bytes(vec![0b1010_0000], 4)))
+    ///     .get_result::<PgBitString>(conn)?;
+    /// assert_eq!("1000", result.to_string());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn bit_and<T>(self, other: T) -> dsl::BitAnd<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(AndBits::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `|` expression.
+    ///
+    /// This operator computes the bitwise OR between two bit strings
+    /// of the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::pg::sql_types::Bit;
+    /// #     let conn = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1100_0000], 4);
+    /// let result = diesel::select(bits.into_sql::<Bit>().bit_or(PgBitString::from_bytes(vec![0b1010_0000], 4)))
+    ///     .get_result::<PgBitString>(conn)?;
+    /// assert_eq!("1110", result.to_string());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn bit_or<T>(self, other: T) -> dsl::BitOr<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(OrBits::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `#` expression.
+    ///
+    /// This operator computes the bitwise XOR between two bit strings
+    /// of the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::pg::sql_types::Bit;
+    /// #     let conn = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1100_0000], 4);
+    /// let result = diesel::select(bits.into_sql::<Bit>().bit_xor(PgBitString::from_bytes(vec![0b1010_0000], 4)))
+    ///     .get_result::<PgBitString>(conn)?;
+    /// assert_eq!("0110", result.to_string());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn bit_xor<T>(self, other: T) -> dsl::BitXor<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(XorBits::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `<<` expression.
+    ///
+    /// This operator shifts the bits to the left by the given number of
+    /// positions, filling the string with zeros. The length of the bit string
+    /// is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::pg::sql_types::Bit;
+    /// #     let conn = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1100_0000], 4);
+    /// let result = diesel::select(bits.into_sql::<Bit>().shift_left(1))
+    ///     .get_result::<PgBitString>(conn)?;
+    /// assert_eq!("1000", result.to_string());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn shift_left<T>(self, other: T) -> dsl::ShiftLeft<Self, T>
+    where
+        T: AsExpression<Integer>,
+    {
+        Grouped(ShiftBitsLeft::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `>>` expression.
+    ///
+    /// This operator shifts the bits to the right by the given number of
+    /// positions, filling the string with zeros. The length of the bit string
+    /// is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::pg::sql_types::Bit;
+    /// #     let conn = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1100_0000], 4);
+    /// let result = diesel::select(bits.into_sql::<Bit>().shift_right(1))
+    ///     .get_result::<PgBitString>(conn)?;
+    /// assert_eq!("0110", result.to_string());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn shift_right<T>(self, other: T) -> dsl::ShiftRight<Self, T>
+    where
+        T: AsExpression<Integer>,
+    {
+        Grouped(ShiftBitsRight::new(self, other.as_expression()))
+    }
+}
+
+impl<T> PgBitStringExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: BitStringOrNullableBitString,
+{
+}
+
+/// PostgreSQL specific methods present on `ltree` expressions
+#[cfg(feature = "postgres_backend")]
+pub trait PgLtreeExpressionMethods: Expression + Sized {
+    /// Creates a PostgreSQL `@>` expression.
+    ///
+    /// This operator returns whether the left hand side is an ancestor of the
+    /// right hand side (or equal to it).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     categories {
+    /// #         id -> Integer,
+    /// #         path -> Ltree,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::categories::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS categories").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE categories (id SERIAL PRIMARY KEY, path LTREE NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(categories)
+    ///     .values(&vec![
+    ///         path.eq("Top"),
+    ///         path.eq("Top.Science"),
+    ///         path.eq("Top.Science.Astronomy"),
+    ///         path.eq("Top.Hobbies"),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let ancestors = categories
+    ///     .select(path)
+    ///     .filter(path.contains("Top.Science.Astronomy"))
+    ///     .order(id)
+    ///     .load::<String>(conn)?;
+    /// assert_eq!(vec!["Top", "Top.Science", "Top.Science.Astronomy"], ancestors);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn contains<T>(self, other: T) -> dsl::Contains<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(Contains::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `<@` expression.
+    ///
+    /// This operator returns whether the left hand side is a descendant of
+    /// the right hand side (or equal to it).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     categories {
+    /// #         id -> Integer,
+    /// #         path -> Ltree,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::categories::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS categories").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE categories (id SERIAL PRIMARY KEY, path LTREE NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(categories)
+    ///     .values(&vec![
+    ///         path.eq("Top"),
+    ///         path.eq("Top.Science"),
+    ///         path.eq("Top.Science.Astronomy"),
+    ///         path.eq("Top.Hobbies"),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let science = categories
+    ///     .select(path)
+    ///     .filter(path.is_contained_by("Top.Science"))
+    ///     .order(id)
+    ///     .load::<String>(conn)?;
+    /// assert_eq!(vec!["Top.Science", "Top.Science.Astronomy"], science);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::wrong_self_convention)] // This is named after the sql operator
+    fn is_contained_by<T>(self, other: T) -> dsl::IsContainedBy<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(IsContainedBy::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `~` expression.
+    ///
+    /// This operator returns whether the path matches the given `lquery`
+    /// pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     categories {
+    /// #         id -> Integer,
+    /// #         path -> Ltree,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::categories::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS categories").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE categories (id SERIAL PRIMARY KEY, path LTREE NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(categories)
+    ///     .values(&vec![
+    ///         path.eq("Top"),
+    ///         path.eq("Top.Science"),
+    ///         path.eq("Top.Science.Astronomy"),
+    ///         path.eq("Top.Hobbies.Amateurs_Astronomy"),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let astronomy = categories
+    ///     .select(path)
+    ///     .filter(path.matches_lquery("*.Astronomy|Amateurs_Astronomy"))
+    ///     .order(id)
+    ///     .load::<String>(conn)?;
+    /// assert_eq!(
+    ///     vec!["Top.Science.Astronomy", "Top.Hobbies.Amateurs_Astronomy"],
+    ///     astronomy
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn matches_lquery<T>(self, query: T) -> dsl::MatchesLquery<Self, T>
+    where
+        T: AsExpression<Lquery>,
+    {
+        Grouped(MatchesLquery::new(self, query.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `?` expression.
+    ///
+    /// This operator returns whether the path matches any of the given
+    /// `lquery` patterns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     categories {
+    /// #         id -> Integer,
+    /// #         path -> Ltree,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::categories::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS categories").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE categories (id SERIAL PRIMARY KEY, path LTREE NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(categories)
+    ///     .values(&vec![
+    ///         path.eq("Top"),
+    ///         path.eq("Top.Science"),
+    ///         path.eq("Top.Science.Astronomy"),
+    ///         path.eq("Top.Hobbies"),
+    ///     ])
+    ///     .execute(conn)?;
     ///
-    /// // If the value is nullable, the output will be nullable
-    /// let names = users.select(hair_color.concat("ish".as_bytes())).load(connection);
-    /// let expected_names = vec![
-    ///     Some(b"Greenish".to_vec()),
-    ///     None,
-    /// ];
-    /// assert_eq!(Ok(expected_names), names);
+    /// let found = categories
+    ///     .select(path)
+    ///     .filter(path.matches_any_lquery(vec!["Top", "*.Hobbies"]))
+    ///     .order(id)
+    ///     .load::<String>(conn)?;
+    /// assert_eq!(vec!["Top", "Top.Hobbies"], found);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn matches_any_lquery<T>(self, queries: T) -> dsl::MatchesAnyLquery<Self, T>
+    where
+        T: AsExpression<Array<Lquery>>,
+    {
+        Grouped(MatchesAnyLquery::new(self, queries.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `@` expression.
+    ///
+    /// This operator returns whether the path matches the given `ltxtquery`
+    /// full text search like pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     categories {
+    /// #         id -> Integer,
+    /// #         path -> Ltree,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::categories::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS categories").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE categories (id SERIAL PRIMARY KEY, path LTREE NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(categories)
+    ///     .values(&vec![
+    ///         path.eq("Top"),
+    ///         path.eq("Top.Science"),
+    ///         path.eq("Top.Science.Astronomy"),
+    ///         path.eq("Top.Hobbies.Amateurs_Astronomy"),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let found = categories
+    ///     .select(path)
+    ///     .filter(path.matches_ltxtquery("Astronomy% & !Science"))
+    ///     .load::<String>(conn)?;
+    /// assert_eq!(vec!["Top.Hobbies.Amateurs_Astronomy"], found);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn matches_ltxtquery<T>(self, query: T) -> dsl::MatchesLtxtquery<Self, T>
+    where
+        T: AsExpression<Ltxtquery>,
+    {
+        Grouped(MatchesLtxtquery::new(self, query.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `||` expression.
+    ///
+    /// This operator concatenates two paths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     categories {
+    /// #         id -> Integer,
+    /// #         path -> Ltree,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::categories::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(conn).unwrap();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS categories").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE categories (id SERIAL PRIMARY KEY, path LTREE NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(categories)
+    ///     .values(path.eq("Top.Science"))
+    ///     .execute(conn)?;
+    ///
+    /// let child = categories
+    ///     .select(path.concat("Astronomy"))
+    ///     .get_result::<String>(conn)?;
+    /// assert_eq!("Top.Science.Astronomy", child);
+    /// #     Ok(())
     /// # }
     /// ```
     fn concat<T>(self, other: T) -> dsl::Concat<Self, T>
@@ -4017,120 +5156,198 @@ pub trait PgBinaryExpressionMethods: Expression + Sized {
     {
         Grouped(Concat::new(self, other.as_expression()))
     }
+}
 
-    /// Creates a PostgreSQL binary `LIKE` expression.
+impl<T> PgLtreeExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: LtreeOrNullableLtree,
+{
+}
+
+/// PostgreSQL specific methods present on the `vector`, `halfvec` and
+/// `sparsevec` expressions of the `pgvector` extension
+///
+/// The distances returned by these methods can be used in `order_by` to
+/// query the nearest neighbors of a vector, which allows PostgreSQL to use
+/// an index on the vector column.
+#[cfg(feature = "postgres_backend")]
+pub trait PgVectorExpressionMethods: Expression + Sized {
+    /// Creates a PostgreSQL `<->` expression.
     ///
-    /// This method is case sensitive. There is no case-insensitive
-    /// equivalent as of PostgreSQL 14.
+    /// This operator returns the euclidean distance between two vectors.
     ///
-    /// # Examples
+    /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
-    /// #     users {
+    /// #     items {
     /// #         id -> Integer,
-    /// #         name -> Binary,
+    /// #         embedding -> diesel::pg::sql_types::Vector,
     /// #     }
     /// # }
     /// #
     /// # fn main() {
-    /// #     use self::users::dsl::*;
-    /// #     use diesel::insert_into;
-    /// #
-    /// #     let connection = &mut connection_no_data();
-    /// #     diesel::sql_query("CREATE TABLE users (
-    /// #         id INTEGER PRIMARY KEY,
-    /// #         name BYTEA NOT NULL
-    /// #     )").execute(connection).unwrap();
-    /// #
-    /// #     insert_into(users)
-    /// #         .values(&vec![
-    /// #             (id.eq(1), name.eq("Sean".as_bytes())),
-    /// #             (id.eq(2), name.eq("Tess".as_bytes()))
-    /// #         ])
-    /// #         .execute(connection)
-    /// #         .unwrap();
+    /// #     run_test().unwrap();
+    /// # }
     /// #
-    /// let starts_with_s = users
-    ///     .select(name)
-    ///     .filter(name.like(b"S%".to_vec()))
-    ///     .load(connection);
-    /// assert_eq!(Ok(vec![b"Sean".to_vec()]), starts_with_s);
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::items::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS vector").execute(conn)?;
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS items").execute(conn)?;
+    /// #     diesel::sql_query("CREATE TABLE items (id SERIAL PRIMARY KEY, embedding VECTOR(3) NOT NULL)").execute(conn)?;
+    /// diesel::insert_into(items)
+    ///     .values(&vec![
+    ///         embedding.eq(vec![1.0, 1.0, 1.0]),
+    ///         embedding.eq(vec![2.0, 2.0, 2.0]),
+    ///         embedding.eq(vec![1.0, 1.0, 2.0]),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let nearest = items
+    ///     .select(id)
+    ///     .order(embedding.l2_distance(vec![1.0, 1.0, 1.8]))
+    ///     .limit(2)
+    ///     .load::<i32>(conn)?;
+    /// assert_eq!(vec![3, 1], nearest);
+    ///
+    /// let distance = items
+    ///     .select(embedding.l2_distance(vec![1.0, 1.0, 4.0]))
+    ///     .filter(id.eq(3))
+    ///     .get_result::<f64>(conn)?;
+    /// assert_eq!(2.0, distance);
+    /// #     Ok(())
     /// # }
     /// ```
-    fn like<T>(self, other: T) -> dsl::Like<Self, T>
+    fn l2_distance<T>(self, other: T) -> dsl::L2Distance<Self, T>
     where
         Self::SqlType: SqlType,
         T: AsExpression<Self::SqlType>,
     {
-        Grouped(Like::new(self, other.as_expression()))
+        Grouped(Distance::new(self, other.as_expression()))
     }
 
-    /// Creates a PostgreSQL binary `LIKE` expression.
+    /// Creates a PostgreSQL `<=>` expression.
     ///
-    /// This method is case sensitive. There is no case-insensitive
-    /// equivalent as of PostgreSQL 14.
+    /// This operator returns the cosine distance between two vectors.
     ///
-    /// # Examples
+    /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
-    /// #     users {
+    /// #     items {
     /// #         id -> Integer,
-    /// #         name -> Binary,
+    /// #         embedding -> diesel::pg::sql_types::Vector,
     /// #     }
     /// # }
     /// #
     /// # fn main() {
-    /// #     use self::users::dsl::*;
-    /// #     use diesel::insert_into;
+    /// #     run_test().unwrap();
+    /// # }
     /// #
-    /// #     let connection = &mut connection_no_data();
-    /// #     diesel::sql_query("CREATE TABLE users (
-    /// #         id INTEGER PRIMARY KEY,
-    /// #         name BYTEA NOT NULL
-    /// #     )").execute(connection).unwrap();
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::items::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS vector").execute(conn)?;
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS items").execute(conn)?;
+    /// #     diesel::sql_query("CREATE TABLE items (id SERIAL PRIMARY KEY, embedding VECTOR(3) NOT NULL)").execute(conn)?;
+    /// diesel::insert_into(items)
+    ///     .values(&vec![
+    ///         embedding.eq(vec![1.0, 1.0, 1.0]),
+    ///         embedding.eq(vec![2.0, 2.0, 2.0]),
+    ///         embedding.eq(vec![1.0, 1.0, 2.0]),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let most_similar = items
+    ///     .select(id)
+    ///     .order((embedding.cosine_distance(vec![3.0, 3.0, 3.0]), id))
+    ///     .load::<i32>(conn)?;
+    /// assert_eq!(vec![1, 2, 3], most_similar);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn cosine_distance<T>(self, other: T) -> dsl::CosineDistance<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(CosineDistance::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `<#>` expression.
+    ///
+    /// This operator returns the negative inner product of two vectors.
+    /// The inner product is negated so that ordering by this expression in
+    /// ascending order returns the vectors with the largest inner product
+    /// first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
     /// #
-    /// #     insert_into(users)
-    /// #         .values(&vec![
-    /// #             (id.eq(1), name.eq("Sean".as_bytes())),
-    /// #             (id.eq(2), name.eq("Tess".as_bytes()))
-    /// #         ])
-    /// #         .execute(connection)
-    /// #         .unwrap();
+    /// # table! {
+    /// #     items {
+    /// #         id -> Integer,
+    /// #         embedding -> diesel::pg::sql_types::Vector,
+    /// #     }
+    /// # }
     /// #
-    /// let starts_with_s = users
-    ///     .select(name)
-    ///     .filter(name.not_like(b"S%".to_vec()))
-    ///     .load(connection);
-    /// assert_eq!(Ok(vec![b"Tess".to_vec()]), starts_with_s);
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::items::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS vector").execute(conn)?;
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS items").execute(conn)?;
+    /// #     diesel::sql_query("CREATE TABLE items (id SERIAL PRIMARY KEY, embedding VECTOR(3) NOT NULL)").execute(conn)?;
+    /// diesel::insert_into(items)
+    ///     .values(&vec![
+    ///         embedding.eq(vec![1.0, 1.0, 1.0]),
+    ///         embedding.eq(vec![2.0, 2.0, 2.0]),
+    ///         embedding.eq(vec![1.0, 1.0, 2.0]),
+    ///     ])
+    ///     .execute(conn)?;
+    ///
+    /// let largest_product = items
+    ///     .select((id, embedding.max_inner_product(vec![1.0, 1.0, 1.0])))
+    ///     .order(embedding.max_inner_product(vec![1.0, 1.0, 1.0]))
+    ///     .load::<(i32, f64)>(conn)?;
+    /// assert_eq!(vec![(2, -6.0), (3, -4.0), (1, -3.0)], largest_product);
+    /// #     Ok(())
     /// # }
     /// ```
-    fn not_like<T>(self, other: T) -> dsl::NotLike<Self, T>
+    fn max_inner_product<T>(self, other: T) -> dsl::MaxInnerProduct<Self, T>
     where
         Self::SqlType: SqlType,
         T: AsExpression<Self::SqlType>,
     {
-        Grouped(NotLike::new(self, other.as_expression()))
+        Grouped(NegativeInnerProduct::new(self, other.as_expression()))
     }
 }
 
-#[doc(hidden)]
-impl<T> PgBinaryExpressionMethods for T
+#[cfg(feature = "postgres_backend")]
+impl<T> PgVectorExpressionMethods for T
 where
     T: Expression,
-    T::SqlType: BinaryOrNullableBinary,
+    T::SqlType: VectorOrNullableVector,
 {
 }
 
 pub(in crate::pg) mod private {
+    use crate::pg::sql_types::{Bit, HalfVec, Point, Polygon, SparseVec, Vector};
     use crate::sql_types::{
-        AllAreNullable, Array, Binary, Cidr, Inet, Integer, Json, Jsonb, MaybeNullableType,
-        Multirange, Nullable, Range, Record, SingleValue, SqlType, Text,
+        AllAreNullable, Array, Binary, Cidr, Circle, GeometricBox, Hstore, Inet, Integer, Json,
+        Jsonb, Line, LineSegment, Ltree, MaybeNullableType, Multirange, Nullable, Path, Range,
+        Record, SingleValue, SqlType, Text, TsQuery, TsVector, Varbit,
     };
     use crate::{Expression, IntoSql};
 
@@ -4188,6 +5405,129 @@ pub(in crate::pg) mod private {
     impl TextOrNullableText for Text {}
     impl TextOrNullableText for Nullable<Text> {}
 
+    /// Marker trait used to implement `PgTsVectorExpressionMethods` on the appropriate
+    /// types.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::TsVector` nor `diesel::sql_types::Nullable<TsVector>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait TsVectorOrNullableTsVector {}
+
+    impl TsVectorOrNullableTsVector for TsVector {}
+    impl TsVectorOrNullableTsVector for Nullable<TsVector> {}
+
+    /// Marker trait used to restrict the text search functions to `tsquery` expressions
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::TsQuery` nor `diesel::sql_types::Nullable<TsQuery>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait TsQueryOrNullableTsQuery {}
+
+    impl TsQueryOrNullableTsQuery for TsQuery {}
+    impl TsQueryOrNullableTsQuery for Nullable<TsQuery> {}
+
+    /// Marker trait used to implement `PgHstoreExpressionMethods` on the appropriate
+    /// types.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Hstore` nor `diesel::sql_types::Nullable<Hstore>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait HstoreOrNullableHstore {}
+
+    impl HstoreOrNullableHstore for Hstore {}
+    impl HstoreOrNullableHstore for Nullable<Hstore> {}
+
+    /// Marker trait used to implement `PgLtreeExpressionMethods` on the appropriate
+    /// types.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Ltree` nor `diesel::sql_types::Nullable<Ltree>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait LtreeOrNullableLtree {}
+
+    impl LtreeOrNullableLtree for Ltree {}
+    impl LtreeOrNullableLtree for Nullable<Ltree> {}
+
+    /// Marker trait used to implement `PgVectorExpressionMethods` on the appropriate
+    /// types.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a vector sql type like `diesel::pg::sql_types::Vector` or `diesel::sql_types::Nullable<Vector>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait VectorOrNullableVector {}
+
+    impl VectorOrNullableVector for Vector {}
+    impl VectorOrNullableVector for HalfVec {}
+    impl VectorOrNullableVector for SparseVec {}
+    impl VectorOrNullableVector for Nullable<Vector> {}
+    impl VectorOrNullableVector for Nullable<HalfVec> {}
+    impl VectorOrNullableVector for Nullable<SparseVec> {}
+
+    /// Marker trait used to implement `PgGeometricExpressionMethods` on the appropriate
+    /// types.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a geometric sql type like `diesel::pg::sql_types::Point` or `diesel::sql_types::Nullable<Point>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait GeometricOrNullableGeometric {}
+
+    impl GeometricOrNullableGeometric for Point {}
+    impl GeometricOrNullableGeometric for Line {}
+    impl GeometricOrNullableGeometric for LineSegment {}
+    impl GeometricOrNullableGeometric for GeometricBox {}
+    impl GeometricOrNullableGeometric for Path {}
+    impl GeometricOrNullableGeometric for Polygon {}
+    impl GeometricOrNullableGeometric for Circle {}
+    impl GeometricOrNullableGeometric for Nullable<Point> {}
+    impl GeometricOrNullableGeometric for Nullable<Line> {}
+    impl GeometricOrNullableGeometric for Nullable<LineSegment> {}
+    impl GeometricOrNullableGeometric for Nullable<GeometricBox> {}
+    impl GeometricOrNullableGeometric for Nullable<Path> {}
+    impl GeometricOrNullableGeometric for Nullable<Polygon> {}
+    impl GeometricOrNullableGeometric for Nullable<Circle> {}
+
+    /// Marker trait for the geometric types enclosing an area
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::pg::sql_types::GeometricBox`, `diesel::pg::sql_types::Polygon` nor `diesel::pg::sql_types::Circle`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait GeometricArea: SqlType {}
+
+    impl GeometricArea for GeometricBox {}
+    impl GeometricArea for Polygon {}
+    impl GeometricArea for Circle {}
+    impl GeometricArea for Nullable<GeometricBox> {}
+    impl GeometricArea for Nullable<Polygon> {}
+    impl GeometricArea for Nullable<Circle> {}
+
+    /// Marker trait for the geometric types supporting the `?#` operator
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Line`, `diesel::sql_types::LineSegment`, `diesel::sql_types::GeometricBox` nor `diesel::sql_types::Path`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait IntersectableGeometric: SqlType {}
+
+    impl IntersectableGeometric for Line {}
+    impl IntersectableGeometric for LineSegment {}
+    impl IntersectableGeometric for GeometricBox {}
+    impl IntersectableGeometric for Path {}
+    impl IntersectableGeometric for Nullable<Line> {}
+    impl IntersectableGeometric for Nullable<LineSegment> {}
+    impl IntersectableGeometric for Nullable<GeometricBox> {}
+    impl IntersectableGeometric for Nullable<Path> {}
+
+    /// Marker trait used to implement `PgBitStringExpressionMethods` on the appropriate types
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::pg::sql_types::Bit`, `diesel::pg::sql_types::Varbit`, `diesel::sql_types::Nullable<Bit>` nor `diesel::sql_types::Nullable<Varbit>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait BitStringOrNullableBitString {}
+
+    impl BitStringOrNullableBitString for Bit {}
+    impl BitStringOrNullableBitString for Varbit {}
+    impl BitStringOrNullableBitString for Nullable<Bit> {}
+    impl BitStringOrNullableBitString for Nullable<Varbit> {}
+
     /// Marker trait used to extract the inner type
     /// of our `Range<T>` and `Multirange<T>` sql type, used to implement `PgRangeExpressionMethods`
     pub trait RangeOrMultirange: SqlType + SingleValue {
@@ -3,9 +3,11 @@
 use super::expression_methods::InetOrCidr;
 use crate::expression::functions::declare_sql_function;
 use crate::pg::expression::expression_methods::ArrayOrNullableArray;
+use crate::pg::expression::expression_methods::BitStringOrNullableBitString;
 use crate::pg::expression::expression_methods::CombinedAllNullableValue;
 use crate::pg::expression::expression_methods::JsonOrNullableJson;
 use crate::pg::expression::expression_methods::JsonbOrNullableJsonb;
+use crate::pg::expression::expression_methods::LtreeOrNullableLtree;
 use crate::pg::expression::expression_methods::MaybeNullableValue;
 use crate::pg::expression::expression_methods::MultirangeOrNullableMultirange;
 use crate::pg::expression::expression_methods::MultirangeOrRangeMaybeNullable;
@@ -13,6 +15,8 @@ use crate::pg::expression::expression_methods::RangeOrNullableRange;
 use crate::pg::expression::expression_methods::RecordOrNullableRecord;
 use crate::pg::expression::expression_methods::TextArrayOrNullableTextArray;
 use crate::pg::expression::expression_methods::TextOrNullableText;
+use crate::pg::expression::expression_methods::TsQueryOrNullableTsQuery;
+use crate::pg::expression::expression_methods::TsVectorOrNullableTsVector;
 use crate::sql_types::helper::CombinedNullableValue;
 use crate::sql_types::*;
 
@@ -3254,6 +3258,1201 @@ extern "SQL" {
         json: J,
         text: T,
     ) -> Nullable<Text>;
+
+    /// Converts a document to a `tsvector`, using the `default_text_search_config`
+    /// of the connection.
+    ///
+    /// See [`to_tsvector_with_config`](to_tsvector_with_config()) to use a specific text search configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::to_tsvector;
+    /// #     use diesel::sql_types::{Nullable, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let vector = diesel::select(to_tsvector::<Text, _>("fat cat sat").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'cat':2 'fat':1 'sat':3", vector);
+    ///
+    /// let vector = diesel::select(to_tsvector::<Nullable<Text>, _>(None::<String>).cast::<Nullable<Text>>())
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(None, vector);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn to_tsvector<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsVector>>(
+        text: T,
+    ) -> T::Out;
+
+    /// Converts a document to a `tsvector` using the given text search configuration
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::to_tsvector_with_config;
+    /// #     use diesel::sql_types::{RegConfig, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let english = "english".into_sql::<Text>().fallible_cast::<RegConfig>();
+    /// let vector = diesel::select(to_tsvector_with_config::<Text, _, _>(english, "The fat rats").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat':2 'rat':3", vector);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "to_tsvector"]
+    fn to_tsvector_with_config<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsVector>>(
+        config: RegConfig,
+        text: T,
+    ) -> T::Out;
+
+    /// Converts a query written in the `tsquery` syntax to a `tsquery`, normalizing
+    /// the lexemes with the `default_text_search_config` of the connection
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::to_tsquery;
+    /// #     use diesel::sql_types::{Text};
+    /// #     let connection = &mut establish_connection();
+    /// let query = diesel::select(to_tsquery::<Text, _>("fat & (rat | cat)").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat' & ( 'rat' | 'cat' )", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn to_tsquery<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>>(
+        text: T,
+    ) -> T::Out;
+
+    /// Converts a query written in the `tsquery` syntax to a `tsquery` using the
+    /// given text search configuration
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::to_tsquery_with_config;
+    /// #     use diesel::sql_types::{RegConfig, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let english = "english".into_sql::<Text>().fallible_cast::<RegConfig>();
+    /// let query = diesel::select(to_tsquery_with_config::<Text, _, _>(english, "fat & !rats").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat' & !'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "to_tsquery"]
+    fn to_tsquery_with_config<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>>(
+        config: RegConfig,
+        text: T,
+    ) -> T::Out;
+
+    /// Converts unformatted text to a `tsquery` matching all of its words,
+    /// using the `default_text_search_config` of the connection
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::plainto_tsquery;
+    /// #     use diesel::sql_types::{Text};
+    /// #     let connection = &mut establish_connection();
+    /// let query = diesel::select(plainto_tsquery::<Text, _>("Fat Rat").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat' & 'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn plainto_tsquery<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>>(
+        text: T,
+    ) -> T::Out;
+
+    /// Converts unformatted text to a `tsquery` matching all of its words, using
+    /// the given text search configuration
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::plainto_tsquery_with_config;
+    /// #     use diesel::sql_types::{RegConfig, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let english = "english".into_sql::<Text>().fallible_cast::<RegConfig>();
+    /// let query = diesel::select(plainto_tsquery_with_config::<Text, _, _>(english, "The Fat Rats").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat' & 'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "plainto_tsquery"]
+    fn plainto_tsquery_with_config<
+        T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>,
+    >(
+        config: RegConfig,
+        text: T,
+    ) -> T::Out;
+
+    /// Converts unformatted text to a `tsquery` matching its words as phrase,
+    /// using the `default_text_search_config` of the connection
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::phraseto_tsquery;
+    /// #     use diesel::sql_types::{Text};
+    /// #     let connection = &mut establish_connection();
+    /// let query = diesel::select(phraseto_tsquery::<Text, _>("Fat Rat").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat' <-> 'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn phraseto_tsquery<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>>(
+        text: T,
+    ) -> T::Out;
+
+    /// Converts unformatted text to a `tsquery` matching its words as phrase,
+    /// using the given text search configuration
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::phraseto_tsquery_with_config;
+    /// #     use diesel::sql_types::{RegConfig, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let english = "english".into_sql::<Text>().fallible_cast::<RegConfig>();
+    /// let query = diesel::select(phraseto_tsquery_with_config::<Text, _, _>(english, "The Cats ate the Rats").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'cat' <-> 'ate' <2> 'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "phraseto_tsquery"]
+    fn phraseto_tsquery_with_config<
+        T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>,
+    >(
+        config: RegConfig,
+        text: T,
+    ) -> T::Out;
+
+    /// Converts a query written in the syntax of web search engines to a `tsquery`,
+    /// using the `default_text_search_config` of the connection
+    ///
+    /// Unquoted words are combined with `&`, quoted text becomes a phrase, `or`
+    /// combines with `|` and `-` negates a word. Unlike [`to_tsquery`](to_tsquery()) this never
+    /// raises an error for user provided input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::websearch_to_tsquery;
+    /// #     use diesel::sql_types::{Text};
+    /// #     let connection = &mut establish_connection();
+    /// let query = diesel::select(websearch_to_tsquery::<Text, _>("\"sad cat\" or \"fat rat\"").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'sad' <-> 'cat' | 'fat' <-> 'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn websearch_to_tsquery<T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>>(
+        text: T,
+    ) -> T::Out;
+
+    /// Converts a query written in the syntax of web search engines to a `tsquery`,
+    /// using the given text search configuration
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::websearch_to_tsquery_with_config;
+    /// #     use diesel::sql_types::{RegConfig, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let english = "english".into_sql::<Text>().fallible_cast::<RegConfig>();
+    /// let query = diesel::select(websearch_to_tsquery_with_config::<Text, _, _>(english, "fat -rats").cast::<Text>())
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("'fat' & !'rat'", query);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "websearch_to_tsquery"]
+    fn websearch_to_tsquery_with_config<
+        T: TextOrNullableText + SingleValue + MaybeNullableValue<TsQuery>,
+    >(
+        config: RegConfig,
+        text: T,
+    ) -> T::Out;
+
+    /// Ranks how well a document matches a query, based on the frequency of
+    /// the matching lexemes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{to_tsquery, to_tsvector, ts_rank};
+    /// #     use diesel::sql_types::{Text, TsQuery, TsVector};
+    /// #     let connection = &mut establish_connection();
+    /// let rank = diesel::select(ts_rank::<TsVector, TsQuery, _, _>(
+    ///     to_tsvector::<Text, _>("The fat cat sat on the fat rat"),
+    ///     to_tsquery::<Text, _>("fat"),
+    /// ))
+    /// .get_result::<f32>(connection)?;
+    /// assert!(rank > 0.0);
+    ///
+    /// let rank = diesel::select(ts_rank::<TsVector, TsQuery, _, _>(
+    ///     to_tsvector::<Text, _>("The fat cat"),
+    ///     to_tsquery::<Text, _>("dog"),
+    /// ))
+    /// .get_result::<f32>(connection)?;
+    /// assert_eq!(0.0, rank);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn ts_rank<
+        V: TsVectorOrNullableTsVector + SingleValue,
+        Q: TsQueryOrNullableTsQuery + SingleValue + CombinedNullableValue<V, Float>,
+    >(
+        vector: V,
+        query: Q,
+    ) -> Q::Out;
+
+    /// Returns an excerpt of a document with the terms matching the query
+    /// highlighted, by default by wrapping them in `<b>` and `</b>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{to_tsquery, ts_headline};
+    /// #     use diesel::sql_types::{Text, TsQuery};
+    /// #     let connection = &mut establish_connection();
+    /// let headline = diesel::select(ts_headline::<Text, TsQuery, _, _>(
+    ///     "The fat cat sat on the mat",
+    ///     to_tsquery::<Text, _>("cat"),
+    /// ))
+    /// .get_result::<String>(connection)?;
+    /// assert_eq!("The fat <b>cat</b> sat on the mat", headline);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn ts_headline<
+        T: TextOrNullableText + SingleValue,
+        Q: TsQueryOrNullableTsQuery + SingleValue + CombinedNullableValue<T, Text>,
+    >(
+        document: T,
+        query: Q,
+    ) -> Q::Out;
+
+    /// Extracts the n'th bit from a bit string, counting from zero at the
+    /// leftmost bit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::get_bit;
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::pg::sql_types::Bit;
+    /// #     let connection = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1010_0000], 4);
+    /// let bit = diesel::select(get_bit::<Bit, _, _>(&bits, 0))
+    ///     .get_result::<i32>(connection)?;
+    /// assert_eq!(1, bit);
+    ///
+    /// let bit = diesel::select(get_bit::<Bit, _, _>(&bits, 1))
+    ///     .get_result::<i32>(connection)?;
+    /// assert_eq!(0, bit);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn get_bit<B: BitStringOrNullableBitString + SingleValue + MaybeNullableValue<Integer>>(
+        bits: B,
+        n: Integer,
+    ) -> B::Out;
+
+    /// Sets the n'th bit of a bit string to the given value, counting from
+    /// zero at the leftmost bit
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::set_bit;
+    /// #     use diesel::pg::data_types::PgBitString;
+    /// #     use diesel::sql_types::Varbit;
+    /// #     let connection = &mut establish_connection();
+    /// let bits = PgBitString::from_bytes(vec![0b1010_0000], 4);
+    /// let bits = diesel::select(set_bit::<Varbit, _, _, _>(&bits, 3, 1))
+    ///     .get_result::<PgBitString>(connection)?;
+    /// assert_eq!("1011", bits.to_string());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn set_bit<B: BitStringOrNullableBitString + SingleValue>(
+        bits: B,
+        n: Integer,
+        value: Integer,
+    ) -> B;
+
+    /// Returns the subpath of an `ltree` from position `start` up to, but
+    /// not including, position `end`, counting from zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::subltree;
+    /// #     use diesel::sql_types::Ltree;
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let path = diesel::select(subltree::<Ltree, _, _, _>("Top.Child1.Child2", 1, 2))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("Child1", path);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn subltree<L: LtreeOrNullableLtree + SingleValue>(ltree: L, start: Integer, end: Integer)
+    -> L;
+
+    /// Returns the subpath of an `ltree` starting at position `offset`,
+    /// counting from zero
+    ///
+    /// If `offset` is negative, the subpath starts that far from the end of
+    /// the path.
+    ///
+    /// See [`subpath_with_length`](subpath_with_length()) to limit the length
+    /// of the subpath.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::subpath;
+    /// #     use diesel::sql_types::Ltree;
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let path = diesel::select(subpath::<Ltree, _, _>("Top.Child1.Child2", 1))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("Child1.Child2", path);
+    ///
+    /// let path = diesel::select(subpath::<Ltree, _, _>("Top.Child1.Child2", -1))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("Child2", path);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn subpath<L: LtreeOrNullableLtree + SingleValue>(ltree: L, offset: Integer) -> L;
+
+    /// Returns the subpath of an `ltree` starting at position `offset` with
+    /// `len` labels, counting from zero
+    ///
+    /// If `offset` is negative, the subpath starts that far from the end of
+    /// the path. If `len` is negative, that many labels are left off the end
+    /// of the path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::subpath_with_length;
+    /// #     use diesel::sql_types::Ltree;
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let path = diesel::select(subpath_with_length::<Ltree, _, _, _>("Top.Child1.Child2", 0, 2))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("Top.Child1", path);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "subpath"]
+    fn subpath_with_length<L: LtreeOrNullableLtree + SingleValue>(
+        ltree: L,
+        offset: Integer,
+        len: Integer,
+    ) -> L;
+
+    /// Returns the number of labels in an `ltree`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::nlevel;
+    /// #     use diesel::sql_types::{Ltree, Nullable};
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let levels = diesel::select(nlevel::<Ltree, _>("Top.Child1.Child2"))
+    ///     .get_result::<i32>(connection)?;
+    /// assert_eq!(3, levels);
+    ///
+    /// let levels = diesel::select(nlevel::<Nullable<Ltree>, _>(None::<String>))
+    ///     .get_result::<Option<i32>>(connection)?;
+    /// assert_eq!(None, levels);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn nlevel<L: LtreeOrNullableLtree + SingleValue + MaybeNullableValue<Integer>>(
+        ltree: L,
+    ) -> L::Out;
+
+    /// Computes the longest common ancestor of the paths in an array
+    ///
+    /// Returns `NULL` if the array is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::lca;
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let ancestor = diesel::select(lca(vec!["Top.Science.Astronomy", "Top.Science.Physics"]))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("Top.Science".to_owned()), ancestor);
+    ///
+    /// let ancestor = diesel::select(lca(Vec::<String>::new()))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(None, ancestor);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn lca(ltrees: Array<Ltree>) -> Nullable<Ltree>;
+
+    /// Converts text to an `ltree`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{nlevel, text2ltree};
+    /// #     use diesel::sql_types::{Ltree, Text};
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let levels = diesel::select(nlevel::<Ltree, _>(text2ltree::<Text, _>("Top.Science")))
+    ///     .get_result::<i32>(connection)?;
+    /// assert_eq!(2, levels);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn text2ltree<T: TextOrNullableText + SingleValue + MaybeNullableValue<Ltree>>(
+        text: T,
+    ) -> T::Out;
+
+    /// Converts an `ltree` to text
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::ltree2text;
+    /// #     use diesel::sql_types::Ltree;
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS ltree").execute(connection)?;
+    /// let text = diesel::select(ltree2text::<Ltree, _>("Top.Science"))
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("Top.Science", text);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn ltree2text<L: LtreeOrNullableLtree + SingleValue + MaybeNullableValue<Text>>(
+        ltree: L,
+    ) -> L::Out;
+
+    /// Returns the integers from `start` to `stop`, both inclusive
+    ///
+    /// This is a set-returning function, which is used as the source of a
+    /// query. Each value is returned as a separate row of the
+    /// [`generate_series::value`] column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::generate_series;
+    /// #     use schema::users;
+    /// #     let connection = &mut establish_connection();
+    /// let values = generate_series(1, 4).load::<i32>(connection)?;
+    /// assert_eq!(vec![1, 2, 3, 4], values);
+    ///
+    /// let values = generate_series(1, 10)
+    ///     .filter(generate_series::value.gt(7))
+    ///     .order(generate_series::value.desc())
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![10, 9, 8], values);
+    ///
+    /// let values = users::table
+    ///     .inner_join(generate_series(1, 2).on(generate_series::value.le(users::id)))
+    ///     .select((users::name, generate_series::value))
+    ///     .order((users::id, generate_series::value))
+    ///     .load::<(String, i32)>(connection)?;
+    /// let expected = vec![
+    ///     ("Sean".to_string(), 1),
+    ///     ("Tess".to_string(), 1),
+    ///     ("Tess".to_string(), 2),
+    /// ];
+    /// assert_eq!(expected, values);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[table_function(columns(value))]
+    fn generate_series(start: Integer, stop: Integer) -> Integer;
+
+    /// Returns the integers from `start` to `stop`, incrementing by `step`
+    ///
+    /// This is a set-returning function, which is used as the source of a
+    /// query. Each value is returned as a separate row of the
+    /// [`generate_series_with_step::value`] column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::generate_series_with_step;
+    /// #     let connection = &mut establish_connection();
+    /// let values = generate_series_with_step(10, 1, -3).load::<i32>(connection)?;
+    /// assert_eq!(vec![10, 7, 4, 1], values);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "generate_series"]
+    #[table_function(columns(value))]
+    fn generate_series_with_step(start: Integer, stop: Integer, step: Integer) -> Integer;
+
+    /// Expands an array into a set of rows
+    ///
+    /// This is a set-returning function, which is used as the source of a
+    /// query. Each element is returned as a separate row of the
+    /// [`unnest::value`] column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::unnest;
+    /// #     use diesel::sql_types::{Integer, Text};
+    /// #     let connection = &mut establish_connection();
+    /// let values = unnest::<Integer, _>(vec![3, 1, 2]).load::<i32>(connection)?;
+    /// assert_eq!(vec![3, 1, 2], values);
+    ///
+    /// let values = unnest::<Text, _>(vec!["a", "b", "c"])
+    ///     .filter(unnest::value::default().ne("b"))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["a", "c"], values);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[table_function(columns(value))]
+    fn unnest<T: SingleValue>(array: Array<T>) -> T;
+
+    /// Expands two arrays into a set of rows
+    ///
+    /// The arrays are expanded in parallel and need to be of the same length,
+    /// unless their element types are nullable. PostgreSQL pads shorter arrays
+    /// with `NULL` values, which fail to load for non-nullable element types.
+    /// A common use case of this function is inserting many rows with a single
+    /// bind parameter per column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::unnest_2;
+    /// #     use diesel::query_builder::AsQuery;
+    /// #     use diesel::sql_types::{Integer, Text};
+    /// #     use schema::users;
+    /// #     let connection = &mut establish_connection();
+    /// let new_users = unnest_2::<Integer, Text, _, _>(vec![3, 4], vec!["Jim", "Ruby"]);
+    /// diesel::insert_into(users::table)
+    ///     .values(new_users.as_query())
+    ///     .into_columns((users::id, users::name))
+    ///     .execute(connection)?;
+    ///
+    /// let names = users::table
+    ///     .select(users::name)
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess", "Jim", "Ruby"], names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "unnest"]
+    #[table_function(columns(value_1, value_2))]
+    fn unnest_2<T1: SingleValue, T2: SingleValue>(
+        array_1: Array<T1>,
+        array_2: Array<T2>,
+    ) -> (T1, T2);
+
+    /// Expands three arrays into a set of rows
+    ///
+    /// The arrays are expanded in parallel and need to be of the same length,
+    /// unless their element types are nullable. PostgreSQL pads shorter arrays
+    /// with `NULL` values, which fail to load for non-nullable element types.
+    /// A common use case of this function is inserting many rows with a single
+    /// bind parameter per column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::unnest_3;
+    /// #     use diesel::query_builder::AsQuery;
+    /// #     use diesel::sql_types::{Integer, Nullable, Text};
+    /// #     use schema::animals;
+    /// #     let connection = &mut establish_connection();
+    /// let new_animals = unnest_3::<Text, Integer, Nullable<Text>, _, _, _>(
+    ///     vec!["cat", "spider"],
+    ///     vec![4, 8],
+    ///     vec![Some("Tom"), None],
+    /// );
+    /// diesel::insert_into(animals::table)
+    ///     .values(new_animals.as_query())
+    ///     .into_columns((animals::species, animals::legs, animals::name))
+    ///     .execute(connection)?;
+    ///
+    /// let names = animals::table
+    ///     .select(animals::name)
+    ///     .filter(animals::species.ne("dog"))
+    ///     .order(animals::id)
+    ///     .load::<Option<String>>(connection)?;
+    /// assert_eq!(vec![None, Some("Tom".to_string()), None], names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "unnest"]
+    #[table_function(columns(value_1, value_2, value_3))]
+    fn unnest_3<T1: SingleValue, T2: SingleValue, T3: SingleValue>(
+        array_1: Array<T1>,
+        array_2: Array<T2>,
+        array_3: Array<T3>,
+    ) -> (T1, T2, T3);
+
+    /// Expands the top-level JSON array into a set of JSON values
+    ///
+    /// This is a set-returning function, which is used as the source of a
+    /// query. Each element is returned as a separate row of the
+    /// [`jsonb_array_elements::value`] column.
+    ///
+    /// Functions returning `record`, like `jsonb_to_recordset` or
+    /// `jsonb_to_record`, are not provided by Diesel, as the names and types of
+    /// their columns depend on the query. They need to be declared with
+    /// `define_column_types = true` instead, as shown in the
+    /// [table functions](crate::declare_sql_function#table-functions) section of
+    /// [`#[declare_sql_function]`](crate::declare_sql_function).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::jsonb_array_elements;
+    /// #     use serde_json::{json, Value};
+    /// #     let connection = &mut establish_connection();
+    /// let values = jsonb_array_elements(json!([1, "two", {"three": 3}]))
+    ///     .load::<Value>(connection)?;
+    /// assert_eq!(vec![json!(1), json!("two"), json!({"three": 3})], values);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[table_function(columns(value))]
+    fn jsonb_array_elements(jsonb: Jsonb) -> Jsonb;
+
+    /// Expands the top-level JSON object into a set of key/value pairs
+    ///
+    /// This is a set-returning function, which is used as the source of a
+    /// query. Each pair is returned as a separate row of the
+    /// [`jsonb_each::key`] and [`jsonb_each::value`] columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::jsonb_each;
+    /// #     use serde_json::{json, Value};
+    /// #     let connection = &mut establish_connection();
+    /// let pairs = jsonb_each(json!({"a": 1, "b": [true]}))
+    ///     .order(jsonb_each::key)
+    ///     .load::<(String, Value)>(connection)?;
+    /// let expected = vec![
+    ///     ("a".to_string(), json!(1)),
+    ///     ("b".to_string(), json!([true])),
+    /// ];
+    /// assert_eq!(expected, pairs);
+    ///
+    /// let keys = jsonb_each(json!({"a": 1, "b": [true]}))
+    ///     .select(jsonb_each::key)
+    ///     .filter(jsonb_each::value.eq(json!(1)))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["a"], keys);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[table_function(columns(key, value))]
+    fn jsonb_each(jsonb: Jsonb) -> (Text, Jsonb);
+
+    /// Collects all input values, including nulls, into an array
+    ///
+    /// Returns `NULL` if there are no input rows.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See
+    /// [`AggregateExpressionMethods`](crate::expression_methods::AggregateExpressionMethods)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::array_agg;
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let all_species = animals
+    ///     .select(array_agg(species).aggregate_order(species.desc()))
+    ///     .get_result::<Option<Vec<String>>>(connection)?;
+    /// assert_eq!(Some(vec!["spider".to_owned(), "dog".to_owned()]), all_species);
+    ///
+    /// let names = animals
+    ///     .select(array_agg(name).aggregate_order(id))
+    ///     .get_result::<Option<Vec<Option<String>>>>(connection)?;
+    /// assert_eq!(Some(vec![Some("Jack".to_owned()), None]), names);
+    ///
+    /// let none = animals
+    ///     .select(array_agg(species))
+    ///     .filter(legs.gt(100))
+    ///     .get_result::<Option<Vec<String>>>(connection)?;
+    /// assert_eq!(None, none);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn array_agg<T: SingleValue>(expression: T) -> Nullable<Array<T>>;
+
+    /// Concatenates the non-null input values into a string, separated by `delimiter`
+    ///
+    /// Returns `NULL` if there are no non-null input values.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See
+    /// [`AggregateExpressionMethods`](crate::expression_methods::AggregateExpressionMethods)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::string_agg;
+    /// #     use schema::posts::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let titles = posts
+    ///     .select(string_agg(title, ", ").aggregate_order(title))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(
+    ///     Some("About Rust, My first post, My first post too".to_owned()),
+    ///     titles
+    /// );
+    ///
+    /// let titles = posts
+    ///     .select(
+    ///         string_agg(title, " | ")
+    ///             .aggregate_order(id)
+    ///             .aggregate_filter(user_id.eq(1)),
+    ///     )
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("My first post | About Rust".to_owned()), titles);
+    ///
+    /// let titles_per_user = posts
+    ///     .group_by(user_id)
+    ///     .select((user_id, string_agg(title, ", ").aggregate_order(id)))
+    ///     .order(user_id)
+    ///     .load::<(i32, Option<String>)>(connection)?;
+    /// assert_eq!(
+    ///     vec![
+    ///         (1, Some("My first post, About Rust".to_owned())),
+    ///         (2, Some("My first post too".to_owned())),
+    ///     ],
+    ///     titles_per_user
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn string_agg<T: TextOrNullableText + SingleValue>(
+        expression: T,
+        delimiter: Text,
+    ) -> Nullable<Text>;
+
+    /// Collects all input values, including nulls, into a JSON array
+    ///
+    /// Returns `NULL` if there are no input rows.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See
+    /// [`AggregateExpressionMethods`](crate::expression_methods::AggregateExpressionMethods)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_agg;
+    /// #     use schema::animals::dsl::*;
+    /// #     use serde_json::{json, Value};
+    /// #     let connection = &mut establish_connection();
+    /// let names = animals
+    ///     .select(json_agg(name).aggregate_order(id))
+    ///     .get_result::<Option<Value>>(connection)?;
+    /// assert_eq!(Some(json!(["Jack", null])), names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn json_agg<T: SingleValue>(expression: T) -> Nullable<Json>;
+
+    /// Collects all input values, including nulls, into a JSONB array
+    ///
+    /// Returns `NULL` if there are no input rows.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See
+    /// [`AggregateExpressionMethods`](crate::expression_methods::AggregateExpressionMethods)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::jsonb_agg;
+    /// #     use schema::animals::dsl::*;
+    /// #     use serde_json::{json, Value};
+    /// #     let connection = &mut establish_connection();
+    /// let legs_of_named_animals = animals
+    ///     .select(jsonb_agg(legs).aggregate_filter(name.is_not_null()))
+    ///     .get_result::<Option<Value>>(connection)?;
+    /// assert_eq!(Some(json!([4])), legs_of_named_animals);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn jsonb_agg<T: SingleValue>(expression: T) -> Nullable<Jsonb>;
+
+    /// Returns true if all non-null input values are true, otherwise false
+    ///
+    /// Returns `NULL` if there are no non-null input values.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See
+    /// [`AggregateExpressionMethods`](crate::expression_methods::AggregateExpressionMethods)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::bool_and;
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let all_have_legs = animals
+    ///     .select(bool_and(legs.gt(0)))
+    ///     .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(true), all_have_legs);
+    ///
+    /// let all_are_named = animals
+    ///     .select(bool_and(name.is_not_null()))
+    ///     .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(false), all_are_named);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn bool_and<T: BoolOrNullableBool + SingleValue>(expression: T) -> Nullable<Bool>;
+
+    /// Returns true if at least one non-null input value is true, otherwise false
+    ///
+    /// Returns `NULL` if there are no non-null input values.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See
+    /// [`AggregateExpressionMethods`](crate::expression_methods::AggregateExpressionMethods)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::bool_or;
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let any_spider = animals
+    ///     .select(bool_or(species.eq("spider")))
+    ///     .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(true), any_spider);
+    ///
+    /// let any_named_spider = animals
+    ///     .select(bool_or(species.eq("spider")).aggregate_filter(name.is_not_null()))
+    ///     .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(false), any_named_spider);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn bool_or<T: BoolOrNullableBool + SingleValue>(expression: T) -> Nullable<Bool>;
 }
 
 pub(super) mod return_type_helpers_reexported {
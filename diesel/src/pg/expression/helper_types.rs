@@ -3,8 +3,9 @@ use crate::expression::Expression;
 use crate::expression::grouped::Grouped;
 use crate::expression_methods::JsonIndex;
 use crate::pg::expression::expression_methods::private::JsonRemoveIndex;
+use crate::pg::sql_types::Point;
 use crate::pg::types::sql_types::Array;
-use crate::sql_types::{Inet, Integer, Nullable, VarChar};
+use crate::sql_types::{Inet, Integer, Lquery, Ltxtquery, Nullable, Text, TsQuery, VarChar};
 
 /// The return type of [`lhs.ilike(rhs)`](super::expression_methods::PgTextExpressionMethods::ilike)
 #[cfg(feature = "postgres_backend")]
@@ -357,6 +358,81 @@ pub type RemoveByPathFromJsonb<Lhs, Rhs> =
 #[doc(hidden)] // needed for `#[auto_type]`
 pub type RemoveByPath<Lhs, Rhs> = RemoveByPathFromJsonb<Lhs, Rhs>;
 
+/// The return type of [`lhs.matches(rhs)`](super::expression_methods::PgTsVectorExpressionMethods::matches)
+#[cfg(feature = "postgres_backend")]
+pub type Matches<Lhs, Rhs> = Grouped<super::operators::TsMatch<Lhs, AsExprOf<Rhs, TsQuery>>>;
+
+/// The return type of [`lhs.retrieve_value(rhs)`](super::expression_methods::PgHstoreExpressionMethods::retrieve_value)
+#[cfg(feature = "postgres_backend")]
+pub type RetrieveValueHstore<Lhs, Rhs> =
+    Grouped<super::operators::RetrieveValueHstore<Lhs, AsExprOf<Rhs, Text>>>;
+
+#[doc(hidden)] // needed for `#[auto_type]`
+pub type RetrieveValue<Lhs, Rhs> = RetrieveValueHstore<Lhs, Rhs>;
+
+/// The return type of [`lhs.distance(rhs)`](super::expression_methods::PgGeometricExpressionMethods::distance)
+#[cfg(feature = "postgres_backend")]
+pub type Distance<Lhs, Rhs> = Grouped<super::operators::Distance<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.contains_point(rhs)`](super::expression_methods::PgGeometricExpressionMethods::contains_point)
+#[cfg(feature = "postgres_backend")]
+pub type ContainsPoint<Lhs, Rhs> = Grouped<super::operators::Contains<Lhs, AsExprOf<Rhs, Point>>>;
+
+/// The return type of [`lhs.intersects(rhs)`](super::expression_methods::PgGeometricExpressionMethods::intersects)
+#[cfg(feature = "postgres_backend")]
+pub type Intersects<Lhs, Rhs> = Grouped<super::operators::Intersects<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.bit_and(rhs)`](super::expression_methods::PgBitStringExpressionMethods::bit_and)
+#[cfg(feature = "postgres_backend")]
+pub type BitAnd<Lhs, Rhs> = Grouped<super::operators::AndBits<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.bit_or(rhs)`](super::expression_methods::PgBitStringExpressionMethods::bit_or)
+#[cfg(feature = "postgres_backend")]
+pub type BitOr<Lhs, Rhs> = Grouped<super::operators::OrBits<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.bit_xor(rhs)`](super::expression_methods::PgBitStringExpressionMethods::bit_xor)
+#[cfg(feature = "postgres_backend")]
+pub type BitXor<Lhs, Rhs> = Grouped<super::operators::XorBits<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.shift_left(rhs)`](super::expression_methods::PgBitStringExpressionMethods::shift_left)
+#[cfg(feature = "postgres_backend")]
+pub type ShiftLeft<Lhs, Rhs> =
+    Grouped<super::operators::ShiftBitsLeft<Lhs, AsExprOf<Rhs, Integer>>>;
+
+/// The return type of [`lhs.shift_right(rhs)`](super::expression_methods::PgBitStringExpressionMethods::shift_right)
+#[cfg(feature = "postgres_backend")]
+pub type ShiftRight<Lhs, Rhs> =
+    Grouped<super::operators::ShiftBitsRight<Lhs, AsExprOf<Rhs, Integer>>>;
+
+/// The return type of [`lhs.matches_lquery(rhs)`](super::expression_methods::PgLtreeExpressionMethods::matches_lquery)
+#[cfg(feature = "postgres_backend")]
+pub type MatchesLquery<Lhs, Rhs> =
+    Grouped<super::operators::MatchesLquery<Lhs, AsExprOf<Rhs, Lquery>>>;
+
+/// The return type of [`lhs.matches_any_lquery(rhs)`](super::expression_methods::PgLtreeExpressionMethods::matches_any_lquery)
+#[cfg(feature = "postgres_backend")]
+pub type MatchesAnyLquery<Lhs, Rhs> =
+    Grouped<super::operators::MatchesAnyLquery<Lhs, AsExprOf<Rhs, Array<Lquery>>>>;
+
+/// The return type of [`lhs.matches_ltxtquery(rhs)`](super::expression_methods::PgLtreeExpressionMethods::matches_ltxtquery)
+#[cfg(feature = "postgres_backend")]
+pub type MatchesLtxtquery<Lhs, Rhs> =
+    Grouped<super::operators::MatchesLtxtquery<Lhs, AsExprOf<Rhs, Ltxtquery>>>;
+
+/// The return type of [`lhs.l2_distance(rhs)`](super::expression_methods::PgVectorExpressionMethods::l2_distance)
+#[cfg(feature = "postgres_backend")]
+pub type L2Distance<Lhs, Rhs> = Grouped<super::operators::Distance<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.cosine_distance(rhs)`](super::expression_methods::PgVectorExpressionMethods::cosine_distance)
+#[cfg(feature = "postgres_backend")]
+pub type CosineDistance<Lhs, Rhs> =
+    Grouped<super::operators::CosineDistance<Lhs, AsExpr<Rhs, Lhs>>>;
+
+/// The return type of [`lhs.max_inner_product(rhs)`](super::expression_methods::PgVectorExpressionMethods::max_inner_product)
+#[cfg(feature = "postgres_backend")]
+pub type MaxInnerProduct<Lhs, Rhs> =
+    Grouped<super::operators::NegativeInnerProduct<Lhs, AsExpr<Rhs, Lhs>>>;
+
 #[doc(hidden)]
 #[deprecated(note = "Use `dsl::Concat` instead")]
 pub type ConcatBinary<Lhs, Rhs> = crate::dsl::Concat<Lhs, Rhs>;
@@ -12,6 +12,7 @@ pub mod extensions;
 pub mod functions;
 pub(crate) mod helper_types;
 pub(crate) mod operators;
+pub(crate) mod ordered_set_aggregates;
 
 mod date_and_time;
 
@@ -44,4 +45,7 @@ pub mod dsl {
 
     #[doc(inline)]
     pub use super::functions::*;
+
+    #[doc(inline)]
+    pub use super::ordered_set_aggregates::{mode, percentile_cont, percentile_disc};
 }
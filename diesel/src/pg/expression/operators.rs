@@ -10,8 +10,8 @@ use crate::sql_types::is_nullable::{
     IsOneNullable, IsSqlTypeNullable, MaybeNullable, NotNull, OneNullable,
 };
 use crate::sql_types::{
-    Array, Bigint, Bool, DieselNumericOps, Inet, Integer, Jsonb, MaybeNullableType, OneIsNullable,
-    SqlType, Text,
+    Array, Bigint, Bool, DieselNumericOps, Double, Inet, Integer, Jsonb, MaybeNullableType,
+    OneIsNullable, SqlType, Text,
 };
 use crate::{Column, QueryResult};
 
@@ -47,6 +47,35 @@ infix_operator!(HasKeyJsonb, " ? ", backend: Pg);
 infix_operator!(HasAnyKeyJsonb, " ?| ", backend: Pg);
 infix_operator!(HasAllKeysJsonb, " ?& ", backend: Pg);
 infix_operator!(RangeAdjacent, " -|- ", backend: Pg);
+infix_operator!(TsMatch, " @@ ", backend: Pg);
+infix_operator!(Distance, " <-> ", Double, backend: Pg);
+infix_operator!(Intersects, " ?# ", backend: Pg);
+infix_operator!(MatchesLquery, " ~ ", backend: Pg);
+infix_operator!(MatchesAnyLquery, " ? ", backend: Pg);
+infix_operator!(MatchesLtxtquery, " @ ", backend: Pg);
+infix_operator!(CosineDistance, " <=> ", Double, backend: Pg);
+infix_operator!(NegativeInnerProduct, " <#> ", Double, backend: Pg);
+__diesel_infix_operator!(AndBits, " & ", __diesel_internal_SameResultAsInput, backend: Pg);
+__diesel_infix_operator!(OrBits, " | ", __diesel_internal_SameResultAsInput, backend: Pg);
+__diesel_infix_operator!(XorBits, " # ", __diesel_internal_SameResultAsInput, backend: Pg);
+__diesel_infix_operator!(
+    ShiftBitsLeft,
+    " << ",
+    __diesel_internal_SameResultAsInput,
+    backend: Pg
+);
+__diesel_infix_operator!(
+    ShiftBitsRight,
+    " >> ",
+    __diesel_internal_SameResultAsInput,
+    backend: Pg
+);
+__diesel_infix_operator!(
+    RetrieveValueHstore,
+    " -> ",
+    ConstantNullability crate::sql_types::Nullable<Text>,
+    backend: Pg
+);
 infix_operator!(RemoveFromJsonb, " - ", Jsonb, backend: Pg);
 __diesel_infix_operator!(RetrieveAsObjectJson, " -> ", __diesel_internal_SameResultAsInput, backend: Pg);
 __diesel_infix_operator!(
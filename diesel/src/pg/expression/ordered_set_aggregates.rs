@@ -0,0 +1,356 @@
+//! Support for the ordered-set aggregate functions of PostgreSQL
+//!
+//! Ordered-set aggregates are called as `name(args) WITHIN GROUP (ORDER BY expr)`.
+//! The aggregated values are given by the `ORDER BY` expression, while the
+//! direct arguments (like the fraction of a percentile) are evaluated only once.
+//!
+//! Ordered-set aggregates support
+//! [`aggregate_filter`](crate::expression_methods::AggregateExpressionMethods::aggregate_filter),
+//! which adds a `FILTER (WHERE …)` clause after the `WITHIN GROUP` clause.
+//! `aggregate_distinct`, `aggregate_all` and `aggregate_order` are not
+//! supported, as PostgreSQL does not accept these modifiers for ordered-set
+//! aggregates.
+
+use self::private::{ContinuousPercentileType, OrderedSetFunction, OrderedSetReturnType};
+use crate::dsl::AsExprOf;
+use crate::expression::expression_types::NotSelectable;
+use crate::expression::functions::aggregate_expressions::aggregate_filter::{
+    Filter, FilterDsl, NoFilter,
+};
+use crate::expression::{
+    AppearsOnTable, AsExpression, Expression, SelectableExpression, ValidGrouping, is_aggregate,
+};
+use crate::pg::Pg;
+use crate::query_builder::where_clause::{NoWhereClause, WhereAnd};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{BoolOrNullableBool, Double, IntoNullable, SingleValue};
+use core::marker::PhantomData;
+
+/// Computes a continuous percentile of the values given by `order`
+///
+/// Returns a value corresponding to the specified `fraction` in the ordering,
+/// interpolating between adjacent input items if needed. `fraction` must be
+/// between 0 and 1. `order` must be a `Double` or an `Interval` expression.
+/// Null values of `order` are ignored. The function returns `NULL` if there
+/// are no non-null input values.
+///
+/// This function renders as `percentile_cont(fraction) WITHIN GROUP (ORDER BY order)`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::dsl::percentile_cont;
+/// #     use diesel::sql_types::Double;
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let median = animals
+///     .select(percentile_cont(0.5, legs.cast::<Double>()))
+///     .get_result::<Option<f64>>(connection)?;
+/// assert_eq!(Some(6.0), median);
+///
+/// let upper_quartile = animals
+///     .select(percentile_cont(0.75, legs.cast::<Double>()))
+///     .get_result::<Option<f64>>(connection)?;
+/// assert_eq!(Some(7.0), upper_quartile);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn percentile_cont<F, O>(fraction: F, order: O) -> percentile_cont<F, O>
+where
+    F: AsExpression<Double>,
+    O: Expression,
+{
+    WithinGroup::new(fraction.as_expression(), order)
+}
+
+/// Return type of [`percentile_cont(fraction, order)`](super::dsl::percentile_cont())
+#[allow(non_camel_case_types)]
+pub type percentile_cont<F, O> = WithinGroup<PercentileCont, AsExprOf<F, Double>, O>;
+
+/// Computes a discrete percentile of the values given by `order`
+///
+/// Returns the first input value whose position in the ordering equals or
+/// exceeds the specified `fraction`. `fraction` must be between 0 and 1.
+/// Null values of `order` are ignored. The function returns `NULL` if there
+/// are no non-null input values.
+///
+/// This function renders as `percentile_disc(fraction) WITHIN GROUP (ORDER BY order)`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::dsl::percentile_disc;
+/// #     use schema::posts::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let median_title = posts
+///     .select(percentile_disc(0.5, title))
+///     .get_result::<Option<String>>(connection)?;
+/// assert_eq!(Some("My first post".to_owned()), median_title);
+///
+/// let median_post_per_user = posts
+///     .group_by(user_id)
+///     .select((user_id, percentile_disc(0.5, id)))
+///     .order(user_id)
+///     .load::<(i32, Option<i32>)>(connection)?;
+/// assert_eq!(vec![(1, Some(1)), (2, Some(3))], median_post_per_user);
+///
+/// let median_first_post = posts
+///     .select(percentile_disc(0.5, title).aggregate_filter(title.like("%first post%")))
+///     .get_result::<Option<String>>(connection)?;
+/// assert_eq!(Some("My first post".to_owned()), median_first_post);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn percentile_disc<F, O>(fraction: F, order: O) -> percentile_disc<F, O>
+where
+    F: AsExpression<Double>,
+    O: Expression,
+{
+    WithinGroup::new(fraction.as_expression(), order)
+}
+
+/// Return type of [`percentile_disc(fraction, order)`](super::dsl::percentile_disc())
+#[allow(non_camel_case_types)]
+pub type percentile_disc<F, O> = WithinGroup<PercentileDisc, AsExprOf<F, Double>, O>;
+
+/// Computes the most frequent value given by `order`
+///
+/// If there are multiple equally frequent values, the first one in the
+/// ordering is returned. Null values of `order` are ignored. The function
+/// returns `NULL` if there are no non-null input values.
+///
+/// This function renders as `mode() WITHIN GROUP (ORDER BY order)`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::dsl::mode;
+/// #     use schema::posts::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let most_active_user = posts
+///     .select(mode(user_id))
+///     .get_result::<Option<i32>>(connection)?;
+/// assert_eq!(Some(1), most_active_user);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn mode<O>(order: O) -> mode<O>
+where
+    O: Expression,
+{
+    WithinGroup::new(NoArguments, order)
+}
+
+/// Return type of [`mode(order)`](super::dsl::mode())
+#[allow(non_camel_case_types)]
+pub type mode<O> = WithinGroup<Mode, NoArguments, O>;
+
+/// An ordered-set aggregate function call
+///
+/// Values of this type are constructed by [`percentile_cont()`],
+/// [`percentile_disc()`] and [`mode()`]. `Filter` holds the `FILTER` clause
+/// added by
+/// [`aggregate_filter`](crate::expression_methods::AggregateExpressionMethods::aggregate_filter).
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct WithinGroup<F, Args, O, Filter = NoFilter> {
+    function: PhantomData<F>,
+    arguments: Args,
+    order: O,
+    filter: Filter,
+}
+
+impl<F, Args, O> WithinGroup<F, Args, O> {
+    fn new(arguments: Args, order: O) -> Self {
+        Self {
+            function: PhantomData,
+            arguments,
+            order,
+            filter: NoFilter,
+        }
+    }
+}
+
+impl<F, Args, O, Filter> Expression for WithinGroup<F, Args, O, Filter>
+where
+    O: Expression,
+    F: OrderedSetReturnType<O::SqlType>,
+{
+    type SqlType = F::SqlType;
+}
+
+impl<F, Args, O, Filter, GB> ValidGrouping<GB> for WithinGroup<F, Args, O, Filter> {
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl<F, Args, O, Filter, QS> AppearsOnTable<QS> for WithinGroup<F, Args, O, Filter>
+where
+    Self: Expression,
+    Args: AppearsOnTable<QS>,
+    O: AppearsOnTable<QS>,
+{
+}
+
+impl<F, Args, O, Filter, QS> SelectableExpression<QS> for WithinGroup<F, Args, O, Filter>
+where
+    Self: AppearsOnTable<QS>,
+    Args: SelectableExpression<QS>,
+    O: SelectableExpression<QS>,
+{
+}
+
+impl<F, Args, O, Filter> QueryFragment<Pg> for WithinGroup<F, Args, O, Filter>
+where
+    F: OrderedSetFunction,
+    Args: QueryFragment<Pg>,
+    O: QueryFragment<Pg>,
+    Filter: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql(F::FUNCTION_NAME);
+        out.push_sql("(");
+        self.arguments.walk_ast(out.reborrow())?;
+        out.push_sql(") WITHIN GROUP (ORDER BY ");
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        self.filter.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<F, Args, O, P, ST> FilterDsl<P> for WithinGroup<F, Args, O, NoFilter>
+where
+    P: Expression<SqlType = ST>,
+    ST: BoolOrNullableBool,
+    NoWhereClause: WhereAnd<P>,
+{
+    type Output = WithinGroup<F, Args, O, Filter<<NoWhereClause as WhereAnd<P>>::Output>>;
+
+    fn filter(self, predicate: P) -> Self::Output {
+        WithinGroup {
+            function: self.function,
+            arguments: self.arguments,
+            order: self.order,
+            filter: Filter(NoWhereClause.and(predicate)),
+        }
+    }
+}
+
+impl<F, Args, O, W, P, ST> FilterDsl<P> for WithinGroup<F, Args, O, Filter<W>>
+where
+    P: Expression<SqlType = ST>,
+    ST: BoolOrNullableBool,
+    W: WhereAnd<P>,
+{
+    type Output = WithinGroup<F, Args, O, Filter<<W as WhereAnd<P>>::Output>>;
+
+    fn filter(self, predicate: P) -> Self::Output {
+        WithinGroup {
+            function: self.function,
+            arguments: self.arguments,
+            order: self.order,
+            filter: Filter(self.filter.0.and(predicate)),
+        }
+    }
+}
+
+/// The empty argument list of `mode()`
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct NoArguments;
+
+impl Expression for NoArguments {
+    type SqlType = NotSelectable;
+}
+
+impl QueryFragment<Pg> for NoArguments {
+    fn walk_ast<'b>(&'b self, _out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl<QS> AppearsOnTable<QS> for NoArguments {}
+
+impl<QS> SelectableExpression<QS> for NoArguments {}
+
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PercentileCont;
+
+impl OrderedSetFunction for PercentileCont {
+    const FUNCTION_NAME: &'static str = "percentile_cont";
+}
+
+impl<ST> OrderedSetReturnType<ST> for PercentileCont
+where
+    ST: ContinuousPercentileType,
+{
+    type SqlType = ST::Nullable;
+}
+
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PercentileDisc;
+
+impl OrderedSetFunction for PercentileDisc {
+    const FUNCTION_NAME: &'static str = "percentile_disc";
+}
+
+impl<ST> OrderedSetReturnType<ST> for PercentileDisc
+where
+    ST: SingleValue + IntoNullable<Nullable: SingleValue>,
+{
+    type SqlType = ST::Nullable;
+}
+
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Mode;
+
+impl OrderedSetFunction for Mode {
+    const FUNCTION_NAME: &'static str = "mode";
+}
+
+impl<ST> OrderedSetReturnType<ST> for Mode
+where
+    ST: SingleValue + IntoNullable<Nullable: SingleValue>,
+{
+    type SqlType = ST::Nullable;
+}
+
+mod private {
+    use crate::sql_types::{Double, Interval, IntoNullable, Nullable, SingleValue, SqlType};
+
+    pub trait OrderedSetFunction {
+        const FUNCTION_NAME: &'static str;
+    }
+
+    pub trait OrderedSetReturnType<ST> {
+        type SqlType: SqlType + SingleValue;
+    }
+
+    /// Types which can be interpolated by `percentile_cont`
+    pub trait ContinuousPercentileType: SingleValue + IntoNullable<Nullable: SingleValue> {}
+
+    impl ContinuousPercentileType for Double {}
+    impl ContinuousPercentileType for Nullable<Double> {}
+    impl ContinuousPercentileType for Interval {}
+    impl ContinuousPercentileType for Nullable<Interval> {}
+}
@@ -5,7 +5,7 @@
 //! PostgreSQL, you may need to work with this module directly.
 
 pub mod expression;
-mod types;
+pub(crate) mod types;
 
 pub(crate) mod backend;
 #[cfg(feature = "postgres")]
@@ -59,15 +59,37 @@ pub mod data_types {
     #[doc(inline)]
     pub use super::types::array::NdArray;
     #[doc(inline)]
+    pub use super::types::bit_string::PgBitString;
+    #[doc(inline)]
     pub use super::types::date_and_time::{PgDate, PgInterval, PgTime, PgTimestamp};
     #[doc(inline)]
     pub use super::types::floats::PgNumeric;
     #[doc(inline)]
+    pub use super::types::geometric::{
+        PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint, PgPolygon,
+    };
+    #[doc(inline)]
     pub use super::types::money::PgMoney;
     pub use super::types::money::PgMoney as Cents;
     #[doc(inline)]
     pub use super::types::pg_lsn::PgLsn;
+    #[doc(inline)]
+    pub use super::types::pgvector::PgSparseVec;
+    #[doc(inline)]
+    pub use super::types::text_search::{
+        PgTsLexeme, PgTsPosition, PgTsQuery, PgTsVector, PgTsWeight,
+    };
 }
 
-#[doc(inline)]
-pub use self::types::sql_types;
+/// PostgreSQL specific SQL types
+///
+/// Note: Except for `Bit` and the types of the `pgvector` extension all types
+/// in this module can be accessed through `diesel::sql_types`
+pub mod sql_types {
+    #[doc(inline)]
+    pub use super::types::bit_string::Bit;
+    #[doc(inline)]
+    pub use super::types::pgvector::{HalfVec, SparseVec, Vector};
+    #[doc(inline)]
+    pub use super::types::sql_types::*;
+}
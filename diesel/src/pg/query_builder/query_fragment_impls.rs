@@ -7,7 +7,7 @@ use crate::query_builder::locking_clause::{
 };
 use crate::query_builder::upsert::into_conflict_clause::OnConflictSelectWrapper;
 use crate::query_builder::upsert::on_conflict_target_decorations::DecoratedConflictTarget;
-use crate::query_builder::{AstPass, QueryFragment};
+use crate::query_builder::{AstPass, OptimizerHint, QueryFragment};
 use crate::result::QueryResult;
 use crate::serialize::ToSql;
 use crate::sql_types::{HasSqlType, SingleValue};
@@ -133,3 +133,14 @@ where
         self.0.walk_ast(out)
     }
 }
+
+// `pg_hint_plan` expects the hint as comment in front of the query
+impl<Q> QueryFragment<Pg> for OptimizerHint<Q>
+where
+    Q: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        self.walk_hint(out.reborrow())?;
+        self.query.walk_ast(out)
+    }
+}
@@ -0,0 +1,267 @@
+//! Support for the `bit(n)` and `bit varying(n)` types of PostgreSQL
+//!
+//! Bit strings are transmitted as a 4 byte big endian bit length followed
+//! by the bits packed into `ceil(len / 8)` bytes, most significant bit first.
+//! Unused bits in the last byte are zero.
+
+use core::fmt;
+use std::io::prelude::*;
+
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+use diesel_derives::AsExpression;
+use diesel_derives::FromSqlRow;
+
+use crate::deserialize::{self, FromSql};
+use crate::pg::sql_types::Varbit;
+use crate::pg::{Pg, PgValue};
+use crate::query_builder::QueryId;
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::SqlType;
+
+/// The [`bit`] SQL type. This is a PostgreSQL specific type.
+///
+/// A bit string of fixed length. Use [`Varbit`] for `bit varying(n)` columns.
+/// For the `BIT(n)` type of MySQL and MariaDB see `diesel::mysql::sql_types::Bit`.
+///
+/// This type is only exported from `diesel::pg::sql_types`, as
+/// `diesel::sql_types::Bit` remains an alias for `Binary` for existing schemas.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`PgBitString`]
+/// - [`bitvec::vec::BitVec<u8, Msb0>`][BitVec] with `feature = "bitvec"`
+///
+/// ### [`FromSql`] impls
+///
+/// - [`PgBitString`]
+/// - [`bitvec::vec::BitVec<u8, Msb0>`][BitVec] with `feature = "bitvec"`
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`bit`]: https://www.postgresql.org/docs/current/datatype-bit.html
+/// [BitVec]: https://docs.rs/bitvec/1/bitvec/vec/struct.BitVec.html
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(postgres_type(oid = 1560, array_oid = 1561))]
+pub struct Bit;
+
+/// A PostgreSQL bit string, as stored in `bit(n)` and `bit varying(n)` columns.
+///
+/// The bits are stored packed into bytes, with the first bit of the string
+/// being the most significant bit of the first byte.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Bit)]
+#[diesel(sql_type = Varbit)]
+pub struct PgBitString {
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+impl PgBitString {
+    /// Creates an empty bit string
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a bit string containing the first `len` bits of `bytes`
+    ///
+    /// Excess bytes and any bits after `len` are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` contains less than `len` bits.
+    pub fn from_bytes(mut bytes: Vec<u8>, len: usize) -> Self {
+        assert!(
+            len <= bytes.len() * 8,
+            "A bit string of length {len} requires at least {} bytes",
+            len.div_ceil(8)
+        );
+        bytes.truncate(len.div_ceil(8));
+        if let Some(last) = bytes.last_mut() {
+            *last &= padding_mask(len);
+        }
+        Self { len, bytes }
+    }
+
+    /// The number of bits in this bit string
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this bit string does not contain any bits
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at position `index` or `None` if `index` is out of bounds
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.bytes[index / 8] & bit_mask(index) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit at position `index` to `value`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(
+            index < self.len,
+            "Index {index} is out of bounds for a bit string of length {}",
+            self.len
+        );
+        if value {
+            self.bytes[index / 8] |= bit_mask(index);
+        } else {
+            self.bytes[index / 8] &= !bit_mask(index);
+        }
+    }
+
+    /// Appends a bit to the end of this bit string
+    pub fn push(&mut self, value: bool) {
+        if self.len == self.bytes.len() * 8 {
+            self.bytes.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Returns an iterator over all bits of this bit string
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|index| self.bytes[index / 8] & bit_mask(index) != 0)
+    }
+
+    /// The packed bytes of this bit string, most significant bit first
+    ///
+    /// Unused bits of the last byte are always zero.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl FromIterator<bool> for PgBitString {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut bits = Self::new();
+        for bit in iter {
+            bits.push(bit);
+        }
+        bits
+    }
+}
+
+impl fmt::Display for PgBitString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+fn bit_mask(index: usize) -> u8 {
+    0x80 >> (index % 8)
+}
+
+fn padding_mask(len: usize) -> u8 {
+    match len % 8 {
+        0 => 0xff,
+        used => !(0xff >> used),
+    }
+}
+
+pub(super) fn read_bit_string(value: PgValue<'_>) -> deserialize::Result<(usize, &[u8])> {
+    let mut bytes = value.as_bytes();
+    let len = usize::try_from(bytes.read_i32::<NetworkEndian>()?)?;
+    if bytes.len() != len.div_ceil(8) {
+        return Err(format!(
+            "Expected {} bytes for a bit string of length {len}, but received {}",
+            len.div_ceil(8),
+            bytes.len()
+        )
+        .into());
+    }
+    Ok((len, bytes))
+}
+
+pub(super) fn write_bit_string(
+    out: &mut Output<'_, '_, Pg>,
+    len: usize,
+    bytes: &[u8],
+) -> serialize::Result {
+    out.write_i32::<NetworkEndian>(i32::try_from(len)?)?;
+    out.write_all(bytes)?;
+    Ok(IsNull::No)
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<Bit, Pg> for PgBitString {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let (len, bytes) = read_bit_string(value)?;
+        Ok(Self::from_bytes(bytes.to_vec(), len))
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<Bit, Pg> for PgBitString {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_bit_string(out, self.len, &self.bytes)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<Varbit, Pg> for PgBitString {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Bit, Pg>::from_sql(value)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<Varbit, Pg> for PgBitString {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Bit, Pg>::to_sql(self, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    #[diesel_test_helper::test]
+    fn bit_string_roundtrip() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        let input = [
+            true, false, true, true, false, false, false, true, true, true,
+        ]
+        .into_iter()
+        .collect::<PgBitString>();
+        ToSql::<Varbit, Pg>::to_sql(&input, &mut bytes).unwrap();
+        assert_eq!(buffer, [0, 0, 0, 10, 0b1011_0001, 0b1100_0000]);
+        let output: PgBitString = FromSql::<Bit, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+        assert_eq!(output.to_string(), "1011000111");
+    }
+
+    #[diesel_test_helper::test]
+    fn bit_string_accessors() {
+        let mut bits = PgBitString::from_bytes(vec![0xff, 0xff, 0xff], 12);
+        assert_eq!(bits.len(), 12);
+        assert_eq!(bits.as_bytes(), [0xff, 0xf0]);
+        bits.set(0, false);
+        assert_eq!(bits.get(0), Some(false));
+        assert_eq!(bits.get(11), Some(true));
+        assert_eq!(bits.get(12), None);
+        assert!(PgBitString::new().is_empty());
+    }
+
+    #[diesel_test_helper::test]
+    fn bit_string_with_wrong_length_fails() {
+        let buffer = [0, 0, 0, 9, 0xff];
+        let result = FromSql::<Bit, Pg>::from_sql(PgValue::for_test(&buffer))
+            .map(|bits: PgBitString| bits.len());
+        assert!(result.is_err());
+    }
+}
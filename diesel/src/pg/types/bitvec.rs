@@ -0,0 +1,74 @@
+use bitvec::order::Msb0;
+use bitvec::vec::BitVec;
+
+use super::bit_string::{read_bit_string, write_bit_string};
+use crate::deserialize::{self, FromSql, FromSqlRow};
+use crate::expression::AsExpression;
+use crate::pg::sql_types::{Bit, Varbit};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, Output, ToSql};
+
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Bit)]
+#[diesel(sql_type = Varbit)]
+#[allow(dead_code)]
+struct BitVecProxy(BitVec<u8, Msb0>);
+
+#[cfg(all(feature = "postgres_backend", feature = "bitvec"))]
+impl FromSql<Bit, Pg> for BitVec<u8, Msb0> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let (len, bytes) = read_bit_string(value)?;
+        let mut bits = BitVec::from_slice(bytes);
+        bits.truncate(len);
+        Ok(bits)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "bitvec"))]
+impl ToSql<Bit, Pg> for BitVec<u8, Msb0> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        // the unused bits of the last byte are unspecified for `BitVec`,
+        // while PostgreSQL expects them to be zero
+        let mut bits = self.clone();
+        bits.set_uninitialized(false);
+        write_bit_string(out, bits.len(), bits.as_raw_slice())
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "bitvec"))]
+impl FromSql<Varbit, Pg> for BitVec<u8, Msb0> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Bit, Pg>::from_sql(value)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "bitvec"))]
+impl ToSql<Varbit, Pg> for BitVec<u8, Msb0> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Bit, Pg>::to_sql(self, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pg::data_types::PgBitString;
+    use crate::query_builder::bind_collector::ByteWrapper;
+    use bitvec::bitvec;
+
+    #[diesel_test_helper::test]
+    fn bitvec_roundtrip() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        let mut input = bitvec![u8, Msb0; 1; 16];
+        input.truncate(11);
+        ToSql::<Bit, Pg>::to_sql(&input, &mut bytes).unwrap();
+        assert_eq!(buffer, [0, 0, 0, 11, 0xff, 0b1110_0000]);
+        let output: BitVec<u8, Msb0> =
+            FromSql::<Varbit, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+        let output: PgBitString = FromSql::<Bit, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert!(input.iter().by_vals().eq(output.iter()));
+    }
+}
@@ -0,0 +1,331 @@
+//! Support for the geometric types of PostgreSQL
+//!
+//! All coordinates are transmitted as big endian `float8` values.
+
+use diesel_derives::AsExpression;
+use diesel_derives::FromSqlRow;
+
+use super::sql_types;
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+
+/// A point on a plane, represented by the PostgreSQL `point` type
+#[derive(Debug, Clone, Copy, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::Point)]
+pub struct PgPoint {
+    /// The x coordinate
+    pub x: f64,
+    /// The y coordinate
+    pub y: f64,
+}
+
+/// An infinite line, represented by the PostgreSQL `line` type
+///
+/// The line consists of all points satisfying `a * x + b * y + c = 0`,
+/// where `a` and `b` are not both zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::Line)]
+pub struct PgLine {
+    /// The coefficient of `x`
+    pub a: f64,
+    /// The coefficient of `y`
+    pub b: f64,
+    /// The constant term
+    pub c: f64,
+}
+
+/// A finite line segment between two points, represented by the PostgreSQL
+/// `lseg` type
+#[derive(Debug, Clone, Copy, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::LineSegment)]
+pub struct PgLineSegment {
+    /// The first end point
+    pub start: PgPoint,
+    /// The second end point
+    pub end: PgPoint,
+}
+
+/// A rectangular box, represented by the PostgreSQL `box` type
+///
+/// PostgreSQL reorders the corners of a box it receives, so that `high`
+/// holds the upper right and `low` the lower left corner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::GeometricBox)]
+pub struct PgBox {
+    /// The upper right corner
+    pub high: PgPoint,
+    /// The lower left corner
+    pub low: PgPoint,
+}
+
+/// A list of connected points, represented by the PostgreSQL `path` type
+#[derive(Debug, Clone, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::Path)]
+pub struct PgPath {
+    /// The points of the path
+    pub points: Vec<PgPoint>,
+    /// Whether the last point is connected to the first one
+    pub closed: bool,
+}
+
+/// A closed path enclosing an area, represented by the PostgreSQL
+/// `polygon` type
+#[derive(Debug, Clone, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::Polygon)]
+pub struct PgPolygon(pub Vec<PgPoint>);
+
+/// A circle, represented by the PostgreSQL `circle` type
+#[derive(Debug, Clone, Copy, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::Circle)]
+pub struct PgCircle {
+    /// The center of the circle
+    pub center: PgPoint,
+    /// The radius of the circle
+    pub radius: f64,
+}
+
+fn read_point(bytes: &mut &[u8]) -> deserialize::Result<PgPoint> {
+    Ok(PgPoint {
+        x: bytes.read_f64::<NetworkEndian>()?,
+        y: bytes.read_f64::<NetworkEndian>()?,
+    })
+}
+
+fn write_point(out: &mut Output<'_, '_, Pg>, point: &PgPoint) -> serialize::Result {
+    out.write_f64::<NetworkEndian>(point.x)?;
+    out.write_f64::<NetworkEndian>(point.y)?;
+    Ok(IsNull::No)
+}
+
+fn read_points(bytes: &mut &[u8]) -> deserialize::Result<Vec<PgPoint>> {
+    let count = bytes.read_i32::<NetworkEndian>()?;
+    (0..count).map(|_| read_point(bytes)).collect()
+}
+
+fn write_points(out: &mut Output<'_, '_, Pg>, points: &[PgPoint]) -> serialize::Result {
+    out.write_i32::<NetworkEndian>(i32::try_from(points.len())?)?;
+    for point in points {
+        write_point(out, point)?;
+    }
+    Ok(IsNull::No)
+}
+
+fn ensure_consumed(bytes: &[u8], name: &str) -> deserialize::Result<()> {
+    if bytes.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Received more data than expected while decoding a {name}").into())
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::Point, Pg> for PgPoint {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let point = read_point(&mut bytes)?;
+        ensure_consumed(bytes, "point")?;
+        Ok(point)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::Point, Pg> for PgPoint {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_point(out, self)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::Line, Pg> for PgLine {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let line = PgLine {
+            a: bytes.read_f64::<NetworkEndian>()?,
+            b: bytes.read_f64::<NetworkEndian>()?,
+            c: bytes.read_f64::<NetworkEndian>()?,
+        };
+        ensure_consumed(bytes, "line")?;
+        Ok(line)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::Line, Pg> for PgLine {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_f64::<NetworkEndian>(self.a)?;
+        out.write_f64::<NetworkEndian>(self.b)?;
+        out.write_f64::<NetworkEndian>(self.c)?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::LineSegment, Pg> for PgLineSegment {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let segment = PgLineSegment {
+            start: read_point(&mut bytes)?,
+            end: read_point(&mut bytes)?,
+        };
+        ensure_consumed(bytes, "lseg")?;
+        Ok(segment)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::LineSegment, Pg> for PgLineSegment {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_point(out, &self.start)?;
+        write_point(out, &self.end)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::GeometricBox, Pg> for PgBox {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let pg_box = PgBox {
+            high: read_point(&mut bytes)?,
+            low: read_point(&mut bytes)?,
+        };
+        ensure_consumed(bytes, "box")?;
+        Ok(pg_box)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::GeometricBox, Pg> for PgBox {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_point(out, &self.high)?;
+        write_point(out, &self.low)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::Path, Pg> for PgPath {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let closed = bytes.read_u8()? != 0;
+        let points = read_points(&mut bytes)?;
+        ensure_consumed(bytes, "path")?;
+        Ok(PgPath { points, closed })
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::Path, Pg> for PgPath {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_u8(u8::from(self.closed))?;
+        write_points(out, &self.points)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::Polygon, Pg> for PgPolygon {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let points = read_points(&mut bytes)?;
+        ensure_consumed(bytes, "polygon")?;
+        Ok(PgPolygon(points))
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::Polygon, Pg> for PgPolygon {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_points(out, &self.0)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::Circle, Pg> for PgCircle {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let circle = PgCircle {
+            center: read_point(&mut bytes)?,
+            radius: bytes.read_f64::<NetworkEndian>()?,
+        };
+        ensure_consumed(bytes, "circle")?;
+        Ok(circle)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::Circle, Pg> for PgCircle {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_point(out, &self.center)?;
+        out.write_f64::<NetworkEndian>(self.radius)?;
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    fn roundtrip<ST, T>(input: T) -> Vec<u8>
+    where
+        T: ToSql<ST, Pg> + FromSql<ST, Pg> + PartialEq + core::fmt::Debug,
+    {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<ST, Pg>::to_sql(&input, &mut bytes).unwrap();
+        let output: T = FromSql::<ST, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+        buffer
+    }
+
+    fn point(x: f64, y: f64) -> PgPoint {
+        PgPoint { x, y }
+    }
+
+    #[diesel_test_helper::test]
+    fn point_roundtrip() {
+        let buffer = roundtrip::<sql_types::Point, _>(point(1.0, -2.5));
+        assert_eq!(
+            buffer,
+            [0x3f, 0xf0, 0, 0, 0, 0, 0, 0, 0xc0, 0x04, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn line_and_segment_roundtrip() {
+        roundtrip::<sql_types::Line, _>(PgLine {
+            a: 1.0,
+            b: -1.0,
+            c: 0.5,
+        });
+        roundtrip::<sql_types::LineSegment, _>(PgLineSegment {
+            start: point(0.0, 0.0),
+            end: point(3.0, 4.0),
+        });
+    }
+
+    #[diesel_test_helper::test]
+    fn box_and_circle_roundtrip() {
+        roundtrip::<sql_types::GeometricBox, _>(PgBox {
+            high: point(2.0, 2.0),
+            low: point(-1.0, 0.0),
+        });
+        roundtrip::<sql_types::Circle, _>(PgCircle {
+            center: point(1.0, 1.0),
+            radius: 5.0,
+        });
+    }
+
+    #[diesel_test_helper::test]
+    fn path_and_polygon_roundtrip() {
+        let points = vec![point(0.0, 0.0), point(1.0, 0.0), point(1.0, 1.0)];
+        let buffer = roundtrip::<sql_types::Path, _>(PgPath {
+            points: points.clone(),
+            closed: true,
+        });
+        assert_eq!(&buffer[..5], [1, 0, 0, 0, 3]);
+        let buffer = roundtrip::<sql_types::Polygon, _>(PgPolygon(points));
+        assert_eq!(&buffer[..4], [0, 0, 0, 3]);
+        assert_eq!(buffer.len(), 4 + 3 * 16);
+    }
+}
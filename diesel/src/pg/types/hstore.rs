@@ -0,0 +1,135 @@
+use alloc::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io::prelude::*;
+
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::Hstore;
+
+#[allow(dead_code)]
+mod foreign_derives {
+    use super::*;
+    use crate::deserialize::FromSqlRow;
+    use crate::expression::AsExpression;
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Hstore)]
+    struct HashMapProxy(HashMap<String, Option<String>>);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Hstore)]
+    struct BTreeMapProxy(BTreeMap<String, Option<String>>);
+}
+
+fn read_string(bytes: &mut &[u8], len: usize) -> deserialize::Result<String> {
+    if bytes.len() < len {
+        return Err("Received less data than expected while decoding an hstore".into());
+    }
+    let (string, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Ok(String::from_utf8(string.to_vec())?)
+}
+
+fn read_pairs<C>(value: PgValue<'_>) -> deserialize::Result<C>
+where
+    C: FromIterator<(String, Option<String>)>,
+{
+    let mut bytes = value.as_bytes();
+    let count = bytes.read_i32::<NetworkEndian>()?;
+    let pairs = (0..count)
+        .map(|_| {
+            let key_len = bytes.read_i32::<NetworkEndian>()?;
+            let key = read_string(&mut bytes, usize::try_from(key_len)?)?;
+            let value_len = bytes.read_i32::<NetworkEndian>()?;
+            let value = if value_len < 0 {
+                None
+            } else {
+                Some(read_string(&mut bytes, usize::try_from(value_len)?)?)
+            };
+            Ok((key, value))
+        })
+        .collect::<deserialize::Result<C>>()?;
+    if !bytes.is_empty() {
+        return Err("Received more data than expected while decoding an hstore".into());
+    }
+    Ok(pairs)
+}
+
+fn write_pairs<'a, I>(out: &mut Output<'_, '_, Pg>, len: usize, pairs: I) -> serialize::Result
+where
+    I: Iterator<Item = (&'a String, &'a Option<String>)>,
+{
+    out.write_i32::<NetworkEndian>(i32::try_from(len)?)?;
+    for (key, value) in pairs {
+        out.write_i32::<NetworkEndian>(i32::try_from(key.len())?)?;
+        out.write_all(key.as_bytes())?;
+        match value {
+            Some(value) => {
+                out.write_i32::<NetworkEndian>(i32::try_from(value.len())?)?;
+                out.write_all(value.as_bytes())?;
+            }
+            None => out.write_i32::<NetworkEndian>(-1)?,
+        }
+    }
+    Ok(IsNull::No)
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<Hstore, Pg> for HashMap<String, Option<String>> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        read_pairs(value)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<Hstore, Pg> for HashMap<String, Option<String>> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_pairs(out, self.len(), self.iter())
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<Hstore, Pg> for BTreeMap<String, Option<String>> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        read_pairs(value)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<Hstore, Pg> for BTreeMap<String, Option<String>> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_pairs(out, self.len(), self.iter())
+    }
+}
+
+#[cfg(test)]
+#[diesel_test_helper::test]
+fn hstore_roundtrip() {
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    let mut buffer = Vec::new();
+    let mut bytes = Output::test(ByteWrapper(&mut buffer));
+    let input = BTreeMap::from([
+        ("a".to_owned(), Some("1".to_owned())),
+        ("b".to_owned(), None),
+    ]);
+    ToSql::<Hstore, Pg>::to_sql(&input, &mut bytes).unwrap();
+    assert_eq!(
+        buffer,
+        [
+            0, 0, 0, 2, 0, 0, 0, 1, b'a', 0, 0, 0, 1, b'1', 0, 0, 0, 1, b'b', 0xff, 0xff, 0xff,
+            0xff
+        ]
+    );
+    let output: BTreeMap<String, Option<String>> =
+        FromSql::from_sql(PgValue::for_test(&buffer)).unwrap();
+    assert_eq!(input, output);
+    let output: HashMap<String, Option<String>> =
+        FromSql::from_sql(PgValue::for_test(&buffer)).unwrap();
+    assert_eq!(input.into_iter().collect::<HashMap<_, _>>(), output);
+}
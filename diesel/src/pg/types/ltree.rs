@@ -0,0 +1,83 @@
+use std::io::prelude::*;
+
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::{Lquery, Ltree, Ltxtquery};
+
+// The binary representation of `ltree`, `lquery` and `ltxtquery` is the text
+// representation prefixed with a version number, which is `1` for all
+// versions of the extension that support binary input and output
+const BINARY_VERSION: u8 = 1;
+
+fn read_text(value: PgValue<'_>, type_name: &str) -> deserialize::Result<String> {
+    match value.as_bytes().split_first() {
+        Some((&BINARY_VERSION, text)) => Ok(String::from_utf8(text.to_vec())?),
+        Some((version, _)) => {
+            Err(format!("Unsupported binary format version {version} for {type_name}").into())
+        }
+        None => Err(format!("Received an empty value while decoding a {type_name}").into()),
+    }
+}
+
+fn write_text(out: &mut Output<'_, '_, Pg>, text: &str) -> serialize::Result {
+    out.write_all(&[BINARY_VERSION])?;
+    out.write_all(text.as_bytes())?;
+    Ok(IsNull::No)
+}
+
+macro_rules! ltree_text_impls {
+    ($($sql_type:ident => $type_name:expr_2021,)+) => {
+        $(
+            #[cfg(feature = "postgres_backend")]
+            impl FromSql<$sql_type, Pg> for String {
+                fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+                    read_text(value, $type_name)
+                }
+            }
+
+            #[cfg(feature = "postgres_backend")]
+            impl ToSql<$sql_type, Pg> for String {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                    write_text(out, self)
+                }
+            }
+
+            #[cfg(feature = "postgres_backend")]
+            impl ToSql<$sql_type, Pg> for str {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+                    write_text(out, self)
+                }
+            }
+        )+
+    };
+}
+
+ltree_text_impls!(
+    Ltree => "ltree",
+    Lquery => "lquery",
+    Ltxtquery => "ltxtquery",
+);
+
+#[cfg(test)]
+#[diesel_test_helper::test]
+fn ltree_roundtrip() {
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    let mut buffer = Vec::new();
+    let mut bytes = Output::test(ByteWrapper(&mut buffer));
+    ToSql::<Ltree, Pg>::to_sql("Top.Science", &mut bytes).unwrap();
+    assert_eq!(buffer, b"\x01Top.Science");
+    let output: String = FromSql::<Ltree, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+    assert_eq!("Top.Science", output);
+}
+
+#[cfg(test)]
+#[diesel_test_helper::test]
+fn ltree_rejects_unknown_version() {
+    let result = <String as FromSql<Lquery, Pg>>::from_sql(PgValue::for_test(b"\x02*.Science"));
+    assert_eq!(
+        "Unsupported binary format version 2 for lquery",
+        result.unwrap_err().to_string()
+    );
+}
@@ -2,17 +2,23 @@
 
 #[doc(hidden)]
 pub(in crate::pg) mod array;
+pub(in crate::pg) mod bit_string;
+#[cfg(feature = "bitvec")]
+mod bitvec;
 #[doc(hidden)]
 pub(in crate::pg) mod date_and_time;
 mod enum_;
 #[doc(hidden)]
 pub(in crate::pg) mod floats;
+pub(in crate::pg) mod geometric;
+mod hstore;
 mod integers;
 #[cfg(feature = "ipnet-address")]
 mod ipnet_address;
 #[cfg(feature = "serde_json")]
 mod json;
 mod json_function_enum;
+mod ltree;
 mod mac_addr;
 mod mac_addr_8;
 #[doc(hidden)]
@@ -22,16 +28,16 @@ mod multirange;
 mod network_address;
 mod numeric;
 pub(in crate::pg) mod pg_lsn;
+pub(in crate::pg) mod pgvector;
 mod primitives;
 mod ranges;
 mod record;
+pub(in crate::pg) mod text_search;
 #[cfg(feature = "uuid")]
 mod uuid;
 
-/// PostgreSQL specific SQL types
-///
-/// Note: All types in this module can be accessed through `diesel::sql_types`
-pub mod sql_types {
+/// PostgreSQL specific SQL types, exported as `diesel::pg::sql_types`
+pub(crate) mod sql_types {
     use crate::query_builder::QueryId;
     use crate::sql_types::SqlType;
 
@@ -676,6 +682,101 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "citext"))]
     pub struct Citext;
 
+    /// The [`hstore`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A set of key/value pairs provided by the `hstore` extension. Values
+    /// may be `NULL`, keys may not. As the type is defined by an extension
+    /// its OID is looked up by name when it is first used.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`HashMap<String, Option<String>>`][HashMap]
+    /// - [`BTreeMap<String, Option<String>>`][BTreeMap]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`HashMap<String, Option<String>>`][HashMap]
+    /// - [`BTreeMap<String, Option<String>>`][BTreeMap]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [HashMap]: std::collections::HashMap
+    /// [BTreeMap]: std::collections::BTreeMap
+    /// [`hstore`]: https://www.postgresql.org/docs/current/hstore.html
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "hstore"))]
+    pub struct Hstore;
+
+    /// The [`ltree`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A label path in a tree-like structure, like `Top.Science.Astronomy`,
+    /// provided by the `ltree` extension. As the type is defined by an
+    /// extension its OID is looked up by name when it is first used.
+    /// See [`PgLtreeExpressionMethods`](crate::expression_methods::PgLtreeExpressionMethods)
+    /// for the supported operators.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`String`]
+    /// - [`&str`][str]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`String`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`ltree`]: https://www.postgresql.org/docs/current/ltree.html
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "ltree"))]
+    pub struct Ltree;
+
+    /// The [`lquery`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A regular-expression-like pattern for matching `ltree` values, like
+    /// `*.Science.*`, provided by the `ltree` extension.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`String`]
+    /// - [`&str`][str]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`String`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`lquery`]: https://www.postgresql.org/docs/current/ltree.html
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "lquery"))]
+    pub struct Lquery;
+
+    /// The [`ltxtquery`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A full-text-search-like pattern for matching `ltree` values, like
+    /// `Science & !Astronomy`, provided by the `ltree` extension.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`String`]
+    /// - [`&str`][str]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`String`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`ltxtquery`]: https://www.postgresql.org/docs/current/ltree.html
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "ltxtquery"))]
+    pub struct Ltxtquery;
+
     /// The [`pg_lsn`] SQL type. This is a PostgreSQL specific type. Encodes a position in the PostgreSQL *Write Ahead Log* (WAL).
     ///
     /// ### [`ToSql`] impls
@@ -695,6 +796,261 @@ pub mod sql_types {
     #[diesel(postgres_type(oid = 3220, array_oid = 3221))]
     pub struct PgLsn;
 
+    /// The [`tsvector`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A document optimized for full text search, see
+    /// [`PgTsVectorExpressionMethods`](crate::expression_methods::PgTsVectorExpressionMethods)
+    /// and [`to_tsvector`](crate::pg::expression::functions::to_tsvector())
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgTsVector`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgTsVector`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgTsVector`]: crate::pg::data_types::PgTsVector
+    /// [`tsvector`]: https://www.postgresql.org/docs/current/datatype-textsearch.html#DATATYPE-TSVECTOR
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 3614, array_oid = 3643))]
+    pub struct TsVector;
+
+    #[doc(hidden)]
+    pub type Tsvector = TsVector;
+
+    /// The [`tsquery`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A full text search query, usually constructed with
+    /// [`to_tsquery`](crate::pg::expression::functions::to_tsquery())
+    /// or one of its variants
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgTsQuery`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgTsQuery`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgTsQuery`]: crate::pg::data_types::PgTsQuery
+    /// [`tsquery`]: https://www.postgresql.org/docs/current/datatype-textsearch.html#DATATYPE-TSQUERY
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 3615, array_oid = 3645))]
+    pub struct TsQuery;
+
+    #[doc(hidden)]
+    pub type Tsquery = TsQuery;
+
+    /// The [`regconfig`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// Identifies a text search configuration like `english` by its OID.
+    /// A configuration name can be turned into a `regconfig` with
+    /// `"english".into_sql::<Text>().fallible_cast::<RegConfig>()`.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`u32`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`u32`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`u32`]: https://doc.rust-lang.org/nightly/std/primitive.u32.html
+    /// [`regconfig`]: https://www.postgresql.org/docs/current/datatype-oid.html
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 3734, array_oid = 3735))]
+    pub struct RegConfig;
+
+    #[doc(hidden)]
+    pub type Regconfig = RegConfig;
+
+    /// The [`point`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A point on a plane. For the `POINT` type of MySQL and MariaDB see
+    /// `diesel::mysql::sql_types::Point`.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgPoint`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgPoint`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgPoint`]: crate::pg::data_types::PgPoint
+    /// [`point`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-GEOMETRIC-POINTS
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 600, array_oid = 1017))]
+    pub struct Point;
+
+    /// The [`line`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// An infinite line.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgLine`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgLine`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgLine`]: crate::pg::data_types::PgLine
+    /// [`line`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-LINE
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 628, array_oid = 629))]
+    pub struct Line;
+
+    /// The [`lseg`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A finite line segment.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgLineSegment`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgLineSegment`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgLineSegment`]: crate::pg::data_types::PgLineSegment
+    /// [`lseg`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-LSEG
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 601, array_oid = 1018))]
+    pub struct LineSegment;
+
+    #[doc(hidden)]
+    pub type Lseg = LineSegment;
+
+    /// The [`box`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A rectangular box. `diesel print-schema` maps `box` columns to this type.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgBox`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgBox`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgBox`]: crate::pg::data_types::PgBox
+    /// [`box`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-GEOMETRIC-BOXES
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 603, array_oid = 1020))]
+    pub struct GeometricBox;
+
+    /// The [`path`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// An open or closed path.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgPath`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgPath`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgPath`]: crate::pg::data_types::PgPath
+    /// [`path`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-GEOMETRIC-PATHS
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 602, array_oid = 1019))]
+    pub struct Path;
+
+    /// The [`circle`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A circle.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgCircle`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgCircle`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgCircle`]: crate::pg::data_types::PgCircle
+    /// [`circle`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-CIRCLE
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 718, array_oid = 719))]
+    pub struct Circle;
+
+    /// The [`polygon`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A closed path. For the `POLYGON` type of MySQL and MariaDB see
+    /// `diesel::mysql::sql_types::Polygon`.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgPolygon`]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgPolygon`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgPolygon`]: crate::pg::data_types::PgPolygon
+    /// [`polygon`]: https://www.postgresql.org/docs/current/datatype-geometric.html#DATATYPE-POLYGON
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 604, array_oid = 1027))]
+    pub struct Polygon;
+
+    /// The [`bit varying`] SQL type. This is a PostgreSQL specific type.
+    ///
+    /// A bit string of variable length. Use [`Bit`](crate::pg::sql_types::Bit) for fixed
+    /// length `bit(n)` columns.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`PgBitString`]
+    /// - [`bitvec::vec::BitVec<u8, Msb0>`][BitVec] with `feature = "bitvec"`
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`PgBitString`]
+    /// - [`bitvec::vec::BitVec<u8, Msb0>`][BitVec] with `feature = "bitvec"`
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgBitString`]: crate::pg::data_types::PgBitString
+    /// [`bit varying`]: https://www.postgresql.org/docs/current/datatype-bit.html
+    /// [BitVec]: https://docs.rs/bitvec/1/bitvec/vec/struct.BitVec.html
+    #[cfg(feature = "postgres_backend")]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(oid = 1562, array_oid = 1563))]
+    pub struct Varbit;
+
     #[doc(inline)]
     pub use crate::sql_types::Jsonb;
 }
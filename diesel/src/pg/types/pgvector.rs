@@ -0,0 +1,356 @@
+//! Support for the `vector`, `halfvec` and `sparsevec` types of the
+//! `pgvector` extension
+//!
+//! Dense vectors are transmitted as a 2 byte big endian number of dimensions,
+//! 2 unused bytes and the elements as big endian 4 byte (`vector`) or 2 byte
+//! (`halfvec`) floats. Sparse vectors are transmitted as a 4 byte number of
+//! dimensions, a 4 byte number of non-zero elements, 4 unused bytes, followed
+//! by the zero based indices and the values of the non-zero elements.
+//!
+//! The SQL types are only exported from `diesel::pg::sql_types`, as the
+//! `pgvector` crate provides SQL types with the same names which are often
+//! glob imported together with `diesel::sql_types::*`.
+
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+use diesel_derives::AsExpression;
+use diesel_derives::FromSqlRow;
+#[cfg(feature = "pgvector")]
+use half::f16;
+
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::query_builder::QueryId;
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::SqlType;
+
+/// The [`vector`] SQL type of the `pgvector` extension. This is a
+/// PostgreSQL specific type.
+///
+/// A dense vector of single precision floats, as used to store
+/// embeddings. As the type is defined by an extension its OID is looked
+/// up by name when it is first used. See
+/// [`PgVectorExpressionMethods`](crate::expression_methods::PgVectorExpressionMethods)
+/// for the supported distance operators.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`Vec<f32>`]
+/// - [`&[f32]`][slice]
+///
+/// ### [`FromSql`] impls
+///
+/// - [`Vec<f32>`]
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`vector`]: https://github.com/pgvector/pgvector
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(postgres_type(name = "vector"))]
+pub struct Vector;
+
+/// The [`halfvec`] SQL type of the `pgvector` extension. This is a
+/// PostgreSQL specific type.
+///
+/// A dense vector of half precision floats.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`Vec<half::f16>`][f16] with `feature = "pgvector"`
+/// - [`&[half::f16]`][f16] with `feature = "pgvector"`
+///
+/// ### [`FromSql`] impls
+///
+/// - [`Vec<half::f16>`][f16] with `feature = "pgvector"`
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [f16]: https://docs.rs/half/2/half/struct.f16.html
+/// [`halfvec`]: https://github.com/pgvector/pgvector
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(postgres_type(name = "halfvec"))]
+pub struct HalfVec;
+
+/// The [`sparsevec`] SQL type of the `pgvector` extension. This is a
+/// PostgreSQL specific type.
+///
+/// A vector which only stores its non-zero elements.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`PgSparseVec`]
+///
+/// ### [`FromSql`] impls
+///
+/// - [`PgSparseVec`]
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [`sparsevec`]: https://github.com/pgvector/pgvector
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(postgres_type(name = "sparsevec"))]
+pub struct SparseVec;
+
+/// A sparse vector of the `pgvector` extension, as stored in `sparsevec`
+/// columns.
+///
+/// Only the non-zero elements are stored, as their zero based index together
+/// with their value.
+#[derive(Debug, Clone, Default, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = SparseVec)]
+pub struct PgSparseVec {
+    /// The number of dimensions of the vector
+    pub dimensions: i32,
+    /// The zero based indices of the non-zero elements in ascending order
+    pub indices: Vec<i32>,
+    /// The values of the non-zero elements
+    pub values: Vec<f32>,
+}
+
+impl PgSparseVec {
+    /// Creates a sparse vector from the non-zero elements of a dense vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if `elements` has more than `i32::MAX` elements.
+    pub fn from_dense(elements: &[f32]) -> Self {
+        let dimensions = i32::try_from(elements.len())
+            .expect("A sparse vector has at most `i32::MAX` dimensions");
+        let (indices, values) = (0..dimensions)
+            .zip(elements)
+            .filter(|(_, value)| **value != 0.0)
+            .unzip();
+        Self {
+            dimensions,
+            indices,
+            values,
+        }
+    }
+
+    /// Returns the dense representation of this vector
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is negative or not less than the number of
+    /// dimensions.
+    pub fn to_dense(&self) -> Vec<f32> {
+        let mut elements = vec![0.0; usize::try_from(self.dimensions).unwrap_or_default()];
+        for (index, value) in self.indices.iter().zip(&self.values) {
+            let index = usize::try_from(*index).expect("Sparse vector indices are not negative");
+            elements[index] = *value;
+        }
+        elements
+    }
+}
+
+#[derive(AsExpression)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Vector)]
+#[allow(dead_code)]
+struct VectorProxy(Vec<f32>);
+
+#[derive(AsExpression)]
+#[diesel(foreign_derive, not_sized)]
+#[diesel(sql_type = Vector)]
+#[allow(dead_code)]
+struct VectorSliceProxy([f32]);
+
+#[cfg(feature = "pgvector")]
+#[derive(AsExpression)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = HalfVec)]
+#[allow(dead_code)]
+struct HalfVecProxy(Vec<f16>);
+
+#[cfg(feature = "pgvector")]
+#[derive(AsExpression)]
+#[diesel(foreign_derive, not_sized)]
+#[diesel(sql_type = HalfVec)]
+#[allow(dead_code)]
+struct HalfVecSliceProxy([f16]);
+
+fn read_dimensions(bytes: &mut &[u8], type_name: &str) -> deserialize::Result<usize> {
+    let dimensions = bytes.read_u16::<NetworkEndian>()?;
+    let unused = bytes.read_u16::<NetworkEndian>()?;
+    if unused != 0 {
+        return Err(format!("Unexpected header while decoding a {type_name}").into());
+    }
+    Ok(usize::from(dimensions))
+}
+
+fn write_dimensions(
+    out: &mut Output<'_, '_, Pg>,
+    len: usize,
+    type_name: &str,
+) -> serialize::Result {
+    let dimensions =
+        u16::try_from(len).map_err(|_| format!("A {type_name} cannot have {len} dimensions"))?;
+    out.write_u16::<NetworkEndian>(dimensions)?;
+    out.write_u16::<NetworkEndian>(0)?;
+    Ok(IsNull::No)
+}
+
+impl FromSql<Vector, Pg> for Vec<f32> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let dimensions = read_dimensions(&mut bytes, "vector")?;
+        (0..dimensions)
+            .map(|_| Ok(bytes.read_f32::<NetworkEndian>()?))
+            .collect()
+    }
+}
+
+impl ToSql<Vector, Pg> for [f32] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_dimensions(out, self.len(), "vector")?;
+        for element in self {
+            out.write_f32::<NetworkEndian>(*element)?;
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Vector, Pg> for Vec<f32> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Vector, Pg>::to_sql(self.as_slice(), out)
+    }
+}
+
+#[cfg(feature = "pgvector")]
+impl FromSql<HalfVec, Pg> for Vec<f16> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let dimensions = read_dimensions(&mut bytes, "halfvec")?;
+        (0..dimensions)
+            .map(|_| Ok(f16::from_bits(bytes.read_u16::<NetworkEndian>()?)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "pgvector")]
+impl ToSql<HalfVec, Pg> for [f16] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        write_dimensions(out, self.len(), "halfvec")?;
+        for element in self {
+            out.write_u16::<NetworkEndian>(element.to_bits())?;
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "pgvector")]
+impl ToSql<HalfVec, Pg> for Vec<f16> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<HalfVec, Pg>::to_sql(self.as_slice(), out)
+    }
+}
+
+impl FromSql<SparseVec, Pg> for PgSparseVec {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let dimensions = bytes.read_i32::<NetworkEndian>()?;
+        let non_zero = bytes.read_i32::<NetworkEndian>()?;
+        let unused = bytes.read_i32::<NetworkEndian>()?;
+        if unused != 0 {
+            return Err("Unexpected header while decoding a sparsevec".into());
+        }
+        let non_zero = usize::try_from(non_zero)
+            .map_err(|_| "Received a negative number of elements while decoding a sparsevec")?;
+        let indices = (0..non_zero)
+            .map(|_| bytes.read_i32::<NetworkEndian>())
+            .collect::<Result<_, _>>()?;
+        let values = (0..non_zero)
+            .map(|_| bytes.read_f32::<NetworkEndian>())
+            .collect::<Result<_, _>>()?;
+        Ok(PgSparseVec {
+            dimensions,
+            indices,
+            values,
+        })
+    }
+}
+
+impl ToSql<SparseVec, Pg> for PgSparseVec {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        if self.indices.len() != self.values.len() {
+            return Err(format!(
+                "A sparsevec requires as many indices as values, got {} indices and {} values",
+                self.indices.len(),
+                self.values.len()
+            )
+            .into());
+        }
+        let non_zero = i32::try_from(self.indices.len())?;
+        out.write_i32::<NetworkEndian>(self.dimensions)?;
+        out.write_i32::<NetworkEndian>(non_zero)?;
+        out.write_i32::<NetworkEndian>(0)?;
+        for index in &self.indices {
+            out.write_i32::<NetworkEndian>(*index)?;
+        }
+        for value in &self.values {
+            out.write_f32::<NetworkEndian>(*value)?;
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    #[diesel_test_helper::test]
+    fn vector_roundtrip() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        let input = vec![1.0_f32, -2.5];
+        ToSql::<Vector, Pg>::to_sql(&input, &mut bytes).unwrap();
+        assert_eq!(buffer, [0, 2, 0, 0, 0x3f, 0x80, 0, 0, 0xc0, 0x20, 0, 0]);
+        let output: Vec<f32> = FromSql::<Vector, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+    }
+
+    #[cfg(feature = "pgvector")]
+    #[diesel_test_helper::test]
+    fn halfvec_roundtrip() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        let input = vec![f16::from_f32(1.0), f16::from_f32(-2.5)];
+        ToSql::<HalfVec, Pg>::to_sql(&input, &mut bytes).unwrap();
+        assert_eq!(buffer, [0, 2, 0, 0, 0x3c, 0x00, 0xc1, 0x00]);
+        let output: Vec<f16> =
+            FromSql::<HalfVec, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+    }
+
+    #[diesel_test_helper::test]
+    fn sparsevec_roundtrip() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        let input = PgSparseVec::from_dense(&[0.0, 1.0, 0.0, 0.0, -2.5]);
+        assert_eq!(input.indices, [1, 4]);
+        ToSql::<SparseVec, Pg>::to_sql(&input, &mut bytes).unwrap();
+        assert_eq!(
+            buffer,
+            [
+                0, 0, 0, 5, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4, 0x3f, 0x80, 0, 0, 0xc0,
+                0x20, 0, 0
+            ]
+        );
+        let output: PgSparseVec =
+            FromSql::<SparseVec, Pg>::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+        assert_eq!(output.to_dense(), [0.0, 1.0, 0.0, 0.0, -2.5]);
+    }
+
+    #[diesel_test_helper::test]
+    fn vector_rejects_too_many_dimensions() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        let input = vec![0.0_f32; 70_000];
+        let result = ToSql::<Vector, Pg>::to_sql(&input, &mut bytes);
+        assert_eq!(
+            "A vector cannot have 70000 dimensions",
+            result.unwrap_err().to_string()
+        );
+    }
+}
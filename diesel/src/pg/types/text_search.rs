@@ -0,0 +1,409 @@
+use std::io::Write;
+
+use diesel_derives::AsExpression;
+use diesel_derives::FromSqlRow;
+
+use super::sql_types;
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+
+/// The weight of a lexeme position in a `tsvector`
+///
+/// `A` is the highest weight, `D` the lowest and the default one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PgTsWeight {
+    /// Weight `A`
+    A,
+    /// Weight `B`
+    B,
+    /// Weight `C`
+    C,
+    /// Weight `D`
+    D,
+}
+
+impl PgTsWeight {
+    // `tsvector` stores the weight in the two highest bits of a position
+    fn from_position_bits(bits: u16) -> Self {
+        match bits >> 14 {
+            3 => PgTsWeight::A,
+            2 => PgTsWeight::B,
+            1 => PgTsWeight::C,
+            _ => PgTsWeight::D,
+        }
+    }
+
+    fn position_bits(self) -> u16 {
+        match self {
+            PgTsWeight::A => 3 << 14,
+            PgTsWeight::B => 2 << 14,
+            PgTsWeight::C => 1 << 14,
+            PgTsWeight::D => 0,
+        }
+    }
+
+    // `tsquery` stores the allowed weights of a lexeme as bitmask
+    fn query_bit(self) -> u8 {
+        match self {
+            PgTsWeight::A => 1 << 3,
+            PgTsWeight::B => 1 << 2,
+            PgTsWeight::C => 1 << 1,
+            PgTsWeight::D => 1,
+        }
+    }
+}
+
+/// A position of a lexeme in a `tsvector`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgTsPosition {
+    /// The position of the lexeme in the document, between 1 and 16383
+    pub position: u16,
+    /// The weight of this position
+    pub weight: PgTsWeight,
+}
+
+/// A lexeme of a `tsvector` together with its positions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgTsLexeme {
+    /// The normalized word
+    pub word: String,
+    /// The positions of the lexeme in the document
+    ///
+    /// PostgreSQL requires the positions to be in ascending order
+    /// without duplicates. This list is empty if the `tsvector`
+    /// was stripped of its positions.
+    pub positions: Vec<PgTsPosition>,
+}
+
+/// A document in the form of a PostgreSQL `tsvector`
+///
+/// This is the Rust representation of the
+/// [`TsVector`](super::sql_types::TsVector) SQL type.
+/// PostgreSQL sorts the lexemes and removes duplicates when
+/// receiving a value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::TsVector)]
+pub struct PgTsVector(pub Vec<PgTsLexeme>);
+
+/// A text search query in the form of a PostgreSQL `tsquery`
+///
+/// This is the Rust representation of the
+/// [`TsQuery`](super::sql_types::TsQuery) SQL type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, AsExpression, FromSqlRow)]
+#[diesel(sql_type = sql_types::TsQuery)]
+pub enum PgTsQuery {
+    /// An empty query, which matches nothing
+    Empty,
+    /// A lexeme to search for
+    Lexeme {
+        /// The lexeme
+        word: String,
+        /// The weights the lexeme must have to match,
+        /// an empty list allows any weight
+        weights: Vec<PgTsWeight>,
+        /// Whether the lexeme matches as prefix (`word:*`)
+        prefix: bool,
+    },
+    /// Negates a query, `!query`
+    Not(Box<PgTsQuery>),
+    /// Both queries must match, `left & right`
+    And(Box<PgTsQuery>, Box<PgTsQuery>),
+    /// One of the queries must match, `left | right`
+    Or(Box<PgTsQuery>, Box<PgTsQuery>),
+    /// `right` must follow `left` at the given distance, `left <N> right`
+    Phrase(Box<PgTsQuery>, Box<PgTsQuery>, u16),
+}
+
+const QI_VAL: u8 = 1;
+const QI_OPR: u8 = 2;
+const OP_NOT: u8 = 1;
+const OP_AND: u8 = 2;
+const OP_OR: u8 = 3;
+const OP_PHRASE: u8 = 4;
+
+fn read_cstring(bytes: &mut &[u8]) -> deserialize::Result<String> {
+    let end = bytes
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or("Missing null terminator while decoding a text search value")?;
+    let s = core::str::from_utf8(&bytes[..end])?.to_owned();
+    *bytes = &bytes[end + 1..];
+    Ok(s)
+}
+
+fn write_cstring(out: &mut Output<'_, '_, Pg>, s: &str) -> serialize::Result {
+    if s.as_bytes().contains(&0) {
+        return Err("A text search lexeme must not contain null bytes".into());
+    }
+    out.write_all(s.as_bytes())?;
+    out.write_u8(0)?;
+    Ok(IsNull::No)
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::TsVector, Pg> for PgTsVector {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let count = bytes.read_i32::<NetworkEndian>()?;
+        let mut lexemes = Vec::with_capacity(usize::try_from(count)?);
+        for _ in 0..count {
+            let word = read_cstring(&mut bytes)?;
+            let npos = bytes.read_u16::<NetworkEndian>()?;
+            let positions = (0..npos)
+                .map(|_| {
+                    let bits = bytes.read_u16::<NetworkEndian>()?;
+                    Ok(PgTsPosition {
+                        position: bits & 0x3fff,
+                        weight: PgTsWeight::from_position_bits(bits),
+                    })
+                })
+                .collect::<deserialize::Result<_>>()?;
+            lexemes.push(PgTsLexeme { word, positions });
+        }
+        Ok(PgTsVector(lexemes))
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::TsVector, Pg> for PgTsVector {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_i32::<NetworkEndian>(i32::try_from(self.0.len())?)?;
+        for lexeme in &self.0 {
+            write_cstring(out, &lexeme.word)?;
+            out.write_u16::<NetworkEndian>(u16::try_from(lexeme.positions.len())?)?;
+            for position in &lexeme.positions {
+                if position.position > 0x3fff {
+                    return Err("A tsvector position must not be larger than 16383".into());
+                }
+                out.write_u16::<NetworkEndian>(
+                    position.position | position.weight.position_bits(),
+                )?;
+            }
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl PgTsQuery {
+    // operators are stored in prefix order, followed by
+    // their right operand and then by their left operand
+    fn read_item(bytes: &mut &[u8]) -> deserialize::Result<Self> {
+        match bytes.read_u8()? {
+            QI_VAL => {
+                let weight_mask = bytes.read_u8()?;
+                let prefix = bytes.read_u8()? != 0;
+                let word = read_cstring(bytes)?;
+                let weights = [PgTsWeight::A, PgTsWeight::B, PgTsWeight::C, PgTsWeight::D]
+                    .into_iter()
+                    .filter(|w| weight_mask & w.query_bit() != 0)
+                    .collect();
+                Ok(PgTsQuery::Lexeme {
+                    word,
+                    weights,
+                    prefix,
+                })
+            }
+            QI_OPR => {
+                let oper = bytes.read_u8()?;
+                let distance = if oper == OP_PHRASE {
+                    bytes.read_u16::<NetworkEndian>()?
+                } else {
+                    0
+                };
+                if oper == OP_NOT {
+                    return Ok(PgTsQuery::Not(Box::new(Self::read_item(bytes)?)));
+                }
+                let right = Box::new(Self::read_item(bytes)?);
+                let left = Box::new(Self::read_item(bytes)?);
+                match oper {
+                    OP_AND => Ok(PgTsQuery::And(left, right)),
+                    OP_OR => Ok(PgTsQuery::Or(left, right)),
+                    OP_PHRASE => Ok(PgTsQuery::Phrase(left, right, distance)),
+                    _ => Err(format!("Unknown tsquery operator {oper}").into()),
+                }
+            }
+            tpe => Err(format!("Unknown tsquery item type {tpe}").into()),
+        }
+    }
+
+    fn item_count(&self) -> usize {
+        match self {
+            PgTsQuery::Empty => 0,
+            PgTsQuery::Lexeme { .. } => 1,
+            PgTsQuery::Not(inner) => 1 + inner.item_count(),
+            PgTsQuery::And(left, right)
+            | PgTsQuery::Or(left, right)
+            | PgTsQuery::Phrase(left, right, _) => 1 + left.item_count() + right.item_count(),
+        }
+    }
+
+    fn write_item(&self, out: &mut Output<'_, '_, Pg>) -> serialize::Result {
+        match self {
+            PgTsQuery::Empty => {
+                return Err("An empty tsquery cannot be nested in another tsquery".into());
+            }
+            PgTsQuery::Lexeme {
+                word,
+                weights,
+                prefix,
+            } => {
+                out.write_u8(QI_VAL)?;
+                out.write_u8(weights.iter().fold(0, |mask, w| mask | w.query_bit()))?;
+                out.write_u8(u8::from(*prefix))?;
+                write_cstring(out, word)?;
+            }
+            PgTsQuery::Not(inner) => {
+                out.write_u8(QI_OPR)?;
+                out.write_u8(OP_NOT)?;
+                inner.write_item(out)?;
+            }
+            PgTsQuery::And(left, right) | PgTsQuery::Or(left, right) => {
+                let oper = if matches!(self, PgTsQuery::And(..)) {
+                    OP_AND
+                } else {
+                    OP_OR
+                };
+                out.write_u8(QI_OPR)?;
+                out.write_u8(oper)?;
+                right.write_item(out)?;
+                left.write_item(out)?;
+            }
+            PgTsQuery::Phrase(left, right, distance) => {
+                out.write_u8(QI_OPR)?;
+                out.write_u8(OP_PHRASE)?;
+                out.write_u16::<NetworkEndian>(*distance)?;
+                right.write_item(out)?;
+                left.write_item(out)?;
+            }
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::TsQuery, Pg> for PgTsQuery {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let count = bytes.read_i32::<NetworkEndian>()?;
+        if count == 0 {
+            return Ok(PgTsQuery::Empty);
+        }
+        let query = PgTsQuery::read_item(&mut bytes)?;
+        if !bytes.is_empty() {
+            return Err("Received more data than expected while decoding a tsquery".into());
+        }
+        Ok(query)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::TsQuery, Pg> for PgTsQuery {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_i32::<NetworkEndian>(i32::try_from(self.item_count())?)?;
+        if *self != PgTsQuery::Empty {
+            self.write_item(out)?;
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::RegConfig, Pg> for u32 {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<sql_types::Oid, Pg>::from_sql(value)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::RegConfig, Pg> for u32 {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<sql_types::Oid, Pg>::to_sql(self, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    fn lexeme(word: &str) -> Box<PgTsQuery> {
+        Box::new(PgTsQuery::Lexeme {
+            word: word.into(),
+            weights: Vec::new(),
+            prefix: false,
+        })
+    }
+
+    #[diesel_test_helper::test]
+    fn tsvector_roundtrip() {
+        let input = PgTsVector(vec![
+            PgTsLexeme {
+                word: "cat".into(),
+                positions: vec![
+                    PgTsPosition {
+                        position: 3,
+                        weight: PgTsWeight::A,
+                    },
+                    PgTsPosition {
+                        position: 16383,
+                        weight: PgTsWeight::D,
+                    },
+                ],
+            },
+            PgTsLexeme {
+                word: "fat".into(),
+                positions: Vec::new(),
+            },
+        ]);
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<sql_types::TsVector, Pg>::to_sql(&input, &mut bytes).unwrap();
+        assert_eq!(
+            buffer,
+            [
+                0, 0, 0, 2, b'c', b'a', b't', 0, 0, 2, 0xc0, 3, 0x3f, 0xff, b'f', b'a', b't', 0, 0,
+                0
+            ]
+        );
+        let output: PgTsVector = FromSql::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+    }
+
+    #[diesel_test_helper::test]
+    fn tsquery_roundtrip() {
+        // 'fat' & !'rat:A*' | 'cat' <2> 'dog'
+        let input = PgTsQuery::Or(
+            Box::new(PgTsQuery::And(
+                lexeme("fat"),
+                Box::new(PgTsQuery::Not(Box::new(PgTsQuery::Lexeme {
+                    word: "rat".into(),
+                    weights: vec![PgTsWeight::A],
+                    prefix: true,
+                }))),
+            )),
+            Box::new(PgTsQuery::Phrase(lexeme("cat"), lexeme("dog"), 2)),
+        );
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<sql_types::TsQuery, Pg>::to_sql(&input, &mut bytes).unwrap();
+        // the right operand is written before the left one
+        assert_eq!(
+            &buffer[..9],
+            [0, 0, 0, 8, QI_OPR, OP_OR, QI_OPR, OP_PHRASE, 0]
+        );
+        let output: PgTsQuery = FromSql::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(input, output);
+    }
+
+    #[diesel_test_helper::test]
+    fn empty_tsquery_roundtrip() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<sql_types::TsQuery, Pg>::to_sql(&PgTsQuery::Empty, &mut bytes).unwrap();
+        assert_eq!(buffer, [0, 0, 0, 0]);
+        let output: PgTsQuery = FromSql::from_sql(PgValue::for_test(&buffer)).unwrap();
+        assert_eq!(PgTsQuery::Empty, output);
+    }
+}
@@ -98,6 +98,7 @@ where
     )]
     #[cfg(any(
         feature = "__sqlite-shared",
+        feature = "mariadb_backend",
         feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
     ))]
     pub(crate) fn skip_from(&mut self, value: bool) {
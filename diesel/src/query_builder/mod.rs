@@ -26,6 +26,7 @@ pub(crate) mod limit_offset_clause;
 pub(crate) mod locking_clause;
 pub(crate) mod nodes;
 pub(crate) mod offset_clause;
+mod optimizer_hint;
 pub(crate) mod order_clause;
 pub(crate) mod select_clause;
 pub(crate) mod select_statement;
@@ -55,6 +56,8 @@ pub use self::insert_statement::{
     InsertOrIgnoreStatement, InsertStatement, ReplaceStatement,
 };
 #[doc(inline)]
+pub use self::optimizer_hint::OptimizerHint;
+#[doc(inline)]
 pub use self::query_id::QueryId;
 #[doc(inline)]
 pub use self::sql_query::{BoxedCloneSqlQuery, BoxedSqlQuery, SqlQuery};
@@ -150,6 +153,9 @@ pub use crate::pg::query_builder::only::Only;
 #[cfg(feature = "postgres_backend")]
 pub use crate::pg::query_builder::tablesample::{Tablesample, TablesampleMethod};
 
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+pub use crate::mysql_like::query_builder::index_hints::IndexHints;
+
 #[cfg(feature = "postgres_backend")]
 pub(crate) use self::bind_collector::ByteWrapper;
 use crate::backend::Backend;
@@ -0,0 +1,138 @@
+use alloc::string::String;
+
+use crate::backend::Backend;
+use crate::query_builder::{AstPass, Query, QueryId};
+use crate::query_dsl::RunQueryDslSupport;
+use crate::result::{Error, QueryResult};
+
+/// A query carrying an optimizer hint comment
+///
+/// This type is constructed by
+/// [`QueryDsl::optimizer_hint`](crate::QueryDsl::optimizer_hint()).
+/// Depending on the backend the hint is rendered either as leading comment
+/// of the query or directly after the `SELECT` keyword.
+#[derive(Debug, Clone)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+// the hint is only rendered by the backend specific `QueryFragment` impls
+#[cfg_attr(
+    not(any(
+        feature = "postgres_backend",
+        feature = "__sqlite-shared",
+        feature = "mysql_backend",
+        feature = "mariadb_backend"
+    )),
+    allow(dead_code)
+)]
+pub struct OptimizerHint<Q> {
+    pub(crate) query: Q,
+    hint: String,
+}
+
+impl<Q> OptimizerHint<Q> {
+    pub(crate) fn new(query: Q, hint: String) -> Self {
+        OptimizerHint { query, hint }
+    }
+
+    /// Writes the hint as `/*+ hint */ ` comment
+    #[cfg_attr(
+        not(any(
+            feature = "postgres_backend",
+            feature = "__sqlite-shared",
+            feature = "mysql_backend",
+            feature = "mariadb_backend"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn walk_hint<DB: Backend>(&self, mut out: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        // the hint is pasted into the query as is,
+        // so we must not allow it to close the comment
+        if self.hint.contains("*/") {
+            return Err(Error::QueryBuilderError(
+                "An optimizer hint must not contain `*/`".into(),
+            ));
+        }
+        out.push_sql("/*+ ");
+        out.push_sql(&self.hint);
+        out.push_sql(" */ ");
+        Ok(())
+    }
+}
+
+impl<Q> QueryId for OptimizerHint<Q> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Q: Query> Query for OptimizerHint<Q> {
+    type SqlType = Q::SqlType;
+}
+
+impl<Q> RunQueryDslSupport for OptimizerHint<Q> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    table! {
+        users {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    #[diesel_test_helper::test]
+    fn optimizer_hint_is_rendered_in_front_of_the_query_for_postgres() {
+        let query = users::table
+            .select(users::id)
+            .optimizer_hint("SeqScan(users)");
+        let sql = crate::debug_query::<crate::pg::Pg, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "/*+ SeqScan(users) */ SELECT \"users\".\"id\" FROM \"users\" -- binds: []"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[diesel_test_helper::test]
+    fn optimizer_hint_is_rendered_after_select_for_mysql() {
+        let query = users::table
+            .select(users::id)
+            .distinct()
+            .optimizer_hint("MAX_EXECUTION_TIME(1000)");
+        let sql = crate::debug_query::<crate::mysql::Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT /*+ MAX_EXECUTION_TIME(1000) */ DISTINCT `users`.`id` FROM `users` -- binds: []"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[diesel_test_helper::test]
+    fn optimizer_hint_is_rendered_after_select_for_boxed_mysql_queries() {
+        let query = users::table
+            .select(users::id)
+            .filter(users::name.eq("Sean"))
+            .into_boxed()
+            .optimizer_hint("MAX_EXECUTION_TIME(1000)");
+        let sql = crate::debug_query::<crate::mysql::Mysql, _>(&query).to_string();
+        assert_eq!(
+            sql,
+            "SELECT /*+ MAX_EXECUTION_TIME(1000) */ `users`.`id` FROM `users` \
+             WHERE (`users`.`name` = ?) -- binds: [\"Sean\"]"
+        );
+    }
+
+    #[cfg(feature = "__sqlite-shared")]
+    #[diesel_test_helper::test]
+    fn optimizer_hint_must_not_close_the_comment() {
+        use crate::backend::Backend;
+        use crate::query_builder::QueryFragment;
+        use crate::sqlite::Sqlite;
+
+        let query = users::table.optimizer_hint("*/ DROP TABLE users; /*");
+        let mut query_builder = <Sqlite as Backend>::QueryBuilder::default();
+        assert!(query.to_sql(&mut query_builder, &Sqlite).is_err());
+    }
+}
@@ -165,6 +165,26 @@ impl<'a, ST, QS, DB, GB> BoxedQueryHelper<'a, QS, DB> for BoxedSelectStatement<'
         'b: 'c,
     {
         out.push_sql("SELECT ");
+        self.walk_clauses(out, where_clause_handler)
+    }
+}
+
+impl<'a, ST, QS, DB, GB> BoxedSelectStatement<'a, ST, QS, DB, GB> {
+    /// Walks all clauses following the `SELECT` keyword
+    pub(crate) fn walk_clauses<'b, 'c>(
+        &'b self,
+        mut out: AstPass<'_, 'c, DB>,
+        where_clause_handler: impl Fn(
+            &'b BoxedWhereClause<'a, DB>,
+            AstPass<'_, 'c, DB>,
+        ) -> QueryResult<()>,
+    ) -> QueryResult<()>
+    where
+        DB: Backend,
+        QS: QueryFragment<DB>,
+        BoxedLimitOffsetClause<'a, DB>: QueryFragment<DB>,
+        'b: 'c,
+    {
         self.distinct.walk_ast(out.reborrow())?;
         self.select.walk_ast(out.reborrow())?;
         self.from.walk_ast(out.reborrow())?;
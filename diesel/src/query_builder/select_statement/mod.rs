@@ -216,6 +216,32 @@ impl<F, S, D, W, O, LOf, G, H, LC> SelectStatement<F, S, D, W, O, LOf, G, H, LC>
             locking,
         }
     }
+
+    /// Walks all clauses following the `SELECT` keyword
+    pub(crate) fn walk_clauses<'b, DB>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()>
+    where
+        DB: Backend,
+        S: QueryFragment<DB>,
+        F: QueryFragment<DB>,
+        D: QueryFragment<DB>,
+        W: QueryFragment<DB>,
+        O: QueryFragment<DB>,
+        LOf: QueryFragment<DB>,
+        G: QueryFragment<DB>,
+        H: QueryFragment<DB>,
+        LC: QueryFragment<DB>,
+    {
+        self.distinct.walk_ast(out.reborrow())?;
+        self.select.walk_ast(out.reborrow())?;
+        self.from.walk_ast(out.reborrow())?;
+        self.where_clause.walk_ast(out.reborrow())?;
+        self.group_by.walk_ast(out.reborrow())?;
+        self.having.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        self.limit_offset.walk_ast(out.reborrow())?;
+        self.locking.walk_ast(out.reborrow())?;
+        Ok(())
+    }
 }
 
 impl<F: QuerySource> SelectStatement<FromClause<F>> {
@@ -288,16 +314,7 @@ where
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
         out.push_sql("SELECT ");
-        self.distinct.walk_ast(out.reborrow())?;
-        self.select.walk_ast(out.reborrow())?;
-        self.from.walk_ast(out.reborrow())?;
-        self.where_clause.walk_ast(out.reborrow())?;
-        self.group_by.walk_ast(out.reborrow())?;
-        self.having.walk_ast(out.reborrow())?;
-        self.order.walk_ast(out.reborrow())?;
-        self.limit_offset.walk_ast(out.reborrow())?;
-        self.locking.walk_ast(out.reborrow())?;
-        Ok(())
+        self.walk_clauses(out)
     }
 }
 
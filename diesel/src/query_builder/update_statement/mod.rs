@@ -2,7 +2,7 @@ pub(crate) mod batch_update;
 pub(crate) mod changeset;
 pub(super) mod target;
 
-use private::AllowFilterForUpdate;
+use private::{AllowFilterForUpdate, AllowReturningForUpdate};
 
 use crate::QuerySource;
 use crate::backend::DieselReserveSpecialization;
@@ -277,7 +277,7 @@ where
     T::FromClause: QueryFragment<DB>,
     U: QueryFragment<DB>,
     V: QueryFragment<DB> + AllowFilterForUpdate<U>,
-    Ret: QueryFragment<DB>,
+    Ret: QueryFragment<DB> + AllowReturningForUpdate<DB>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
         if self.values.is_noop(out.backend())? {
@@ -403,6 +403,8 @@ pub struct SetNotCalled;
 
 pub(crate) mod private {
     use crate::backend::Backend;
+    use crate::backend::sql_dialect::returning_clause::SupportsReturningClauseForUpdate;
+    use crate::query_builder::returning::{NoReturningClause, ReturningClause};
     use crate::query_builder::where_clause::{
         BoxedCloneWhereClause, BoxedWhereClause, NoWhereClause, WhereClause,
     };
@@ -460,6 +462,19 @@ pub(crate) mod private {
         T: AllowFilterForUpdate<BoxedCloneWhereClause<'a, DB>>,
     {
     }
+
+    /// A helper trait to mark returning clauses as compatible with `UPDATE`
+    /// statements on a given backend
+    pub trait AllowReturningForUpdate<DB> {}
+
+    impl<DB> AllowReturningForUpdate<DB> for NoReturningClause {}
+
+    impl<DB, Expr> AllowReturningForUpdate<DB> for ReturningClause<Expr>
+    where
+        DB: Backend,
+        DB::ReturningClause: SupportsReturningClauseForUpdate,
+    {
+    }
 }
 
 /// Determines when the `SET` part of an update statement will be added to the sql.
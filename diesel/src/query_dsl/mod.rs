@@ -19,6 +19,7 @@ use crate::helper_types::*;
 use crate::query_builder::locking_clause as lock;
 use crate::query_source::{QueryRelation, joins};
 use crate::result::QueryResult;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 mod belonging_to_dsl;
@@ -40,6 +41,7 @@ pub mod load_dsl;
 mod locking_dsl;
 mod nullable_select_dsl;
 mod offset_dsl;
+mod optimizer_hint_dsl;
 pub(crate) mod order_dsl;
 #[doc(hidden)]
 pub mod positional_order_dsl;
@@ -76,6 +78,7 @@ pub mod methods {
     pub use super::locking_dsl::{LockingDsl, ModifyLockDsl};
     pub use super::nullable_select_dsl::SelectNullableDsl;
     pub use super::offset_dsl::OffsetDsl;
+    pub use super::optimizer_hint_dsl::OptimizerHintDsl;
     pub use super::order_dsl::{OrderDsl, ThenOrderDsl};
     pub use super::select_dsl::SelectDsl;
     pub use super::single_value_dsl::SingleValueDsl;
@@ -1509,6 +1512,58 @@ pub trait QueryDsl: Sized {
     {
         methods::SelectNullableDsl::nullable(self)
     }
+
+    /// Attaches an optimizer hint to the query
+    ///
+    /// The hint is rendered as `/*+ hint */` comment at the position the
+    /// backend expects it:
+    ///
+    /// * MySQL and MariaDB only recognize hints directly after the `SELECT`
+    ///   keyword, so the query is rendered as `SELECT /*+ hint */ …`.
+    ///   On these backends optimizer hints can only be attached to
+    ///   unboxed queries.
+    /// * PostgreSQL extensions like [`pg_hint_plan`] expect the hint in front of the query,
+    ///   so the query is rendered as `/*+ hint */ SELECT …`.
+    ///   Without such an extension the hint is just a comment.
+    /// * SQLite does not support optimizer hints, the hint is rendered as
+    ///   leading comment there as well.
+    ///
+    /// The hint text is inserted verbatim, so it must not contain `*/`.
+    /// Executing a query with such a hint returns an error.
+    ///
+    /// This method needs to be called after all other query builder methods,
+    /// as the returned query does not implement `QueryDsl` anymore.
+    ///
+    /// [`pg_hint_plan`]: https://github.com/ossc-db/pg_hint_plan
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use schema::users;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut establish_connection();
+    /// let names = users::table
+    ///     .select(users::name)
+    ///     .order(users::id)
+    ///     .optimizer_hint("MAX_EXECUTION_TIME(1000)")
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn optimizer_hint<H>(self, hint: H) -> OptimizerHint<Self>
+    where
+        H: Into<String>,
+        Self: methods::OptimizerHintDsl,
+    {
+        methods::OptimizerHintDsl::optimizer_hint(self, hint.into())
+    }
 }
 
 #[diagnostic::do_not_recommend]
@@ -0,0 +1,29 @@
+use alloc::string::String;
+
+use crate::query_builder::{AsQuery, OptimizerHint};
+
+/// The `optimizer_hint` method
+///
+/// This trait should not be relied on directly by most apps. Its behavior is
+/// provided by [`QueryDsl`]. However, you may need a where clause on this trait
+/// to call `optimizer_hint` from generic code.
+///
+/// [`QueryDsl`]: crate::QueryDsl
+pub trait OptimizerHintDsl {
+    /// The type returned by `.optimizer_hint`.
+    type Output;
+
+    /// See the trait documentation.
+    fn optimizer_hint(self, hint: String) -> Self::Output;
+}
+
+impl<T> OptimizerHintDsl for T
+where
+    T: AsQuery,
+{
+    type Output = OptimizerHint<T::Query>;
+
+    fn optimizer_hint(self, hint: String) -> Self::Output {
+        OptimizerHint::new(self.as_query(), hint)
+    }
+}
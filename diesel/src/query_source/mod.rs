@@ -7,6 +7,7 @@
 pub(crate) mod aliasing;
 pub(crate) mod joins;
 mod peano_numbers;
+pub(crate) mod table_function;
 use crate::expression::{Expression, SelectableExpression, ValidGrouping};
 use crate::query_builder::nodes::StaticQueryFragment;
 use crate::query_builder::*;
@@ -15,6 +16,7 @@ pub use self::aliasing::{Alias, AliasSource, AliasedField};
 pub use self::joins::JoinTo;
 pub use self::peano_numbers::*;
 pub(crate) use self::private::Pick;
+pub use self::table_function::{TableFunction, TableFunctionDefinition};
 
 /// Represents a type which can appear in the `FROM` clause. Apps should not
 /// need to concern themselves with this trait.
@@ -0,0 +1,461 @@
+//! Set-returning SQL functions used as query sources
+//!
+//! See the `#[table_function]` attribute of
+//! [`#[declare_sql_function]`](crate::declare_sql_function) for more details
+
+use crate::backend::Backend;
+use crate::dsl;
+use crate::expression::{Expression, SelectableExpression, TypedExpressionType, ValidGrouping};
+#[cfg(any(
+    feature = "postgres_backend",
+    feature = "__sqlite-shared",
+    feature = "mysql_backend",
+    feature = "mariadb_backend"
+))]
+use crate::query_builder::QueryFragment;
+use crate::query_builder::from_clause::NoFromClause;
+use crate::query_builder::{AsQuery, AstPass, FromClause, QueryId, SelectStatement};
+use crate::query_dsl::methods::*;
+use crate::query_dsl::{InternalJoinDsl, QueryDsl, RunQueryDslSupport};
+use crate::query_source::aliasing::AliasAppearsInFromClause;
+use crate::query_source::joins::{AppendSelection, JoinTo, OnClauseWrapper, ToInnerJoin};
+use crate::query_source::{
+    Alias, AliasSource, AppearsInFromClause, Never, Once, QueryRelation, QuerySource,
+};
+use crate::result::QueryResult;
+
+use core::marker::PhantomData;
+
+/// Represents a call to a set-returning SQL function in the `FROM` clause
+/// of a query
+///
+/// Values of this type are returned by functions declared with the
+/// `#[table_function]` attribute of
+/// [`#[declare_sql_function]`](crate::declare_sql_function).
+/// `F` identifies the function, `Args` contains the arguments of this call.
+///
+/// The columns of a table function only refer to `F`, therefore a query can
+/// contain at most one call to a specific table function. Using several
+/// different table functions in the same query is not supported.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct TableFunction<F, Args = ()> {
+    // only rendered by the backend specific `QueryFragment` impls
+    #[cfg_attr(
+        not(any(
+            feature = "postgres_backend",
+            feature = "__sqlite-shared",
+            feature = "mysql_backend",
+            feature = "mariadb_backend"
+        )),
+        allow(dead_code)
+    )]
+    args: Args,
+    function: PhantomData<F>,
+}
+
+impl<F, Args> TableFunction<F, Args> {
+    #[doc(hidden)]
+    /// Used by the code generated by `#[declare_sql_function]`
+    pub const fn new(args: Args) -> Self {
+        Self {
+            args,
+            function: PhantomData,
+        }
+    }
+}
+
+impl<F, Args> TableFunction<F, Args>
+where
+    F: TableFunctionDefinition,
+{
+    /// Returns all columns of this table function
+    ///
+    /// This is a single column or a tuple of columns, depending on the
+    /// declaration of the function
+    pub fn columns(&self) -> F::Columns {
+        F::columns()
+    }
+}
+
+/// Describes a set-returning SQL function declared with the
+/// `#[table_function]` attribute of
+/// [`#[declare_sql_function]`](crate::declare_sql_function)
+///
+/// This trait should never be implemented by an end-user directly.
+pub trait TableFunctionDefinition {
+    /// The name of the SQL function
+    const FUNCTION_NAME: &'static str;
+    /// The name of the function call in the query
+    ///
+    /// Columns of the function are qualified with this name
+    const NAME: &'static str;
+    /// The type returned by `columns`
+    type Columns;
+    /// Returns all columns of this table function
+    fn columns() -> Self::Columns;
+}
+
+#[doc(hidden)]
+/// Writes the column definition list following the alias of a table function,
+/// e.g. `("key", "value")` or `("a" int4, "b" text)`
+pub trait TableFunctionColumnList<DB: Backend> {
+    fn walk_column_list<'b>(out: AstPass<'_, 'b, DB>) -> QueryResult<()>;
+}
+
+#[doc(hidden)]
+/// Writes the column list of a `JSON_TABLE` call, e.g.
+/// `` `a` INTEGER PATH '$."a"', `b` TEXT PATH '$."b"' ``
+pub trait JsonTableColumnList<DB: Backend> {
+    fn walk_json_table_columns<'b>(out: AstPass<'_, 'b, DB>) -> QueryResult<()>;
+}
+
+#[doc(hidden)]
+/// The name of the SQL type `Self` in the column list of `JSON_TABLE`
+pub trait JsonTableColumnType<DB> {
+    const JSON_TABLE_TYPE_NAME: &'static str;
+}
+
+impl<ST, DB> JsonTableColumnType<DB> for crate::sql_types::Nullable<ST>
+where
+    ST: JsonTableColumnType<DB>,
+{
+    const JSON_TABLE_TYPE_NAME: &'static str = ST::JSON_TABLE_TYPE_NAME;
+}
+
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+macro_rules! json_table_column_type {
+    ($($type: ty => $name: literal,)+) => {
+        $(
+            impl<DB: crate::mysql_like::MysqlLikeBackend> JsonTableColumnType<DB> for $type {
+                const JSON_TABLE_TYPE_NAME: &'static str = $name;
+            }
+        )+
+    };
+}
+
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+json_table_column_type! {
+    crate::sql_types::Bool => "BOOLEAN",
+    crate::sql_types::TinyInt => "TINYINT",
+    crate::sql_types::SmallInt => "SMALLINT",
+    crate::sql_types::Integer => "INTEGER",
+    crate::sql_types::BigInt => "BIGINT",
+    crate::mysql_like::sql_types::Unsigned<crate::sql_types::TinyInt> => "TINYINT UNSIGNED",
+    crate::mysql_like::sql_types::Unsigned<crate::sql_types::SmallInt> => "SMALLINT UNSIGNED",
+    crate::mysql_like::sql_types::Unsigned<crate::sql_types::Integer> => "INTEGER UNSIGNED",
+    crate::mysql_like::sql_types::Unsigned<crate::sql_types::BigInt> => "BIGINT UNSIGNED",
+    crate::sql_types::Float => "FLOAT",
+    crate::sql_types::Double => "DOUBLE",
+    crate::sql_types::Numeric => "DECIMAL(65, 30)",
+    crate::sql_types::Text => "TEXT",
+    crate::sql_types::Binary => "BLOB",
+    crate::sql_types::Date => "DATE",
+    crate::sql_types::Time => "TIME",
+    crate::sql_types::Timestamp => "TIMESTAMP",
+    crate::mysql_like::sql_types::Datetime => "DATETIME",
+    crate::sql_types::Json => "JSON",
+}
+
+impl<F, Args> QuerySource for TableFunction<F, Args>
+where
+    Self: Clone,
+    F: TableFunctionDefinition,
+    F::Columns: SelectableExpression<Self>,
+{
+    type FromClause = Self;
+    type DefaultSelection = F::Columns;
+
+    fn from_clause(&self) -> Self::FromClause {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        F::columns()
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl<F, Args> QueryFragment<crate::pg::Pg> for TableFunction<F, Args>
+where
+    F: TableFunctionDefinition + TableFunctionColumnList<crate::pg::Pg>,
+    Args: QueryFragment<crate::pg::Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, crate::pg::Pg>) -> QueryResult<()> {
+        out.push_sql(F::FUNCTION_NAME);
+        out.push_sql("(");
+        self.args.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        out.push_identifier(F::NAME)?;
+        out.push_sql("(");
+        F::walk_column_list(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+// SQLite does not support column definition lists, the columns
+// of a table-valued function always use the names of the function
+#[cfg(feature = "__sqlite-shared")]
+impl<F, Args> QueryFragment<crate::sqlite::Sqlite> for TableFunction<F, Args>
+where
+    F: TableFunctionDefinition,
+    Args: QueryFragment<crate::sqlite::Sqlite>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, crate::sqlite::Sqlite>) -> QueryResult<()> {
+        out.push_sql(F::FUNCTION_NAME);
+        out.push_sql("(");
+        self.args.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        out.push_identifier(F::NAME)?;
+        Ok(())
+    }
+}
+
+// MySQL and MariaDB only support `JSON_TABLE`, which expects the row path
+// and the definition of each column inside of the function call. Therefore
+// only table functions with a single JSON argument are supported
+#[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
+#[diagnostic::do_not_recommend]
+impl<F, Doc, DB> QueryFragment<DB> for TableFunction<F, (Doc,)>
+where
+    DB: crate::mysql_like::MysqlLikeBackend,
+    F: TableFunctionDefinition + JsonTableColumnList<DB>,
+    Doc: Expression + QueryFragment<DB>,
+    Doc::SqlType: crate::mysql_like::expression::expression_methods::JsonOrNullableJson,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("JSON_TABLE(");
+        self.args.0.walk_ast(out.reborrow())?;
+        out.push_sql(", '$[*]' COLUMNS (");
+        F::walk_json_table_columns(out.reborrow())?;
+        out.push_sql(")) AS ");
+        out.push_identifier(F::NAME)?;
+        Ok(())
+    }
+}
+
+impl<F, Args> AsQuery for TableFunction<F, Args>
+where
+    Self: QuerySource,
+    <Self as QuerySource>::DefaultSelection: ValidGrouping<()>,
+{
+    type SqlType = <<Self as QuerySource>::DefaultSelection as Expression>::SqlType;
+    type Query = SelectStatement<FromClause<Self>>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<F, Args> AppearsInFromClause<TableFunction<F>> for TableFunction<F, Args> {
+    type Count = Once;
+}
+
+impl<F, Args, T> AppearsInFromClause<T> for TableFunction<F, Args>
+where
+    T: QueryRelation,
+{
+    type Count = Never;
+}
+
+impl<F, Args, S> AppearsInFromClause<Alias<S>> for TableFunction<F, Args>
+where
+    S: AliasSource,
+{
+    type Count = Never;
+}
+
+impl<T, F> AppearsInFromClause<TableFunction<F>> for T
+where
+    T: QueryRelation,
+{
+    type Count = Never;
+}
+
+// impl<S: AliasSource> AppearsInFromClause<TableFunction<F>> for Alias<S>
+impl<T, S, F> AliasAppearsInFromClause<S, TableFunction<F>> for T
+where
+    T: QueryRelation,
+    S: AliasSource<Target = T>,
+{
+    type Count = Never;
+}
+
+impl<F> AppearsInFromClause<TableFunction<F>> for NoFromClause {
+    type Count = Never;
+}
+
+impl<F, Args, Rhs, On> JoinTo<OnClauseWrapper<Rhs, On>> for TableFunction<F, Args> {
+    type FromClause = Rhs;
+    type OnClause = On;
+
+    fn join_target(rhs: OnClauseWrapper<Rhs, On>) -> (Self::FromClause, Self::OnClause) {
+        (rhs.source, rhs.on)
+    }
+}
+
+impl<F, Args> ToInnerJoin for TableFunction<F, Args> {
+    type InnerJoin = Self;
+}
+
+impl<F, Args, Selection> AppendSelection<Selection> for TableFunction<F, Args>
+where
+    Self: QuerySource,
+{
+    type Output = (<Self as QuerySource>::DefaultSelection, Selection);
+
+    fn append_selection(&self, selection: Selection) -> Self::Output {
+        (self.default_selection(), selection)
+    }
+}
+
+impl<F, Args, Rhs, Kind, On> InternalJoinDsl<Rhs, Kind, On> for TableFunction<F, Args>
+where
+    Self: AsQuery,
+    <Self as AsQuery>::Query: InternalJoinDsl<Rhs, Kind, On>,
+{
+    type Output = <<Self as AsQuery>::Query as InternalJoinDsl<Rhs, Kind, On>>::Output;
+
+    fn join(self, rhs: Rhs, kind: Kind, on: On) -> Self::Output {
+        self.as_query().join(rhs, kind, on)
+    }
+}
+
+impl<F, Args> QueryDsl for TableFunction<F, Args> {}
+
+impl<F, Args> RunQueryDslSupport for TableFunction<F, Args> {}
+
+impl<F, Args, Predicate> FilterDsl<Predicate> for TableFunction<F, Args>
+where
+    Self: AsQuery,
+    <Self as AsQuery>::Query: FilterDsl<Predicate>,
+{
+    type Output = dsl::Filter<<Self as AsQuery>::Query, Predicate>;
+
+    fn filter(self, predicate: Predicate) -> Self::Output {
+        self.as_query().filter(predicate)
+    }
+}
+
+impl<F, Args, Predicate> OrFilterDsl<Predicate> for TableFunction<F, Args>
+where
+    Self: AsQuery,
+    <Self as AsQuery>::Query: OrFilterDsl<Predicate>,
+{
+    type Output = dsl::OrFilter<<Self as AsQuery>::Query, Predicate>;
+
+    fn or_filter(self, predicate: Predicate) -> Self::Output {
+        self.as_query().or_filter(predicate)
+    }
+}
+
+impl<F, Args, Selection> SelectDsl<Selection> for TableFunction<F, Args>
+where
+    Selection: Expression,
+    Self: AsQuery,
+    <Self as AsQuery>::Query: SelectDsl<Selection>,
+{
+    type Output = dsl::Select<<Self as AsQuery>::Query, Selection>;
+
+    fn select(self, selection: Selection) -> Self::Output {
+        self.as_query().select(selection)
+    }
+}
+
+impl<'a, F, Args, DB> BoxedDsl<'a, DB> for TableFunction<F, Args>
+where
+    Self: QuerySource + AsQuery<Query = SelectStatement<FromClause<Self>>>,
+    SelectStatement<FromClause<Self>>: BoxedDsl<'a, DB>,
+    <Self as QuerySource>::DefaultSelection:
+        Expression<SqlType = <Self as AsQuery>::SqlType> + ValidGrouping<()>,
+    <Self as AsQuery>::SqlType: TypedExpressionType,
+{
+    type Output = dsl::IntoBoxed<'a, SelectStatement<FromClause<Self>>, DB>;
+
+    fn internal_into_boxed(self) -> Self::Output {
+        self.as_query().internal_into_boxed()
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl<F, Args, Selection> DistinctOnDsl<Selection> for TableFunction<F, Args>
+where
+    Selection: SelectableExpression<Self>,
+    Self: QuerySource + AsQuery<Query = SelectStatement<FromClause<Self>>>,
+    SelectStatement<FromClause<Self>>: DistinctOnDsl<Selection>,
+    <Self as QuerySource>::DefaultSelection:
+        Expression<SqlType = <Self as AsQuery>::SqlType> + ValidGrouping<()>,
+    <Self as AsQuery>::SqlType: TypedExpressionType,
+{
+    type Output = dsl::DistinctOn<SelectStatement<FromClause<Self>>, Selection>;
+
+    fn distinct_on(self, selection: Selection) -> dsl::DistinctOn<Self, Selection> {
+        DistinctOnDsl::distinct_on(self.as_query(), selection)
+    }
+}
+
+impl<F, Args, Expr> GroupByDsl<Expr> for TableFunction<F, Args>
+where
+    Expr: Expression,
+    Self: QuerySource + AsQuery<Query = SelectStatement<FromClause<Self>>>,
+    <Self as QuerySource>::DefaultSelection:
+        Expression<SqlType = <Self as AsQuery>::SqlType> + ValidGrouping<()>,
+    <Self as AsQuery>::SqlType: TypedExpressionType,
+    <Self as AsQuery>::Query: GroupByDsl<Expr>,
+{
+    type Output = dsl::GroupBy<SelectStatement<FromClause<Self>>, Expr>;
+
+    fn group_by(self, expr: Expr) -> dsl::GroupBy<Self, Expr> {
+        GroupByDsl::group_by(self.as_query(), expr)
+    }
+}
+
+impl<F, Args> LimitDsl for TableFunction<F, Args>
+where
+    Self: AsQuery,
+    <Self as AsQuery>::Query: LimitDsl,
+{
+    type Output = <<Self as AsQuery>::Query as LimitDsl>::Output;
+
+    fn limit(self, limit: i64) -> Self::Output {
+        self.as_query().limit(limit)
+    }
+}
+
+impl<F, Args> OffsetDsl for TableFunction<F, Args>
+where
+    Self: AsQuery,
+    <Self as AsQuery>::Query: OffsetDsl,
+{
+    type Output = <<Self as AsQuery>::Query as OffsetDsl>::Output;
+
+    fn offset(self, offset: i64) -> Self::Output {
+        self.as_query().offset(offset)
+    }
+}
+
+impl<F, Args, Expr> OrderDsl<Expr> for TableFunction<F, Args>
+where
+    Expr: Expression,
+    Self: AsQuery,
+    <Self as AsQuery>::Query: OrderDsl<Expr>,
+{
+    type Output = <<Self as AsQuery>::Query as OrderDsl<Expr>>::Output;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        self.as_query().order(expr)
+    }
+}
+
+impl<F, Args, Expr> ThenOrderDsl<Expr> for TableFunction<F, Args>
+where
+    Expr: Expression,
+    Self: AsQuery,
+    <Self as AsQuery>::Query: ThenOrderDsl<Expr>,
+{
+    type Output = <<Self as AsQuery>::Query as ThenOrderDsl<Expr>>::Output;
+
+    fn then_order_by(self, expr: Expr) -> Self::Output {
+        self.as_query().then_order_by(expr)
+    }
+}
@@ -17,6 +17,7 @@ macro_rules! make_proxy_mod {
 
             // Drop unintended types
             type abbrev = ();
+            type array_agg = ();
             type array_append = ();
             type array_cat = ();
             type array_dims = ();
@@ -38,11 +39,14 @@ macro_rules! make_proxy_mod {
             type array_to_string = ();
             type array_upper = ();
             type avg = ();
+            type bool_and = ();
+            type bool_or = ();
             type broadcast = ();
             type cardinality = ();
             type daterange = ();
             type family = ();
             type first_value = ();
+            type get_bit = ();
             type host = ();
             type hostmask = ();
             type inet_merge = ();
@@ -50,6 +54,7 @@ macro_rules! make_proxy_mod {
             type int4range = ();
             type int8range = ();
             type isempty = ();
+            type json_agg = ();
             type json_array_length = ();
             type json_build_array_0 = ();
             type json_build_array_1 = ();
@@ -63,6 +68,7 @@ macro_rules! make_proxy_mod {
             type json_populate_record = ();
             type json_strip_nulls = ();
             type json_typeof = ();
+            type jsonb_agg = ();
             type jsonb_array_length = ();
             type jsonb_build_array_0 = ();
             type jsonb_build_array_1 = ();
@@ -86,34 +92,63 @@ macro_rules! make_proxy_mod {
             type lag_with_offset = ();
             type lag = ();
             type last_value = ();
+            type lca = ();
             type lead_with_offset_and_default = ();
             type lead_with_offset = ();
             type lead = ();
             type lower_inc = ();
             type lower_inf = ();
             type lower = ();
+            type ltree2text = ();
             type masklen = ();
             type max = ();
             type min = ();
             type multirange_merge = ();
             type netmask = ();
             type network = ();
+            type nlevel = ();
             type nth_value = ();
             type numrange = ();
+            type phraseto_tsquery_with_config = ();
+            type phraseto_tsquery = ();
+            type plainto_tsquery_with_config = ();
+            type plainto_tsquery = ();
             type range_merge = ();
             type row_to_json = ();
+            type set_bit = ();
             type set_masklen = ();
+            type stddev = ();
+            type stddev_pop = ();
+            type stddev_samp = ();
+            type string_agg = ();
+            type subltree = ();
+            type subpath_with_length = ();
+            type subpath = ();
             type sum = ();
+            type text2ltree = ();
             type to_json = ();
             type to_jsonb = ();
+            type to_tsquery_with_config = ();
+            type to_tsquery = ();
+            type to_tsvector_with_config = ();
+            type to_tsvector = ();
             type trim_array = ();
+            type ts_headline = ();
+            type ts_rank = ();
             type tsrange = ();
             type tstzrange = ();
             type upper_inc = ();
             type upper_inf = ();
             type upper = ();
+            type var_pop = ();
+            type var_samp = ();
+            type variance = ();
+            type websearch_to_tsquery_with_config = ();
+            type websearch_to_tsquery = ();
 
             // sqlite
+            type group_concat = ();
+            type group_concat_with_separator = ();
             type json = ();
             type json_array_0 = ();
             type json_array_1 = ();
@@ -177,6 +212,30 @@ macro_rules! make_proxy_mod {
             type jsonb_set_0 = ();
             type jsonb_set_1 = ();
             type jsonb_set_2 = ();
+
+            // mysql
+            type find_in_set = ();
+            type json_arrayagg = ();
+            type json_contains_path_1 = ();
+            type json_contains_path_2 = ();
+            type json_contains_with_path = ();
+            type json_contains = ();
+            type json_extract = ();
+            type json_keys = ();
+            type json_length_with_path = ();
+            type json_length = ();
+            type json_objectagg = ();
+            type json_unquote = ();
+            type st_as_text = ();
+            type st_contains = ();
+            type st_distance_sphere = ();
+            type st_distance = ();
+            type st_geom_from_text = ();
+            type st_intersects = ();
+            type st_srid = ();
+            type st_within = ();
+            type st_x = ();
+            type st_y = ();
         }
     };
 }
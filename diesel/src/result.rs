@@ -131,8 +131,8 @@ pub enum DatabaseErrorKind {
     ///
     /// Corresponds to SQLSTATE code 40001
     ///
-    /// This error is only detected for PostgreSQL, as we do not yet support
-    /// transaction isolation levels for other backends.
+    /// This error is detected for PostgreSQL, and for deadlocks (error 1213)
+    /// on MySQL and MariaDB. The failed transaction can be retried.
     SerializationFailure = 3,
 
     /// The command could not be completed because the transaction was read
@@ -160,6 +160,14 @@ pub enum DatabaseErrorKind {
     /// and may be missed.
     ClosedConnection = 7,
 
+    /// A lock could not be acquired before the lock wait timeout expired.
+    ///
+    /// Only the failed statement is rolled back, the transaction can be
+    /// retried as a whole.
+    ///
+    /// This error is only detected for MySQL and MariaDB (error 1205).
+    LockWaitTimeout = 11,
+
     #[doc(hidden)]
     Unknown = 8, // Match against _ instead, more variants may be added in the future
 }
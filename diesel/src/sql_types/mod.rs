@@ -567,7 +567,7 @@ where
 
 #[doc(inline)]
 #[cfg(feature = "postgres_backend")]
-pub use crate::pg::sql_types::*;
+pub use crate::pg::types::sql_types::*;
 
 #[doc(inline)]
 #[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
@@ -724,6 +724,18 @@ pub trait EnumSqlType<const HAS_EXPLICIT_DISCRIMINANT: bool, DB: Backend>: SqlTy
     type Strategy: crate::types::enum_::EnumMapping<DB>;
 }
 
+/// A marker trait for SQL types representing MySQL or MariaDB `SET` columns
+///
+/// The members of such a set are mapped by an enum deriving
+/// [`#[derive(Enum)]`](crate::types::Enum) for this SQL type, while
+/// a [`BTreeSet`](std::collections::BTreeSet) of these enums represents the whole set.
+///
+/// # Deriving
+///
+/// This trait can be automatically derived by using [`#[derive(SqlType)]`](derive@SqlType)
+/// with the `#[diesel(set_type)]` attribute
+pub trait SetSqlType: SqlType + SingleValue {}
+
 /// Is one value of `IsNull` nullable?
 ///
 /// You should never implement this trait.
@@ -864,3 +876,41 @@ pub(crate) mod helper {
         type Out = <<T::IsNull as OneIsNullable<O::IsNull>>::Out as MaybeNullableType<Out>>::Out;
     }
 }
+
+#[cfg(all(
+    test,
+    feature = "postgres_backend",
+    any(feature = "mysql_backend", feature = "mariadb_backend")
+))]
+mod tests {
+    use crate::deserialize::FromSqlRow;
+    use crate::pg::Pg;
+    use crate::pg::data_types::{PgPoint, PgPolygon};
+    use crate::prelude::*;
+    use crate::query_builder::Query;
+
+    // `diesel print-schema` uses these names together with
+    // `use diesel::sql_types::*` for PostgreSQL schemas, while
+    // `Bit` still refers to `Binary` as in existing schemas
+    table! {
+        places {
+            id -> Integer,
+            location -> Point,
+            boundary -> Nullable<Polygon>,
+            flags -> Bit,
+        }
+    }
+
+    fn assert_loadable<Q, U>(_: Q)
+    where
+        Q: Query,
+        U: FromSqlRow<Q::SqlType, Pg>,
+    {
+    }
+
+    #[diesel_test_helper::test]
+    fn postgres_types_are_used_if_several_backends_are_enabled() {
+        let query = places::table.select((places::location, places::boundary, places::flags));
+        assert_loadable::<_, (PgPoint, Option<PgPolygon>, Vec<u8>)>(query);
+    }
+}
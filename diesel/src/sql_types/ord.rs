@@ -23,6 +23,8 @@ impl SqlOrd for sql_types::Timestamptz {}
 #[cfg(feature = "postgres_backend")]
 impl SqlOrd for sql_types::Citext {}
 #[cfg(feature = "postgres_backend")]
+impl SqlOrd for sql_types::Ltree {}
+#[cfg(feature = "postgres_backend")]
 impl<T: SqlOrd> SqlOrd for sql_types::Array<T> {}
 
 #[cfg(any(feature = "mysql_backend", feature = "mariadb_backend"))]
@@ -97,6 +97,7 @@ pub struct SqliteBatchInsert;
 pub struct SqliteReturningClause;
 
 impl sql_dialect::returning_clause::SupportsReturningClause for SqliteReturningClause {}
+impl sql_dialect::returning_clause::SupportsReturningClauseForUpdate for SqliteReturningClause {}
 
 #[derive(Debug, Clone, Copy)]
 pub struct SqliteBatchUpdate;
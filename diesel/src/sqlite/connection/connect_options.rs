@@ -0,0 +1,338 @@
+//! Options for opening a [`SqliteConnection`](super::SqliteConnection).
+//!
+//! See [`SqliteConnection::establish_with_options`](super::SqliteConnection::establish_with_options)
+//! for usage.
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+extern crate libsqlite3_sys as ffi;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use sqlite_wasm_rs as ffi;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi as libc;
+
+/// The threading mode a connection is opened with.
+///
+/// See: [`sqlite3_open_v2`](https://www.sqlite.org/c3ref/open.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SqliteThreadingMode {
+    /// Open the connection in multi-thread mode (`SQLITE_OPEN_NOMUTEX`).
+    /// The connection does not serialize access to itself, which diesel
+    /// already guarantees by requiring `&mut SqliteConnection`.
+    NoMutex,
+    /// Open the connection in serialized mode (`SQLITE_OPEN_FULLMUTEX`).
+    FullMutex,
+}
+
+/// The [`journal_mode`](https://www.sqlite.org/pragma.html#pragma_journal_mode)
+/// set right after a connection is opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SqliteJournalMode {
+    /// Delete the rollback journal at the end of each transaction (default).
+    Delete,
+    /// Truncate the rollback journal instead of deleting it.
+    Truncate,
+    /// Keep the rollback journal and overwrite its header instead.
+    Persist,
+    /// Keep the rollback journal in memory.
+    Memory,
+    /// Use a [write-ahead log](https://www.sqlite.org/wal.html).
+    Wal,
+    /// Disable the rollback journal.
+    Off,
+}
+
+impl SqliteJournalMode {
+    fn as_sql(self) -> &'static str {
+        match self {
+            SqliteJournalMode::Delete => "DELETE",
+            SqliteJournalMode::Truncate => "TRUNCATE",
+            SqliteJournalMode::Persist => "PERSIST",
+            SqliteJournalMode::Memory => "MEMORY",
+            SqliteJournalMode::Wal => "WAL",
+            SqliteJournalMode::Off => "OFF",
+        }
+    }
+}
+
+/// The [`synchronous`](https://www.sqlite.org/pragma.html#pragma_synchronous)
+/// flag set right after a connection is opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SqliteSynchronous {
+    /// Hand data to the operating system without syncing.
+    Off,
+    /// Sync at the most critical moments. Safe in WAL mode.
+    Normal,
+    /// Sync at every critical moment (default).
+    Full,
+    /// Like [`Full`](Self::Full), also syncing the directory of a deleted
+    /// rollback journal.
+    Extra,
+}
+
+impl SqliteSynchronous {
+    fn as_sql(self) -> &'static str {
+        match self {
+            SqliteSynchronous::Off => "OFF",
+            SqliteSynchronous::Normal => "NORMAL",
+            SqliteSynchronous::Full => "FULL",
+            SqliteSynchronous::Extra => "EXTRA",
+        }
+    }
+}
+
+/// Options controlling how a [`SqliteConnection`](super::SqliteConnection) is
+/// opened, passed to
+/// [`establish_with_options`](super::SqliteConnection::establish_with_options).
+///
+/// The defaults match [`Connection::establish`](crate::Connection::establish):
+/// a read-write connection that creates the database if it does not exist,
+/// with URI filenames enabled and no initial pragmas.
+///
+/// ```rust
+/// # use diesel::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous};
+/// let options = SqliteConnectOptions::new()
+///     .busy_timeout(2000)
+///     .journal_mode(SqliteJournalMode::Wal)
+///     .synchronous(SqliteSynchronous::Normal);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqliteConnectOptions {
+    read_only: bool,
+    create: bool,
+    immutable: bool,
+    threading_mode: Option<SqliteThreadingMode>,
+    vfs: Option<String>,
+    busy_timeout: Option<i32>,
+    journal_mode: Option<SqliteJournalMode>,
+    synchronous: Option<SqliteSynchronous>,
+    pragmas: Vec<(String, String)>,
+}
+
+impl SqliteConnectOptions {
+    /// Creates options matching the behaviour of
+    /// [`Connection::establish`](crate::Connection::establish).
+    pub fn new() -> Self {
+        SqliteConnectOptions {
+            read_only: false,
+            create: true,
+            immutable: false,
+            threading_mode: None,
+            vfs: None,
+            busy_timeout: None,
+            journal_mode: None,
+            synchronous: None,
+            pragmas: Vec::new(),
+        }
+    }
+
+    /// Opens the database read-only (`SQLITE_OPEN_READONLY`). A read-only
+    /// connection never creates the database.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Whether to create the database if it does not exist
+    /// (`SQLITE_OPEN_CREATE`). Defaults to `true`.
+    pub fn create_if_missing(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+
+    /// Marks the database file as
+    /// [immutable](https://www.sqlite.org/uri.html#uriimmutable), so SQLite
+    /// skips all locking and change detection. Only use this for files that
+    /// cannot change while the connection is open, for example on read-only
+    /// media. Implies [`read_only`](Self::read_only).
+    pub fn immutable(mut self, immutable: bool) -> Self {
+        self.immutable = immutable;
+        self
+    }
+
+    /// Sets the threading mode of the connection. Defaults to the mode SQLite
+    /// was compiled or configured with.
+    pub fn threading_mode(mut self, mode: SqliteThreadingMode) -> Self {
+        self.threading_mode = Some(mode);
+        self
+    }
+
+    /// Opens the database through the [VFS](https://www.sqlite.org/vfs.html)
+    /// registered under `name` instead of the default one.
+    pub fn vfs(mut self, name: impl Into<String>) -> Self {
+        self.vfs = Some(name.into());
+        self
+    }
+
+    /// Sets a [busy timeout](https://www.sqlite.org/c3ref/busy_timeout.html)
+    /// of `ms` milliseconds. It is applied before any other initial pragma,
+    /// so those do not fail on a locked database.
+    pub fn busy_timeout(mut self, ms: i32) -> Self {
+        self.busy_timeout = Some(ms);
+        self
+    }
+
+    /// Sets the journal mode right after opening the connection.
+    pub fn journal_mode(mut self, mode: SqliteJournalMode) -> Self {
+        self.journal_mode = Some(mode);
+        self
+    }
+
+    /// Sets the `synchronous` flag right after opening the connection.
+    pub fn synchronous(mut self, synchronous: SqliteSynchronous) -> Self {
+        self.synchronous = Some(synchronous);
+        self
+    }
+
+    /// Runs `PRAGMA name = value` right after opening the connection, after
+    /// the typed pragmas above and in the order the pragmas were added.
+    ///
+    /// Both parts are inserted into the statement verbatim, so they must not
+    /// come from untrusted input.
+    pub fn pragma(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.pragmas.push((name.into(), value.into()));
+        self
+    }
+
+    pub(super) fn open_flags(&self) -> libc::c_int {
+        let mut flags = ffi::SQLITE_OPEN_URI;
+        if self.read_only || self.immutable {
+            flags |= ffi::SQLITE_OPEN_READONLY;
+        } else {
+            flags |= ffi::SQLITE_OPEN_READWRITE;
+            if self.create {
+                flags |= ffi::SQLITE_OPEN_CREATE;
+            }
+        }
+        match self.threading_mode {
+            Some(SqliteThreadingMode::NoMutex) => flags |= ffi::SQLITE_OPEN_NOMUTEX,
+            Some(SqliteThreadingMode::FullMutex) => flags |= ffi::SQLITE_OPEN_FULLMUTEX,
+            None => {}
+        }
+        flags
+    }
+
+    pub(super) fn vfs_name(&self) -> Option<&str> {
+        self.vfs.as_deref()
+    }
+
+    pub(super) fn busy_timeout_ms(&self) -> Option<i32> {
+        self.busy_timeout
+    }
+
+    /// Rewrites `database_url` into a URI with `immutable=1` if requested.
+    pub(super) fn database_url<'a>(&self, database_url: &'a str) -> Cow<'a, str> {
+        if !self.immutable {
+            return Cow::Borrowed(database_url);
+        }
+        let uri = if let Some(uri) = database_url.strip_prefix("sqlite://") {
+            alloc::format!("file:{uri}")
+        } else if database_url.starts_with("file:") {
+            String::from(database_url)
+        } else {
+            // a plain path, escape the characters with a special meaning in URIs
+            let mut uri = String::from("file:");
+            for c in database_url.chars() {
+                match c {
+                    '%' => uri.push_str("%25"),
+                    '?' => uri.push_str("%3f"),
+                    '#' => uri.push_str("%23"),
+                    c => uri.push(c),
+                }
+            }
+            uri
+        };
+        let separator = if uri.contains('?') { '&' } else { '?' };
+        Cow::Owned(alloc::format!("{uri}{separator}immutable=1"))
+    }
+
+    /// The `PRAGMA` statements to run after the connection is opened.
+    pub(super) fn initial_pragmas(&self) -> impl Iterator<Item = String> + '_ {
+        let journal_mode = self
+            .journal_mode
+            .map(|m| alloc::format!("PRAGMA journal_mode = {}", m.as_sql()));
+        let synchronous = self
+            .synchronous
+            .map(|s| alloc::format!("PRAGMA synchronous = {}", s.as_sql()));
+        journal_mode.into_iter().chain(synchronous).chain(
+            self.pragmas
+                .iter()
+                .map(|(name, value)| alloc::format!("PRAGMA {name} = {value}")),
+        )
+    }
+}
+
+impl Default for SqliteConnectOptions {
+    fn default() -> Self {
+        SqliteConnectOptions::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_flags_match_establish() {
+        assert_eq!(
+            SqliteConnectOptions::new().open_flags(),
+            ffi::SQLITE_OPEN_READWRITE | ffi::SQLITE_OPEN_CREATE | ffi::SQLITE_OPEN_URI
+        );
+    }
+
+    #[test]
+    fn read_only_never_creates() {
+        let flags = SqliteConnectOptions::new()
+            .read_only(true)
+            .threading_mode(SqliteThreadingMode::NoMutex)
+            .open_flags();
+        assert_eq!(
+            flags,
+            ffi::SQLITE_OPEN_READONLY | ffi::SQLITE_OPEN_URI | ffi::SQLITE_OPEN_NOMUTEX
+        );
+    }
+
+    #[test]
+    fn immutable_rewrites_url() {
+        let options = SqliteConnectOptions::new().immutable(true);
+        assert_eq!(options.database_url("test.db"), "file:test.db?immutable=1");
+        assert_eq!(
+            options.database_url("a?b#c%.db"),
+            "file:a%3fb%23c%25.db?immutable=1"
+        );
+        assert_eq!(
+            options.database_url("file:test.db?cache=shared"),
+            "file:test.db?cache=shared&immutable=1"
+        );
+        assert_eq!(
+            options.database_url("sqlite://test.db"),
+            "file:test.db?immutable=1"
+        );
+        assert_eq!(
+            SqliteConnectOptions::new().database_url("test.db"),
+            "test.db"
+        );
+    }
+
+    #[test]
+    fn initial_pragmas_keep_order() {
+        let options = SqliteConnectOptions::new()
+            .pragma("foreign_keys", "ON")
+            .synchronous(SqliteSynchronous::Normal)
+            .journal_mode(SqliteJournalMode::Wal);
+        assert_eq!(
+            options.initial_pragmas().collect::<Vec<_>>(),
+            vec![
+                "PRAGMA journal_mode = WAL",
+                "PRAGMA synchronous = NORMAL",
+                "PRAGMA foreign_keys = ON",
+            ]
+        );
+    }
+}
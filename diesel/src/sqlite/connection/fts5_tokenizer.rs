@@ -0,0 +1,282 @@
+#![allow(unsafe_code)] // ffi calls
+//! Custom tokenizers for the SQLite FTS5 extension.
+//!
+//! See [`SqliteConnection::register_fts5_tokenizer`](super::SqliteConnection::register_fts5_tokenizer)
+//! for usage.
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+extern crate libsqlite3_sys as ffi;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use sqlite_wasm_rs as ffi;
+
+use super::raw::RawConnection;
+use super::stmt::ensure_sqlite_ok;
+use crate::result::{DatabaseErrorKind, Error, QueryResult};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::string::ToString;
+use core::ffi as libc;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr::{self, NonNull};
+use core::{slice, str};
+
+/// Why FTS5 asks a tokenizer to split a text.
+///
+/// See: <https://www.sqlite.org/fts5.html#custom_tokenizers>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fts5TokenizeReason {
+    /// A document is inserted into or removed from the index.
+    Document,
+    /// A `MATCH` query is parsed.
+    Query,
+    /// A `MATCH` query is parsed and the text is followed by a `*`, so the
+    /// last token is used as a prefix.
+    Prefix,
+    /// An auxiliary function such as `highlight()` or `snippet()` tokenizes
+    /// a column value.
+    Aux,
+}
+
+impl Fts5TokenizeReason {
+    fn from_ffi(flags: libc::c_int) -> Self {
+        if flags & ffi::FTS5_TOKENIZE_DOCUMENT != 0 {
+            Fts5TokenizeReason::Document
+        } else if flags & ffi::FTS5_TOKENIZE_PREFIX != 0 {
+            Fts5TokenizeReason::Prefix
+        } else if flags & ffi::FTS5_TOKENIZE_QUERY != 0 {
+            Fts5TokenizeReason::Query
+        } else {
+            Fts5TokenizeReason::Aux
+        }
+    }
+}
+
+type TokenCallback = unsafe extern "C" fn(
+    ctx: *mut libc::c_void,
+    flags: libc::c_int,
+    token: *const libc::c_char,
+    token_len: libc::c_int,
+    start: libc::c_int,
+    end: libc::c_int,
+) -> libc::c_int;
+
+/// Receives the tokens produced by a custom FTS5 tokenizer, see
+/// [`SqliteConnection::register_fts5_tokenizer`](super::SqliteConnection::register_fts5_tokenizer).
+///
+/// If FTS5 rejects a token, for example because it ran out of memory, the
+/// error is reported once the tokenizer returns and all further tokens are
+/// ignored.
+pub struct Fts5TokenSink<'a> {
+    ctx: *mut libc::c_void,
+    callback: TokenCallback,
+    text_len: usize,
+    result: libc::c_int,
+    _marker: PhantomData<&'a mut ()>,
+}
+
+impl Fts5TokenSink<'_> {
+    /// Emits `token`, which was produced from the bytes in `range` of the
+    /// tokenized text.
+    ///
+    /// A `range` that is not within the tokenized text fails the whole
+    /// tokenization.
+    pub fn push(&mut self, token: &str, range: Range<usize>) {
+        self.push_with_flags(token, range, 0)
+    }
+
+    /// Emits `token` as a synonym of the previously emitted token, so it
+    /// occupies the same position in the document.
+    ///
+    /// See: <https://www.sqlite.org/fts5.html#synonym_support>
+    pub fn push_colocated(&mut self, token: &str, range: Range<usize>) {
+        self.push_with_flags(token, range, ffi::FTS5_TOKEN_COLOCATED)
+    }
+
+    fn push_with_flags(&mut self, token: &str, range: Range<usize>, flags: libc::c_int) {
+        if self.result != ffi::SQLITE_OK {
+            return;
+        }
+        if range.start > range.end || range.end > self.text_len {
+            self.result = ffi::SQLITE_RANGE;
+            return;
+        }
+        let (Ok(token_len), Ok(start), Ok(end)) = (
+            libc::c_int::try_from(token.len()),
+            libc::c_int::try_from(range.start),
+            libc::c_int::try_from(range.end),
+        ) else {
+            self.result = ffi::SQLITE_TOOBIG;
+            return;
+        };
+        // SAFETY: `callback` and `ctx` were handed to `xTokenize` by FTS5 and
+        // stay valid until it returns, which the lifetime of the sink ensures.
+        self.result = unsafe {
+            (self.callback)(
+                self.ctx,
+                flags,
+                token.as_ptr() as *const libc::c_char,
+                token_len,
+                start,
+                end,
+            )
+        };
+    }
+}
+
+impl core::fmt::Debug for Fts5TokenSink<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Fts5TokenSink")
+            .field("text_len", &self.text_len)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(super) fn register<F>(conn: &RawConnection, name: &str, tokenizer: F) -> QueryResult<()>
+where
+    F: Fn(&str, Fts5TokenizeReason, &mut Fts5TokenSink<'_>) + Send + 'static,
+{
+    let db = conn.internal_connection.as_ptr();
+    let name = CString::new(name)?;
+    let api = fts5_api(db)?.as_ptr();
+    // SAFETY: `api` was returned by the `fts5()` function of this connection
+    // and lives as long as the connection
+    let create_tokenizer = unsafe { (*api).xCreateTokenizer }
+        .ok_or_else(|| fts5_error("The FTS5 extension does not support registering tokenizers"))?;
+
+    let mut methods = ffi::fts5_tokenizer {
+        xCreate: Some(create_tokenizer_instance),
+        xDelete: Some(delete_tokenizer_instance),
+        xTokenize: Some(tokenize::<F>),
+    };
+    // only create the pointer as last step here as we otherwise could leak memory
+    let user_data = Box::into_raw(Box::new(tokenizer));
+    // SAFETY: FTS5 copies `methods`, and takes ownership of `user_data`,
+    // which it frees with `destroy_tokenizer` once the connection is closed
+    let result = unsafe {
+        create_tokenizer(
+            api,
+            name.as_ptr(),
+            user_data as *mut libc::c_void,
+            &mut methods,
+            Some(destroy_tokenizer::<F>),
+        )
+    };
+    if result != ffi::SQLITE_OK {
+        // FTS5 does not call `xDestroy` if the registration failed
+        destroy_tokenizer::<F>(user_data as *mut libc::c_void);
+    }
+    ensure_sqlite_ok(result, db)
+}
+
+/// Fetches the `fts5_api` of a connection, as described in
+/// <https://www.sqlite.org/fts5.html#extending_fts5>
+fn fts5_api(db: *mut ffi::sqlite3) -> QueryResult<NonNull<ffi::fts5_api>> {
+    let mut stmt = ptr::null_mut();
+    // SAFETY: `db` is a valid connection, the statement is finalized below
+    let result = unsafe {
+        ffi::sqlite3_prepare_v2(
+            db,
+            c"SELECT fts5(?1)".as_ptr(),
+            -1,
+            &mut stmt,
+            ptr::null_mut(),
+        )
+    };
+    // fails with "no such function: fts5" if FTS5 is not compiled in
+    ensure_sqlite_ok(result, db)?;
+
+    let mut api: *mut ffi::fts5_api = ptr::null_mut();
+    // SAFETY: `fts5()` writes the api pointer to the bound pointer while the
+    // statement is stepped, `api` outlives the statement
+    let bind_result = unsafe {
+        let result = ffi::sqlite3_bind_pointer(
+            stmt,
+            1,
+            &mut api as *mut *mut ffi::fts5_api as *mut libc::c_void,
+            c"fts5_api_ptr".as_ptr(),
+            None,
+        );
+        if result == ffi::SQLITE_OK {
+            ffi::sqlite3_step(stmt);
+        }
+        result
+    };
+    // SAFETY: `stmt` was prepared above and is not used afterwards
+    let finalize_result = unsafe { ffi::sqlite3_finalize(stmt) };
+    ensure_sqlite_ok(bind_result, db)?;
+    ensure_sqlite_ok(finalize_result, db)?;
+    NonNull::new(api).ok_or_else(|| fts5_error("The FTS5 extension is not available"))
+}
+
+fn fts5_error(message: &str) -> Error {
+    Error::DatabaseError(DatabaseErrorKind::Unknown, Box::new(message.to_string()))
+}
+
+// FTS5 creates one tokenizer instance per table using the tokenizer. The
+// registered closure is stateless from the point of view of FTS5, so every
+// instance is the user data pointer itself.
+unsafe extern "C" fn create_tokenizer_instance(
+    user_data: *mut libc::c_void,
+    _args: *mut *const libc::c_char,
+    _arg_count: libc::c_int,
+    out: *mut *mut ffi::Fts5Tokenizer,
+) -> libc::c_int {
+    // SAFETY: `out` is a valid pointer provided by FTS5
+    unsafe { *out = user_data as *mut ffi::Fts5Tokenizer };
+    ffi::SQLITE_OK
+}
+
+unsafe extern "C" fn delete_tokenizer_instance(_instance: *mut ffi::Fts5Tokenizer) {}
+
+extern "C" fn destroy_tokenizer<F>(user_data: *mut libc::c_void) {
+    // SAFETY: `user_data` was created via `Box::into_raw` in `register`
+    unsafe { core::mem::drop(Box::from_raw(user_data as *mut F)) };
+}
+
+unsafe extern "C" fn tokenize<F>(
+    instance: *mut ffi::Fts5Tokenizer,
+    ctx: *mut libc::c_void,
+    flags: libc::c_int,
+    text: *const libc::c_char,
+    text_len: libc::c_int,
+    callback: Option<TokenCallback>,
+) -> libc::c_int
+where
+    F: Fn(&str, Fts5TokenizeReason, &mut Fts5TokenSink<'_>) + Send + 'static,
+{
+    let Some(callback) = callback else {
+        return ffi::SQLITE_MISUSE;
+    };
+    let text_len = usize::try_from(text_len).unwrap_or_default();
+    let text = if text.is_null() || text_len == 0 {
+        &[][..]
+    } else {
+        // SAFETY: FTS5 passes a buffer of `text_len` bytes that stays valid
+        // for the duration of this call
+        unsafe { slice::from_raw_parts(text as *const u8, text_len) }
+    };
+    let Ok(text) = str::from_utf8(text) else {
+        return ffi::SQLITE_MISMATCH;
+    };
+
+    let result = crate::util::std_compat::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        // SAFETY: every tokenizer instance is the user data pointer, see
+        // `create_tokenizer_instance`, which points to a live `F` until the
+        // connection is closed
+        let tokenizer = unsafe { &*(instance as *const F) };
+        let mut sink = Fts5TokenSink {
+            ctx,
+            callback,
+            text_len: text.len(),
+            result: ffi::SQLITE_OK,
+            _marker: PhantomData,
+        };
+        tokenizer(text, Fts5TokenizeReason::from_ffi(flags), &mut sink);
+        sink.result
+    }));
+    // a panicking tokenizer fails the statement that used it
+    result.unwrap_or(ffi::SQLITE_ERROR)
+}
@@ -1,4 +1,6 @@
 use super::SqliteConnection;
+#[cfg(feature = "sqlite_preupdate_hook")]
+use super::preupdate_hook::SqlitePreUpdateRouter;
 use super::update_hook::SqliteUpdateRouter;
 use core::num::NonZeroU32;
 
@@ -84,6 +86,82 @@ impl SqliteConnection {
         self.raw_connection.remove_update_hook();
     }
 
+    /// Installs a [`SqlitePreUpdateRouter`](crate::sqlite::SqlitePreUpdateRouter)
+    /// as the pre-update hook, invoked just before each row is inserted,
+    /// updated, or deleted. Replaces any previously registered pre-update hook,
+    /// since SQLite allows only one per connection.
+    ///
+    /// Unlike [`on_update`](Self::on_update), the callback receives a
+    /// [`SqlitePreUpdateEvent`](crate::sqlite::SqlitePreUpdateEvent) that can
+    /// read the old and new column values through
+    /// [`old_value`](crate::sqlite::SqlitePreUpdateEvent::old_value) and
+    /// [`new_value`](crate::sqlite::SqlitePreUpdateEvent::new_value), so the
+    /// deleted or overwritten row does not need to be queried afterwards.
+    /// The hook also fires for `WITHOUT ROWID` tables, where the rowids are
+    /// unspecified.
+    ///
+    /// The same rules as for [`on_update`](Self::on_update) apply: callbacks
+    /// run synchronously inside `sqlite3_step()`, must not use the
+    /// connection, and a panic in a callback aborts the process.
+    ///
+    /// Requires the `sqlite_preupdate_hook` feature. This feature does not
+    /// build SQLite itself, the linked SQLite library must have been compiled
+    /// with `SQLITE_ENABLE_PREUPDATE_HOOK`. For the bundled version set
+    /// `LIBSQLITE3_FLAGS=SQLITE_ENABLE_PREUPDATE_HOOK` while building.
+    ///
+    /// See: [`sqlite3_preupdate_hook`](https://www.sqlite.org/c3ref/preupdate_blobwrite.html)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use diesel::prelude::*;
+    /// use diesel::sql_types::Text;
+    /// use diesel::sqlite::{SqliteChangeOps, SqliteConnection, SqlitePreUpdateRouter};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// diesel::table! { users (id) { id -> Integer, name -> Text, } }
+    ///
+    /// # use diesel::connection::SimpleConnection;
+    /// # let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// # conn.batch_execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)").unwrap();
+    /// # conn.batch_execute("INSERT INTO users (name) VALUES ('Alice')").unwrap();
+    /// let renames = Arc::new(Mutex::new(Vec::new()));
+    /// let captured = renames.clone();
+    ///
+    /// conn.on_preupdate(SqlitePreUpdateRouter::new().on(
+    ///     users::table,
+    ///     SqliteChangeOps::UPDATE,
+    ///     move |change| {
+    ///         let old = change.old_value::<Text, String>(1).unwrap();
+    ///         let new = change.new_value::<Text, String>(1).unwrap();
+    ///         captured.lock().unwrap().push((old, new));
+    ///     },
+    /// ));
+    ///
+    /// diesel::update(users::table)
+    ///     .set(users::name.eq("Bob"))
+    ///     .execute(conn)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     *renames.lock().unwrap(),
+    ///     vec![("Alice".to_string(), "Bob".to_string())]
+    /// );
+    /// ```
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    pub fn on_preupdate(&mut self, router: SqlitePreUpdateRouter) {
+        self.raw_connection.set_preupdate_hook(router.into_hook());
+    }
+
+    /// Removes the pre-update hook. Subsequent row changes will not invoke
+    /// any callback.
+    ///
+    /// See [`on_preupdate`](Self::on_preupdate) for usage.
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    pub fn remove_preupdate_hook(&mut self) {
+        self.raw_connection.remove_preupdate_hook();
+    }
+
     /// Registers a callback invoked when a transaction is about to be
     /// committed.
     ///
@@ -1334,6 +1412,124 @@ mod tests {
         assert_eq!(recorded[0].1, 1);
     }
 
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    #[diesel_test_helper::test]
+    fn preupdate_hook_reads_old_and_new_values() {
+        use super::super::preupdate_hook::SqlitePreUpdateRouter;
+        use crate::sql_types::{Integer, Nullable, Text};
+        use std::sync::{Arc, Mutex};
+
+        let conn = &mut connection();
+        setup_hook_tables(conn);
+        crate::sql_query("INSERT INTO hook_users (name) VALUES ('Alice')")
+            .execute(conn)
+            .unwrap();
+
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let f2 = fired.clone();
+        conn.on_preupdate(SqlitePreUpdateRouter::new().on(
+            hook_users::table,
+            SqliteChangeOps::ALL,
+            move |ev| {
+                let old = ev.old_value::<Nullable<Text>, Option<String>>(1).ok();
+                let new = ev.new_value::<Nullable<Text>, Option<String>>(1).ok();
+                f2.lock().unwrap().push((
+                    ev.op,
+                    ev.old_rowid,
+                    ev.new_rowid,
+                    old.flatten(),
+                    new.flatten(),
+                ));
+            },
+        ));
+
+        crate::sql_query("INSERT INTO hook_users (name) VALUES ('Bob')")
+            .execute(conn)
+            .unwrap();
+        crate::sql_query("UPDATE hook_users SET name = 'Carol' WHERE id = 1")
+            .execute(conn)
+            .unwrap();
+        crate::sql_query("DELETE FROM hook_users WHERE id = 2")
+            .execute(conn)
+            .unwrap();
+
+        assert_eq!(
+            *fired.lock().unwrap(),
+            vec![
+                (
+                    SqliteChangeOp::Insert,
+                    None,
+                    Some(2),
+                    None,
+                    Some("Bob".to_owned())
+                ),
+                (
+                    SqliteChangeOp::Update,
+                    Some(1),
+                    Some(1),
+                    Some("Alice".to_owned()),
+                    Some("Carol".to_owned()),
+                ),
+                (
+                    SqliteChangeOp::Delete,
+                    Some(2),
+                    None,
+                    Some("Bob".to_owned()),
+                    None
+                ),
+            ]
+        );
+
+        // Reading a column past the end of the row is an error, not UB.
+        let out_of_range = Arc::new(Mutex::new(None));
+        let o2 = out_of_range.clone();
+        conn.on_preupdate(SqlitePreUpdateRouter::new().on_any(
+            SqliteChangeOps::DELETE,
+            move |ev| {
+                assert_eq!(ev.column_count(), 2);
+                assert_eq!(ev.depth(), 0);
+                *o2.lock().unwrap() = Some(ev.old_value::<Integer, i32>(2).is_err());
+            },
+        ));
+        crate::sql_query("DELETE FROM hook_users WHERE id = 1")
+            .execute(conn)
+            .unwrap();
+        assert_eq!(*out_of_range.lock().unwrap(), Some(true));
+    }
+
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    #[diesel_test_helper::test]
+    fn preupdate_hook_fires_for_without_rowid_tables_and_can_be_removed() {
+        use super::super::preupdate_hook::SqlitePreUpdateRouter;
+        use crate::sql_types::Text;
+        use std::sync::{Arc, Mutex};
+
+        let conn = &mut connection();
+        crate::sql_query("CREATE TABLE kv (k TEXT PRIMARY KEY, v TEXT NOT NULL) WITHOUT ROWID")
+            .execute(conn)
+            .unwrap();
+
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let f2 = fired.clone();
+        conn.on_preupdate(SqlitePreUpdateRouter::new().on_any(
+            SqliteChangeOps::INSERT,
+            move |ev| {
+                let key = ev.new_value::<Text, String>(0).unwrap();
+                f2.lock().unwrap().push(key);
+            },
+        ));
+
+        crate::sql_query("INSERT INTO kv (k, v) VALUES ('a', '1')")
+            .execute(conn)
+            .unwrap();
+        conn.remove_preupdate_hook();
+        crate::sql_query("INSERT INTO kv (k, v) VALUES ('b', '2')")
+            .execute(conn)
+            .unwrap();
+
+        assert_eq!(*fired.lock().unwrap(), vec!["a".to_owned()]);
+    }
+
     /// Regression test for the dangling-pointer soundness bug: after the
     /// connection is moved, a write still fires the registered callback.
     ///
@@ -7,16 +7,23 @@ use sqlite_wasm_rs as ffi;
 pub mod authorizer;
 mod bind_collector;
 mod collation_needed;
+mod connect_options;
+mod fts5_tokenizer;
 mod functions;
 mod hooks;
 mod limits;
 mod owned_row;
+#[cfg(feature = "sqlite_preupdate_hook")]
+mod preupdate_hook;
 mod raw;
 mod row;
 mod serialized_database;
+#[cfg(feature = "sqlite_snapshot")]
+mod snapshot;
 pub(in crate::sqlite) mod sqlite_blob;
 mod sqlite_value;
 mod statement_iterator;
+mod status;
 mod stmt;
 mod trace;
 mod update_hook;
@@ -30,11 +37,20 @@ pub use self::bind_collector::SqliteBindValue;
 #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
 pub use self::bind_collector::{OwnedSqliteBindValue, SqliteBindCollectorData, SqliteBindValueRef};
 pub use self::collation_needed::{CollationNeededContext, SqliteTextRep};
+pub use self::connect_options::{
+    SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous, SqliteThreadingMode,
+};
+pub use self::fts5_tokenizer::{Fts5TokenSink, Fts5TokenizeReason};
 pub use self::limits::SqliteLimit;
+#[cfg(feature = "sqlite_preupdate_hook")]
+pub use self::preupdate_hook::{SqlitePreUpdateEvent, SqlitePreUpdateRouter};
 use self::raw::RawConnection;
 pub use self::serialized_database::SerializedDatabase;
+#[cfg(feature = "sqlite_snapshot")]
+pub use self::snapshot::SqliteSnapshot;
 pub use self::sqlite_value::SqliteValue;
 use self::statement_iterator::*;
+pub use self::status::{SqliteDbStatus, SqliteDbStatusValue};
 use self::stmt::{Statement, StatementUse};
 pub use self::trace::{SqliteTraceEvent, SqliteTraceFlags};
 pub use self::update_hook::{
@@ -196,6 +212,9 @@ pub struct SqliteConnection {
     // and avoiding static mut which will be deprecated in 2024 edition
     metadata_lookup: (),
     instrumentation: DynInstrumentation,
+    // whether to emit `InstrumentationEvent::StatementStatistics`
+    // see `SqliteConnection::set_statement_statistics`
+    statement_statistics: bool,
     // We potentially need to store a serialized
     // database in here to make sure the database bytes
     // live as long as the connection
@@ -253,19 +272,7 @@ impl Connection for SqliteConnection {
     /// * Persistent VFS (Virtual File Systems) is optional,
     ///   see <https://github.com/Spxg/sqlite-wasm-rs> for details
     fn establish(database_url: &str) -> ConnectionResult<Self> {
-        let mut instrumentation = DynInstrumentation::default_instrumentation();
-        instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
-            url: database_url,
-        });
-
-        let establish_result = Self::establish_inner(database_url);
-        instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
-            url: database_url,
-            error: establish_result.as_ref().err(),
-        });
-        let mut conn = establish_result?;
-        conn.instrumentation = instrumentation;
-        Ok(conn)
+        Self::establish_with_options(database_url, &SqliteConnectOptions::new())
     }
 
     fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
@@ -508,6 +515,118 @@ impl SqliteConnection {
         self.transaction_sql(f, "BEGIN EXCLUSIVE")
     }
 
+    /// Take a [`SqliteSnapshot`] of the current state of the `main` database.
+    ///
+    /// The snapshot can be passed to
+    /// [`read_transaction_at`](Self::read_transaction_at) on any connection
+    /// to the same database, to make several connections read exactly the
+    /// same state. The database must be in [WAL mode](https://www.sqlite.org/wal.html).
+    ///
+    /// Outside of a transaction, a short read transaction is opened to take
+    /// the snapshot. Inside a transaction, the snapshot records the state
+    /// read by that transaction, which fails if the transaction has already
+    /// written to the database.
+    ///
+    /// This requires the `sqlite_snapshot` feature and a `libsqlite3` built
+    /// with `SQLITE_ENABLE_SNAPSHOT`.
+    ///
+    /// See: [`sqlite3_snapshot_get`](https://www.sqlite.org/c3ref/snapshot_get.html)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// use diesel::connection::SimpleConnection;
+    /// use diesel::dsl::sql;
+    /// use diesel::sql_types::BigInt;
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let path = dir.path().join("app.db");
+    /// # let path = path.to_str().unwrap();
+    /// let writer = &mut SqliteConnection::establish(path).unwrap();
+    /// writer.batch_execute("PRAGMA journal_mode = WAL; CREATE TABLE logs (line TEXT NOT NULL)")?;
+    ///
+    /// let snapshot = writer.snapshot()?;
+    /// writer.batch_execute("INSERT INTO logs VALUES ('after the snapshot')")?;
+    ///
+    /// let reader = &mut SqliteConnection::establish(path).unwrap();
+    /// let count = reader.read_transaction_at(&snapshot, |conn| {
+    ///     diesel::select(sql::<BigInt>("(SELECT count(*) FROM logs)")).get_result::<i64>(conn)
+    /// })?;
+    /// assert_eq!(count, 0);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlite_snapshot")]
+    pub fn snapshot(&mut self) -> QueryResult<SqliteSnapshot> {
+        // reading anything from the database opens the read transaction
+        // `sqlite3_snapshot_get` requires
+        const START_READ: &str = "SELECT count(*) FROM main.sqlite_master";
+
+        let in_transaction = AnsiTransactionManager::transaction_manager_status_mut(self)
+            .transaction_depth()?
+            .is_some();
+        if in_transaction {
+            self.raw_connection.exec(START_READ)?;
+            self.raw_connection.snapshot_get()
+        } else {
+            self.transaction_sql(
+                |conn| {
+                    conn.raw_connection.exec(START_READ)?;
+                    conn.raw_connection.snapshot_get()
+                },
+                "BEGIN",
+            )
+        }
+    }
+
+    /// Run a read transaction that sees the state of the `main` database
+    /// recorded in `snapshot`, instead of the most recent one.
+    ///
+    /// The transaction is started with `BEGIN` like
+    /// [`immediate_transaction`](Self::immediate_transaction) and friends,
+    /// so this method will return an error if a transaction is already
+    /// open. Writing inside the transaction fails with `SQLITE_BUSY_SNAPSHOT`
+    /// unless `snapshot` is still the most recent state.
+    ///
+    /// Opening the snapshot fails with `SQLITE_ERROR_SNAPSHOT` once the
+    /// write-ahead log it refers to was checkpointed and restarted, for
+    /// example by a [`wal_checkpoint`](Self::wal_checkpoint) with
+    /// [`Restart`](WalCheckpointMode::Restart) or
+    /// [`Truncate`](WalCheckpointMode::Truncate). Keeping a read transaction
+    /// open on some connection while the snapshot is in use prevents that.
+    ///
+    /// This requires the `sqlite_snapshot` feature and a `libsqlite3` built
+    /// with `SQLITE_ENABLE_SNAPSHOT`. See [`snapshot`](Self::snapshot) for
+    /// an example.
+    ///
+    /// See: [`sqlite3_snapshot_open`](https://www.sqlite.org/c3ref/snapshot_open.html)
+    #[cfg(feature = "sqlite_snapshot")]
+    pub fn read_transaction_at<T, E, F>(&mut self, snapshot: &SqliteSnapshot, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        // `sqlite3_snapshot_open` needs the write-ahead log to be opened
+        // by a read in the same transaction first, it then moves that read
+        // transaction to `snapshot`
+        const OPEN_WAL: &str = "SELECT 1 FROM main.sqlite_master LIMIT 1";
+
+        self.transaction_sql(
+            |conn| {
+                conn.raw_connection.exec(OPEN_WAL)?;
+                conn.raw_connection.snapshot_open(snapshot)?;
+                f(conn)
+            },
+            "BEGIN",
+        )
+    }
+
     /// Returns the rowid of the most recent successful INSERT on this connection.
     ///
     /// Returns `None` if no successful INSERT into a rowid table has been performed
@@ -646,7 +765,12 @@ impl SqliteConnection {
             }
         };
 
-        StatementUse::bind(statement, source, &mut *self.instrumentation)
+        StatementUse::bind(
+            statement,
+            source,
+            &mut *self.instrumentation,
+            self.statement_statistics,
+        )
     }
 
     #[doc(hidden)]
@@ -740,6 +864,75 @@ impl SqliteConnection {
             .register_collation_function(collation_name, collation)
     }
 
+    /// Register a custom [FTS5 tokenizer](https://www.sqlite.org/fts5.html#custom_tokenizers)
+    /// under `name`, to be used by FTS5 tables created with
+    /// `tokenize = 'name'`.
+    ///
+    /// `tokenizer` is called with the text to split and the
+    /// [reason](crate::sqlite::fts5::Fts5TokenizeReason) it is split for, and
+    /// emits the tokens to the given [`Fts5TokenSink`](crate::sqlite::fts5::Fts5TokenSink),
+    /// together with the byte range of the text each token was produced
+    /// from. Arguments given after the tokenizer name in the table
+    /// definition are ignored.
+    ///
+    /// If `tokenizer` panics, the statement that used it fails with an error.
+    /// If the name is already registered it will be overwritten for tables
+    /// created afterwards.
+    ///
+    /// This method will return an error if SQLite was built without FTS5.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// diesel::fts5_table! {
+    ///     posts {
+    ///         labels -> Text,
+    ///     }
+    /// }
+    ///
+    /// # fn run_test() -> QueryResult<()> {
+    /// use diesel::sqlite::fts5::{Fts5Table, Fts5TokenSink};
+    ///
+    /// let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// // one token per comma separated value
+    /// conn.register_fts5_tokenizer("csv", |text: &str, _, sink: &mut Fts5TokenSink<'_>| {
+    ///     let mut start = 0;
+    ///     for value in text.split(',') {
+    ///         sink.push(value.trim(), start..start + value.len());
+    ///         start += value.len() + 1;
+    ///     }
+    /// })?;
+    /// diesel::sql_query("CREATE VIRTUAL TABLE posts USING fts5(labels, tokenize = 'csv')")
+    ///     .execute(conn)?;
+    /// diesel::insert_into(posts::table)
+    ///     .values(posts::labels.eq("rust, sql orm"))
+    ///     .execute(conn)?;
+    ///
+    /// let mut matching = |query: &str| {
+    ///     posts::table
+    ///         .filter(posts::table.matches(query))
+    ///         .count()
+    ///         .get_result::<i64>(conn)
+    /// };
+    /// // `sql orm` is a single token, so `sql` alone does not match
+    /// assert_eq!(1, matching("\"sql orm\"")?);
+    /// assert_eq!(0, matching("\"sql\"")?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn register_fts5_tokenizer<F>(&mut self, name: &str, tokenizer: F) -> QueryResult<()>
+    where
+        F: Fn(&str, Fts5TokenizeReason, &mut Fts5TokenSink<'_>) + Send + 'static,
+    {
+        fts5_tokenizer::register(&self.raw_connection, name, tokenizer)
+    }
+
     /// Serialize the current SQLite database into a byte buffer.
     ///
     /// The serialized data is identical to the data that would be written to disk if the database
@@ -807,6 +1000,87 @@ impl SqliteConnection {
         }
     }
 
+    /// Deserialize an SQLite database from a byte buffer into a writable
+    /// in-memory database.
+    ///
+    /// Unlike [`deserialize_readonly_database_from_buffer`](Self::deserialize_readonly_database_from_buffer),
+    /// the data is copied into a buffer owned by SQLite, so the loaded database
+    /// can be modified, grow, and be serialized again with
+    /// [`serialize_database_to_buffer`](Self::serialize_database_to_buffer).
+    /// Changes are never written back to `data`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use diesel::sqlite::SqliteConnection;
+    /// # use diesel::sql_query;
+    /// # use diesel::Connection;
+    /// # use diesel::RunQueryDsl;
+    /// # fn main() {
+    /// let connection = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// sql_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+    ///     .execute(connection).unwrap();
+    /// let image = connection.serialize_database_to_buffer();
+    ///
+    /// let connection = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// connection.deserialize_database_from_buffer(&image).unwrap();
+    ///
+    /// // the loaded database is writable
+    /// sql_query("INSERT INTO users (name) VALUES ('Jane Doe')")
+    ///     .execute(connection).unwrap();
+    /// let modified = connection.serialize_database_to_buffer();
+    /// assert!(modified.len() >= image.len());
+    /// # }
+    /// ```
+    pub fn deserialize_database_from_buffer(&mut self, data: &[u8]) -> QueryResult<()> {
+        self.raw_connection.deserialize_writable(data)
+    }
+
+    /// Establish a connection to the database specified by `database_url`,
+    /// opened according to `options`.
+    ///
+    /// This behaves like [`Connection::establish`], but allows to open the
+    /// database read-only, to fail instead of creating a missing database, to
+    /// select the threading mode or VFS, and to apply initial pragmas before the
+    /// connection is handed out. A failing initial pragma fails the whole call
+    /// with [`ConnectionError::CouldntSetupConfiguration`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use diesel::sqlite::{SqliteConnection, SqliteConnectOptions, SqliteSynchronous};
+    /// # fn main() -> diesel::ConnectionResult<()> {
+    /// let options = SqliteConnectOptions::new()
+    ///     .busy_timeout(2000)
+    ///     .synchronous(SqliteSynchronous::Normal)
+    ///     .pragma("foreign_keys", "ON");
+    /// let connection = &mut SqliteConnection::establish_with_options(":memory:", &options)?;
+    ///
+    /// // opening a database that does not exist fails without `create_if_missing`
+    /// let options = SqliteConnectOptions::new().create_if_missing(false);
+    /// assert!(SqliteConnection::establish_with_options("does_not_exist.db", &options).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn establish_with_options(
+        database_url: &str,
+        options: &SqliteConnectOptions,
+    ) -> ConnectionResult<Self> {
+        let mut instrumentation = DynInstrumentation::default_instrumentation();
+        instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
+            url: database_url,
+        });
+
+        let establish_result = Self::establish_inner(database_url, options);
+        instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
+            url: database_url,
+            error: establish_result.as_ref().err(),
+        });
+        let mut conn = establish_result?;
+        conn.instrumentation = instrumentation;
+        Ok(conn)
+    }
+
     /// Provides temporary access to the raw SQLite database connection handle.
     ///
     /// This method provides a way to access the underlying `sqlite3` pointer,
@@ -940,6 +1214,7 @@ impl SqliteConnection {
             transaction_state: AnsiTransactionManager::default(),
             metadata_lookup: (),
             instrumentation: DynInstrumentation::default_instrumentation(),
+            statement_statistics: false,
             serialized_data: Vec::new(),
         }));
 
@@ -1006,6 +1281,87 @@ impl SqliteConnection {
         self.raw_connection.get_limit(limit)
     }
 
+    /// Read a runtime counter of this connection, like its cache hit rate or
+    /// the memory used by its prepared statements.
+    ///
+    /// See the [SQLite documentation](https://www.sqlite.org/c3ref/db_status.html)
+    /// for details and [`SqliteDbStatus`] for the available counters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # fn main() { run_test(); }
+    /// # fn run_test() {
+    /// use diesel::sqlite::SqliteDbStatus;
+    ///
+    /// let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// diesel::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY)")
+    ///     .execute(conn)
+    ///     .unwrap();
+    ///
+    /// let schema = conn.db_status(SqliteDbStatus::SchemaUsed).unwrap();
+    /// assert!(schema.current > 0);
+    /// # }
+    /// ```
+    pub fn db_status(&self, status: SqliteDbStatus) -> QueryResult<SqliteDbStatusValue> {
+        self.raw_connection.db_status(status, false)
+    }
+
+    /// Like [`db_status`](Self::db_status), but resets the counter afterwards.
+    ///
+    /// Depending on the counter, this resets either the high-water mark or
+    /// the current value. Returns the value from before the reset.
+    pub fn reset_db_status(&mut self, status: SqliteDbStatus) -> QueryResult<SqliteDbStatusValue> {
+        self.raw_connection.db_status(status, true)
+    }
+
+    /// Enable or disable reporting per-statement counters.
+    ///
+    /// When enabled, an
+    /// [`InstrumentationEvent::StatementStatistics`] event is emitted to the
+    /// [`Instrumentation`] of this connection after each query run through
+    /// diesel's query builder, with the number of full scan steps, sorts,
+    /// automatic index rows and virtual machine steps of that query. Disabled
+    /// by default.
+    ///
+    /// See the [SQLite documentation](https://www.sqlite.org/c3ref/c_stmtstatus_counter.html)
+    /// for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # fn main() { run_test(); }
+    /// # fn run_test() {
+    /// use diesel::connection::{InstrumentationEvent, StatementStatistics};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// diesel::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)")
+    ///     .execute(conn)
+    ///     .unwrap();
+    ///
+    /// let collected = Arc::new(Mutex::new(Vec::<StatementStatistics>::new()));
+    /// let captured = collected.clone();
+    /// conn.set_instrumentation(move |event: InstrumentationEvent<'_>| {
+    ///     if let InstrumentationEvent::StatementStatistics { statistics, .. } = event {
+    ///         captured.lock().unwrap().push(statistics);
+    ///     }
+    /// });
+    /// conn.set_statement_statistics(true);
+    ///
+    /// diesel::sql_query("SELECT * FROM t WHERE v = 1")
+    ///     .execute(conn)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(collected.lock().unwrap().len(), 1);
+    /// # }
+    /// ```
+    pub fn set_statement_statistics(&mut self, enabled: bool) {
+        self.statement_statistics = enabled;
+    }
+
     /// Apply SQLite's recommended limits for hardening against untrusted SQL.
     ///
     /// These are the values from the "Untrusted SQL Inputs" table of SQLite's
@@ -1744,15 +2100,27 @@ impl SqliteConnection {
         )
     }
 
-    fn establish_inner(database_url: &str) -> Result<SqliteConnection, ConnectionError> {
+    fn establish_inner(
+        database_url: &str,
+        options: &SqliteConnectOptions,
+    ) -> Result<SqliteConnection, ConnectionError> {
         use crate::result::ConnectionError::CouldntSetupConfiguration;
-        let raw_connection = RawConnection::establish(database_url)?;
+        let mut raw_connection = RawConnection::establish(database_url, options)?;
+        if let Some(ms) = options.busy_timeout_ms() {
+            raw_connection.set_busy_timeout(ms);
+        }
+        for pragma in options.initial_pragmas() {
+            raw_connection
+                .exec(&pragma)
+                .map_err(CouldntSetupConfiguration)?;
+        }
         let conn = Self {
             statement_cache: StatementCache::new(),
             raw_connection,
             transaction_state: AnsiTransactionManager::default(),
             metadata_lookup: (),
             instrumentation: DynInstrumentation::none(),
+            statement_statistics: false,
             serialized_data: Vec::new(),
         };
         conn.register_diesel_sql_functions()
@@ -2276,6 +2644,105 @@ mod tests {
         }
     }
 
+    #[diesel_test_helper::test]
+    fn writable_deserialized_database_can_be_modified_and_reserialized() {
+        let conn1 = &mut connection();
+        crate::sql_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+            .execute(conn1)
+            .unwrap();
+        crate::sql_query("INSERT INTO users (name) VALUES ('John Doe')")
+            .execute(conn1)
+            .unwrap();
+        let image = conn1.serialize_database_to_buffer();
+
+        let conn2 = &mut connection();
+        conn2.deserialize_database_from_buffer(&image).unwrap();
+        // the image is copied, so dropping it does not affect the connection
+        std::mem::drop(image);
+
+        // grow the database beyond the initial buffer size
+        for _ in 0..100 {
+            crate::sql_query("INSERT INTO users (name) SELECT name FROM users LIMIT 1")
+                .execute(conn2)
+                .unwrap();
+        }
+
+        let conn3 = &mut connection();
+        conn3
+            .deserialize_database_from_buffer(&conn2.serialize_database_to_buffer())
+            .unwrap();
+        let count = sql::<crate::sql_types::BigInt>("SELECT COUNT(*) FROM users")
+            .get_result::<i64>(conn3)
+            .unwrap();
+        assert_eq!(count, 101);
+    }
+
+    #[diesel_test_helper::test]
+    fn writable_deserialize_of_empty_buffer_gives_empty_database() {
+        let conn = &mut connection();
+        conn.deserialize_database_from_buffer(&[]).unwrap();
+        crate::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .execute(conn)
+            .unwrap();
+        let count = sql::<crate::sql_types::BigInt>("SELECT COUNT(*) FROM t")
+            .get_result::<i64>(conn)
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[diesel_test_helper::test]
+    fn establish_with_options_applies_flags_and_pragmas() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("options.db");
+        let path = path.to_str().unwrap();
+
+        let no_create = SqliteConnectOptions::new().create_if_missing(false);
+        assert!(SqliteConnection::establish_with_options(path, &no_create).is_err());
+
+        let options = SqliteConnectOptions::new()
+            .busy_timeout(1000)
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal)
+            .threading_mode(SqliteThreadingMode::NoMutex)
+            .pragma("user_version", "42");
+        let conn = &mut SqliteConnection::establish_with_options(path, &options).unwrap();
+        let journal_mode = sql::<Text>("PRAGMA journal_mode")
+            .get_result::<String>(conn)
+            .unwrap();
+        assert_eq!(journal_mode, "wal");
+        let synchronous = sql::<Integer>("PRAGMA synchronous")
+            .get_result::<i32>(conn)
+            .unwrap();
+        assert_eq!(synchronous, 1);
+        crate::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .execute(conn)
+            .unwrap();
+
+        let read_only = SqliteConnectOptions::new().read_only(true);
+        let conn = &mut SqliteConnection::establish_with_options(path, &read_only).unwrap();
+        let user_version = sql::<Integer>("PRAGMA user_version")
+            .get_result::<i32>(conn)
+            .unwrap();
+        assert_eq!(user_version, 42);
+        assert!(
+            crate::sql_query("INSERT INTO t DEFAULT VALUES")
+                .execute(conn)
+                .is_err()
+        );
+
+        let bad_pragma = SqliteConnectOptions::new().pragma("journal_mode", "WAL; SELECT");
+        assert!(matches!(
+            SqliteConnection::establish_with_options(path, &bad_pragma),
+            Err(ConnectionError::CouldntSetupConfiguration(_))
+        ));
+    }
+
+    #[diesel_test_helper::test]
+    fn establish_with_unknown_vfs_fails() {
+        let options = SqliteConnectOptions::new().vfs("no_such_vfs");
+        assert!(SqliteConnection::establish_with_options(":memory:", &options).is_err());
+    }
+
     #[diesel_test_helper::test]
     fn database_deserialize_random_bytes() {
         let buffer = vec![0, 1, 2, 3, 4];
@@ -3058,6 +3525,63 @@ mod tests {
         assert_eq!(conn.get_limit(SqliteLimit::SqlLength), 2048);
     }
 
+    #[diesel_test_helper::test]
+    fn db_status_reads_and_resets_counters() {
+        let mut conn = connection();
+        crate::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .execute(&mut conn)
+            .unwrap();
+        crate::sql_query("SELECT * FROM t")
+            .execute(&mut conn)
+            .unwrap();
+
+        assert!(conn.db_status(SqliteDbStatus::SchemaUsed).unwrap().current > 0);
+        assert!(conn.db_status(SqliteDbStatus::CacheHit).unwrap().current > 0);
+
+        // resetting a cache counter resets its current value
+        conn.reset_db_status(SqliteDbStatus::CacheHit).unwrap();
+        assert_eq!(conn.db_status(SqliteDbStatus::CacheHit).unwrap().current, 0);
+    }
+
+    #[diesel_test_helper::test]
+    fn statement_statistics_are_reported_per_query() {
+        use crate::connection::{InstrumentationEvent, StatementStatistics};
+        use std::sync::{Arc, Mutex};
+
+        let mut conn = connection();
+        crate::sql_query("CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER)")
+            .execute(&mut conn)
+            .unwrap();
+        crate::sql_query("INSERT INTO t (v) VALUES (1), (2), (3), (4)")
+            .execute(&mut conn)
+            .unwrap();
+
+        let collected = Arc::new(Mutex::new(Vec::<StatementStatistics>::new()));
+        let captured = collected.clone();
+        conn.set_instrumentation(move |event: InstrumentationEvent<'_>| {
+            if let InstrumentationEvent::StatementStatistics { statistics, .. } = event {
+                captured.lock().unwrap().push(statistics);
+            }
+        });
+
+        let query = sql::<Integer>("SELECT v FROM t WHERE v > 2 ORDER BY v DESC");
+        query.clone().load::<i32>(&mut conn).unwrap();
+        assert!(collected.lock().unwrap().is_empty());
+
+        conn.set_statement_statistics(true);
+        for _ in 0..2 {
+            query.clone().load::<i32>(&mut conn).unwrap();
+        }
+
+        let collected = collected.lock().unwrap();
+        assert_eq!(collected.len(), 2);
+        // counters must not accumulate across runs of a cached statement
+        assert_eq!(collected[0], collected[1]);
+        assert!(collected[0].full_scan_steps > 0);
+        assert_eq!(collected[0].sorts, 1);
+        assert!(collected[0].vm_steps > 0);
+    }
+
     #[diesel_test_helper::test]
     fn get_limit_does_not_mutate() {
         let conn = connection();
@@ -3740,6 +4264,80 @@ mod tests {
         (dir, path)
     }
 
+    #[cfg(all(
+        feature = "sqlite_snapshot",
+        not(all(target_family = "wasm", target_os = "unknown"))
+    ))]
+    #[diesel_test_helper::test]
+    fn read_transaction_at_sees_the_snapshot_state() {
+        use crate::connection::SimpleConnection;
+        use crate::sql_types::BigInt;
+
+        let (_dir, path) = temp_db_path("snapshot.db");
+        let path = path.to_str().unwrap();
+        let writer = &mut SqliteConnection::establish(path).unwrap();
+        writer
+            .batch_execute("PRAGMA journal_mode = WAL; CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .unwrap();
+        let count = |conn: &mut SqliteConnection| {
+            crate::select(sql::<BigInt>("(SELECT count(*) FROM t)")).get_result::<i64>(conn)
+        };
+
+        let first = writer.snapshot().unwrap();
+        writer
+            .batch_execute("INSERT INTO t DEFAULT VALUES")
+            .unwrap();
+        let second = writer.snapshot().unwrap();
+        writer
+            .batch_execute("INSERT INTO t DEFAULT VALUES")
+            .unwrap();
+        assert!(first.is_older_than(&second));
+        assert!(!second.is_older_than(&first));
+
+        for _ in 0..2 {
+            let reader = &mut SqliteConnection::establish(path).unwrap();
+            assert_eq!(reader.read_transaction_at(&first, count), Ok(0));
+            assert_eq!(reader.read_transaction_at(&second, count), Ok(1));
+            assert_eq!(count(reader), Ok(2));
+        }
+    }
+
+    #[cfg(all(
+        feature = "sqlite_snapshot",
+        not(all(target_family = "wasm", target_os = "unknown"))
+    ))]
+    #[diesel_test_helper::test]
+    fn snapshot_is_invalidated_by_truncating_checkpoint() {
+        use crate::connection::SimpleConnection;
+
+        let (_dir, path) = temp_db_path("snapshot.db");
+        let path = path.to_str().unwrap();
+        let writer = &mut SqliteConnection::establish(path).unwrap();
+        writer
+            .batch_execute("PRAGMA journal_mode = WAL; CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .unwrap();
+        let snapshot = writer.snapshot().unwrap();
+        writer
+            .batch_execute("INSERT INTO t DEFAULT VALUES")
+            .unwrap();
+        writer
+            .wal_checkpoint(None, WalCheckpointMode::Truncate)
+            .unwrap();
+        // the next write starts over at the beginning of the log
+        writer
+            .batch_execute("INSERT INTO t DEFAULT VALUES")
+            .unwrap();
+
+        let reader = &mut SqliteConnection::establish(path).unwrap();
+        assert!(
+            reader
+                .read_transaction_at(&snapshot, |_| Ok::<_, Error>(()))
+                .is_err()
+        );
+        // the failed attempt must not leave a transaction open
+        assert!(reader.immediate_transaction(|_| Ok::<_, Error>(())).is_ok());
+    }
+
     #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
     #[diesel_test_helper::test]
     fn attach_create_disabled_blocks_new_database_files() {
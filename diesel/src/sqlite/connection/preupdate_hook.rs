@@ -0,0 +1,288 @@
+#![allow(unsafe_code)] // ffi calls
+//! Types for the SQLite pre-update hook.
+//!
+//! See [`SqliteConnection::on_preupdate`](super::SqliteConnection::on_preupdate) for usage.
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+extern crate libsqlite3_sys as ffi;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use sqlite_wasm_rs as ffi;
+
+use super::SqliteValue;
+use super::stmt::ensure_sqlite_ok;
+use super::update_hook::{DynNamedTable, SqliteChangeOp, SqliteChangeOps};
+use crate::deserialize::FromSql;
+use crate::query_source::NamedTable;
+use crate::result::{DatabaseErrorKind, Error, QueryResult};
+use crate::sqlite::Sqlite;
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::ffi as libc;
+use core::ptr::{self, NonNull};
+
+/// Describes a row change that is about to happen, with access to the old and
+/// new column values.
+///
+/// Returned to the callbacks of a [`SqlitePreUpdateRouter`] installed via
+/// [`on_preupdate`](super::SqliteConnection::on_preupdate). Column values are
+/// read with [`old_value`](Self::old_value) and [`new_value`](Self::new_value),
+/// which deserialize through [`FromSql`] just like a loaded row.
+///
+/// See: <https://www.sqlite.org/c3ref/preupdate_blobwrite.html>
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct SqlitePreUpdateEvent<'a> {
+    /// The operation that is about to be performed.
+    pub op: SqliteChangeOp,
+    /// The name of the database the change occurs in: `"main"` for the
+    /// primary database, `"temp"` for temporary tables, or the alias from an
+    /// `ATTACH DATABASE` statement.
+    pub db_name: &'a str,
+    /// The name of the table that is modified.
+    pub table_name: &'a str,
+    /// The [rowid](https://www.sqlite.org/rowidtable.html) of the row before
+    /// the change. `None` for inserts.
+    pub old_rowid: Option<i64>,
+    /// The [rowid](https://www.sqlite.org/rowidtable.html) of the row after
+    /// the change. `None` for deletes.
+    pub new_rowid: Option<i64>,
+    db: NonNull<ffi::sqlite3>,
+}
+
+impl<'a> SqlitePreUpdateEvent<'a> {
+    pub(super) fn new(
+        db: NonNull<ffi::sqlite3>,
+        op: SqliteChangeOp,
+        db_name: &'a str,
+        table_name: &'a str,
+        old_rowid: i64,
+        new_rowid: i64,
+    ) -> Self {
+        SqlitePreUpdateEvent {
+            op,
+            db_name,
+            table_name,
+            old_rowid: (op != SqliteChangeOp::Insert).then_some(old_rowid),
+            new_rowid: (op != SqliteChangeOp::Delete).then_some(new_rowid),
+            db,
+        }
+    }
+
+    /// Returns the number of columns in the row being changed.
+    ///
+    /// See: [`sqlite3_preupdate_count`](https://www.sqlite.org/c3ref/preupdate_blobwrite.html)
+    pub fn column_count(&self) -> usize {
+        // SAFETY: `db` is the connection that fired the hook, and the event
+        // cannot outlive the callback it was handed to.
+        let count = unsafe { ffi::sqlite3_preupdate_count(self.db.as_ptr()) };
+        count.try_into().unwrap_or_default()
+    }
+
+    /// Returns the trigger nesting depth of the change: `0` for a change made
+    /// directly by a statement, `1` for a change made by a trigger fired by
+    /// that statement, and so on.
+    ///
+    /// See: [`sqlite3_preupdate_depth`](https://www.sqlite.org/c3ref/preupdate_blobwrite.html)
+    pub fn depth(&self) -> u32 {
+        // SAFETY: see `column_count`
+        let depth = unsafe { ffi::sqlite3_preupdate_depth(self.db.as_ptr()) };
+        depth.try_into().unwrap_or_default()
+    }
+
+    /// Reads the value column `column` (0-based, in table declaration order)
+    /// had before the change.
+    ///
+    /// Returns an error for an [`Insert`](SqliteChangeOp::Insert), which has
+    /// no old row, and for an out-of-range column. Use a
+    /// [`Nullable`](crate::sql_types::Nullable) SQL type to read a column that
+    /// may be `NULL`.
+    ///
+    /// ```rust
+    /// # use diesel::sqlite::SqlitePreUpdateEvent;
+    /// # use diesel::sql_types::Text;
+    /// # fn f(change: &SqlitePreUpdateEvent<'_>) -> diesel::QueryResult<()> {
+    /// let old_name: String = change.old_value::<Text, _>(1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn old_value<ST, T>(&self, column: usize) -> QueryResult<T>
+    where
+        T: FromSql<ST, Sqlite>,
+    {
+        if self.op == SqliteChangeOp::Insert {
+            return Err(no_row_error("an INSERT has no old row"));
+        }
+        self.read_value(column, true)
+    }
+
+    /// Reads the value column `column` (0-based, in table declaration order)
+    /// will have after the change.
+    ///
+    /// Returns an error for a [`Delete`](SqliteChangeOp::Delete), which has
+    /// no new row, and for an out-of-range column. Use a
+    /// [`Nullable`](crate::sql_types::Nullable) SQL type to read a column that
+    /// may be `NULL`.
+    pub fn new_value<ST, T>(&self, column: usize) -> QueryResult<T>
+    where
+        T: FromSql<ST, Sqlite>,
+    {
+        if self.op == SqliteChangeOp::Delete {
+            return Err(no_row_error("a DELETE has no new row"));
+        }
+        self.read_value(column, false)
+    }
+
+    fn read_value<ST, T>(&self, column: usize, old: bool) -> QueryResult<T>
+    where
+        T: FromSql<ST, Sqlite>,
+    {
+        let column =
+            libc::c_int::try_from(column).map_err(|e| Error::DeserializationError(Box::new(e)))?;
+        let mut value = ptr::null_mut();
+        // SAFETY: see `column_count`. SQLite rejects out-of-range columns
+        // with `SQLITE_RANGE` instead of reading out of bounds.
+        let result = unsafe {
+            if old {
+                ffi::sqlite3_preupdate_old(self.db.as_ptr(), column, &mut value)
+            } else {
+                ffi::sqlite3_preupdate_new(self.db.as_ptr(), column, &mut value)
+            }
+        };
+        ensure_sqlite_ok(result, self.db.as_ptr())?;
+
+        // SAFETY: the value is owned by SQLite and stays valid until the
+        // hook returns, which `'a` guarantees.
+        let value = NonNull::new(value).and_then(|value| unsafe { SqliteValue::from_raw(value) });
+        T::from_nullable_sql(value).map_err(Error::DeserializationError)
+    }
+
+    /// Returns `true` if this change is on the [`table!`](macro@crate::table)
+    /// table `T`. See [`SqliteChangeEvent::is_from`](super::SqliteChangeEvent::is_from).
+    pub fn is_from(&self, table: impl NamedTable) -> bool {
+        self.table_name == table.table() && table.schema().is_none_or(|db| self.db_name == db)
+    }
+}
+
+fn no_row_error(message: &str) -> Error {
+    Error::DatabaseError(DatabaseErrorKind::Unknown, Box::new(message.to_string()))
+}
+
+struct Route {
+    table: Option<Box<dyn DynNamedTable + Send>>,
+    ops: SqliteChangeOps,
+    callback: Box<dyn FnMut(SqlitePreUpdateEvent<'_>) + Send>,
+}
+
+impl Route {
+    fn matches(&self, event: &SqlitePreUpdateEvent<'_>) -> bool {
+        self.ops.matches_op(event.op)
+            && self.table.as_deref().is_none_or(|name| {
+                name.table() == event.table_name
+                    && name.schema().is_none_or(|db| db == event.db_name)
+            })
+    }
+}
+
+/// Routes SQLite pre-update events to per-table callbacks, selected by typed
+/// [`table!`](macro@crate::table) markers.
+///
+/// This is the pre-update counterpart of
+/// [`SqliteUpdateRouter`](super::SqliteUpdateRouter): SQLite allows only one
+/// pre-update hook per connection, so the router is composed up front and
+/// installed with [`on_preupdate`](super::SqliteConnection::on_preupdate).
+/// Every matching route fires for a given event, in build order.
+///
+/// ```rust
+/// use diesel::prelude::*;
+/// use diesel::sql_types::Text;
+/// use diesel::sqlite::{SqliteChangeOps, SqliteConnection, SqlitePreUpdateRouter};
+/// use std::sync::{Arc, Mutex};
+///
+/// diesel::table! { users (id) { id -> Integer, name -> Text, } }
+///
+/// # let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+/// # use diesel::connection::SimpleConnection;
+/// # conn.batch_execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)").unwrap();
+/// # conn.batch_execute("INSERT INTO users (name) VALUES ('Alice')").unwrap();
+/// let deleted = Arc::new(Mutex::new(Vec::new()));
+/// let captured = deleted.clone();
+///
+/// conn.on_preupdate(SqlitePreUpdateRouter::new().on(
+///     users::table,
+///     SqliteChangeOps::DELETE,
+///     move |change| {
+///         let name = change.old_value::<Text, String>(1).unwrap();
+///         captured.lock().unwrap().push(name);
+///     },
+/// ));
+///
+/// diesel::delete(users::table).execute(conn).unwrap();
+///
+/// assert_eq!(*deleted.lock().unwrap(), vec!["Alice".to_string()]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SqlitePreUpdateRouter {
+    routes: Vec<Route>,
+}
+
+impl SqlitePreUpdateRouter {
+    /// Creates an empty router that matches nothing until routes are added.
+    pub fn new() -> Self {
+        SqlitePreUpdateRouter { routes: Vec::new() }
+    }
+
+    /// Routes changes on `table` matching `ops` to `callback`.
+    ///
+    /// Tables are matched the same way as by
+    /// [`SqliteUpdateRouter::on`](super::SqliteUpdateRouter::on): an
+    /// unqualified table matches by name in any database, a schema-qualified
+    /// one only in that attached database.
+    pub fn on<T, F>(mut self, table: T, ops: SqliteChangeOps, callback: F) -> Self
+    where
+        T: NamedTable + Send + 'static,
+        F: FnMut(SqlitePreUpdateEvent<'_>) + Send + 'static,
+    {
+        self.routes.push(Route {
+            table: Some(Box::new(table)),
+            ops,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// Routes changes on any table matching `ops` to `callback`.
+    pub fn on_any<F>(mut self, ops: SqliteChangeOps, callback: F) -> Self
+    where
+        F: FnMut(SqlitePreUpdateEvent<'_>) + Send + 'static,
+    {
+        self.routes.push(Route {
+            table: None,
+            ops,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// Dispatches an event to every matching route, in build order.
+    fn dispatch(&mut self, event: SqlitePreUpdateEvent<'_>) {
+        for route in &mut self.routes {
+            if route.matches(&event) {
+                (route.callback)(event);
+            }
+        }
+    }
+
+    /// Turns the router into the callback installed by
+    /// [`on_preupdate`](super::SqliteConnection::on_preupdate).
+    pub(crate) fn into_hook(mut self) -> impl FnMut(SqlitePreUpdateEvent<'_>) + Send {
+        move |event| self.dispatch(event)
+    }
+}
+
+impl Default for SqlitePreUpdateRouter {
+    fn default() -> Self {
+        SqlitePreUpdateRouter::new()
+    }
+}
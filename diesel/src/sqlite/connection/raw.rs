@@ -8,9 +8,15 @@ use sqlite_wasm_rs as ffi;
 use super::SqliteConnection;
 use super::authorizer::{AuthorizerContext, AuthorizerDecision};
 use super::collation_needed::{CollationNeededContext, SqliteTextRep};
+use super::connect_options::SqliteConnectOptions;
 use super::functions::{build_sql_function_args, process_sql_function_result};
 use super::limits::SqliteLimit;
+#[cfg(feature = "sqlite_preupdate_hook")]
+use super::preupdate_hook::SqlitePreUpdateEvent;
 use super::serialized_database::SerializedDatabase;
+#[cfg(feature = "sqlite_snapshot")]
+use super::snapshot::SqliteSnapshot;
+use super::status::{SqliteDbStatus, SqliteDbStatusValue};
 use super::stmt::ensure_sqlite_ok;
 use super::trace::{SqliteTraceEvent, SqliteTraceFlags, TRACE_PROFILE, TRACE_ROW, TRACE_STMT};
 use super::update_hook::{SqliteChangeEvent, SqliteChangeOp};
@@ -66,6 +72,9 @@ pub(super) struct RawConnection {
     pub(super) internal_connection: NonNull<ffi::sqlite3>,
     /// Boxed closure kept alive while the update hook is registered.
     update_hook: Option<Box<dyn FnMut(SqliteChangeEvent<'_>) + Send>>,
+    /// Boxed closure kept alive while the pre-update hook is registered.
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    preupdate_hook: Option<Box<dyn FnMut(SqlitePreUpdateEvent<'_>) + Send>>,
     /// Boxed closure kept alive while the commit hook is registered.
     commit_hook: Option<Box<dyn FnMut() -> CommitDecision + Send>>,
     /// Boxed closure kept alive while the rollback hook is registered.
@@ -92,6 +101,8 @@ impl RawConnection {
         RawConnection {
             internal_connection: conn,
             update_hook: None,
+            #[cfg(feature = "sqlite_preupdate_hook")]
+            preupdate_hook: None,
             commit_hook: None,
             rollback_hook: None,
             progress_hook: None,
@@ -103,17 +114,27 @@ impl RawConnection {
         }
     }
 
-    pub(super) fn establish(database_url: &str) -> ConnectionResult<Self> {
+    pub(super) fn establish(
+        database_url: &str,
+        options: &SqliteConnectOptions,
+    ) -> ConnectionResult<Self> {
         let mut conn_pointer = ptr::null_mut();
 
+        let database_url = options.database_url(database_url);
         let database_url = if database_url.starts_with("sqlite://") {
             CString::new(database_url.replacen("sqlite://", "file:", 1))?
         } else {
-            CString::new(database_url)?
+            CString::new(database_url.as_bytes())?
         };
-        let flags = ffi::SQLITE_OPEN_READWRITE | ffi::SQLITE_OPEN_CREATE | ffi::SQLITE_OPEN_URI;
+        let vfs = options.vfs_name().map(CString::new).transpose()?;
+        let flags = options.open_flags();
         let connection_status = unsafe {
-            ffi::sqlite3_open_v2(database_url.as_ptr(), &mut conn_pointer, flags, ptr::null())
+            ffi::sqlite3_open_v2(
+                database_url.as_ptr(),
+                &mut conn_pointer,
+                flags,
+                vfs.as_deref().map_or(ptr::null(), CStr::as_ptr),
+            )
         };
 
         match connection_status {
@@ -122,6 +143,8 @@ impl RawConnection {
                 Ok(RawConnection {
                     internal_connection: conn_pointer,
                     update_hook: None,
+                    #[cfg(feature = "sqlite_preupdate_hook")]
+                    preupdate_hook: None,
                     commit_hook: None,
                     rollback_hook: None,
                     progress_hook: None,
@@ -328,6 +351,90 @@ impl RawConnection {
         }
     }
 
+    /// Copies `data` into a buffer owned by SQLite and opens it as a writable,
+    /// resizable in-memory database. SQLite frees the buffer when the
+    /// connection is closed or another database is deserialized into it.
+    pub(super) fn deserialize_writable(&mut self, data: &[u8]) -> QueryResult<()> {
+        let db_size: ffi::sqlite3_int64 = data
+            .len()
+            .try_into()
+            .map_err(|e| Error::DeserializationError(Box::new(e)))?;
+        // `sqlite3_malloc64(0)` returns a null pointer, so always allocate at
+        // least one byte to be able to load an empty database
+        let buffer_size = data.len().max(1) as u64;
+        // the cast for the flags is required for old libsqlite3-sys versions
+        #[allow(clippy::unnecessary_cast)]
+        unsafe {
+            let buffer = ffi::sqlite3_malloc64(buffer_size) as *mut u8;
+            if buffer.is_null() {
+                return Err(Error::DatabaseError(
+                    DatabaseErrorKind::Unknown,
+                    Box::new(super::error_message(ffi::SQLITE_NOMEM).to_string()),
+                ));
+            }
+            ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len());
+            // SAFETY: `buffer` was obtained from `sqlite3_malloc64` and is
+            // handed over to SQLite, which frees it on close, on the next
+            // deserialization, or right away if this call fails
+            let result = ffi::sqlite3_deserialize(
+                self.internal_connection.as_ptr(),
+                core::ptr::null(),
+                buffer,
+                db_size,
+                db_size.max(1),
+                (ffi::SQLITE_DESERIALIZE_FREEONCLOSE | ffi::SQLITE_DESERIALIZE_RESIZEABLE) as u32,
+            );
+
+            ensure_sqlite_ok(result, self.internal_connection.as_ptr())
+        }
+    }
+
+    /// Records the state of the `main` database seen by the currently open
+    /// read transaction.
+    #[cfg(feature = "sqlite_snapshot")]
+    pub(super) fn snapshot_get(&self) -> QueryResult<SqliteSnapshot> {
+        let mut snapshot = ptr::null_mut();
+        let result = unsafe {
+            ffi::sqlite3_snapshot_get(
+                self.internal_connection.as_ptr(),
+                c"main".as_ptr(),
+                &mut snapshot,
+            )
+        };
+        ensure_sqlite_ok(result, self.internal_connection.as_ptr())?;
+        let snapshot = NonNull::new(snapshot).ok_or_else(|| {
+            Error::DatabaseError(
+                DatabaseErrorKind::Unknown,
+                Box::new(super::error_message(ffi::SQLITE_NOMEM).to_string()),
+            )
+        })?;
+        // SAFETY: the snapshot was just returned by `sqlite3_snapshot_get`
+        // and is owned by nothing else
+        Ok(unsafe { SqliteSnapshot::from_raw(snapshot) })
+    }
+
+    /// Moves the read transaction that is open on the `main` database to
+    /// `snapshot`.
+    #[cfg(feature = "sqlite_snapshot")]
+    pub(super) fn snapshot_open(&self, snapshot: &SqliteSnapshot) -> QueryResult<()> {
+        let result = unsafe {
+            ffi::sqlite3_snapshot_open(
+                self.internal_connection.as_ptr(),
+                c"main".as_ptr(),
+                snapshot.as_ptr(),
+            )
+        };
+        if result == ffi::SQLITE_OK {
+            Ok(())
+        } else {
+            // `sqlite3_errmsg` is not set by `sqlite3_snapshot_open`
+            Err(DatabaseError(
+                DatabaseErrorKind::Unknown,
+                Box::new(super::error_message(result).to_string()),
+            ))
+        }
+    }
+
     pub(super) fn set_limit(&self, limit: SqliteLimit, value: i32) -> i32 {
         unsafe { ffi::sqlite3_limit(self.internal_connection.as_ptr(), limit.to_ffi(), value) }
     }
@@ -339,6 +446,29 @@ impl RawConnection {
         }
     }
 
+    pub(super) fn db_status(
+        &self,
+        status: SqliteDbStatus,
+        reset: bool,
+    ) -> QueryResult<SqliteDbStatusValue> {
+        let mut current: libc::c_int = 0;
+        let mut highwater: libc::c_int = 0;
+        let result = unsafe {
+            ffi::sqlite3_db_status(
+                self.internal_connection.as_ptr(),
+                status.to_ffi(),
+                &mut current,
+                &mut highwater,
+                libc::c_int::from(reset),
+            )
+        };
+        ensure_sqlite_ok(result, self.internal_connection.as_ptr())?;
+        Ok(SqliteDbStatusValue {
+            current: current.into(),
+            highwater: highwater.into(),
+        })
+    }
+
     /// Set a boolean db_config option.
     pub(super) fn set_db_config_bool(&self, op: i32, value: bool) -> QueryResult<()> {
         let mut result_value: libc::c_int = 0;
@@ -484,6 +614,48 @@ impl RawConnection {
         self.update_hook = None;
     }
 
+    /// Sets the pre-update hook, replacing any previous one.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` is derived from `&raw mut *boxed` and points to the heap
+    /// allocation of the closure. `preupdate_hook_trampoline::<F>` matches the
+    /// signature `sqlite3_preupdate_hook` expects. The pointer stays valid
+    /// because we store `boxed` in `self.preupdate_hook` below, and the hook is
+    /// removed before `sqlite3_close` (see `Drop`).
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    pub(super) fn set_preupdate_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(SqlitePreUpdateEvent<'_>) + Send + 'static,
+    {
+        let mut boxed: Box<dyn FnMut(SqlitePreUpdateEvent<'_>) + Send> = Box::new(hook);
+        let ptr = &raw mut *boxed as *mut libc::c_void;
+
+        unsafe {
+            ffi::sqlite3_preupdate_hook(
+                self.internal_connection.as_ptr(),
+                Some(preupdate_hook_trampoline::<F>),
+                ptr,
+            );
+        }
+
+        // The old box (if any) is dropped here after SQLite has already
+        // switched to the new pointer, preventing use-after-free.
+        self.preupdate_hook = Some(boxed);
+    }
+
+    /// Removes the pre-update hook.
+    ///
+    /// The hook is unregistered before dropping `self.preupdate_hook` so
+    /// SQLite no longer reads the pointer during cleanup.
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    pub(super) fn remove_preupdate_hook(&mut self) {
+        unsafe {
+            ffi::sqlite3_preupdate_hook(self.internal_connection.as_ptr(), None, ptr::null_mut());
+        }
+        self.preupdate_hook = None;
+    }
+
     /// Sets the commit hook, replacing any previous one.
     ///
     /// # Safety
@@ -891,6 +1063,8 @@ impl Drop for RawConnection {
 
         // Unregister before close so the boxed closures drop before sqlite3_close.
         self.remove_update_hook();
+        #[cfg(feature = "sqlite_preupdate_hook")]
+        self.remove_preupdate_hook();
         self.remove_commit_hook();
         self.remove_rollback_hook();
         self.remove_progress_handler();
@@ -1310,6 +1484,54 @@ unsafe extern "C" fn update_hook_trampoline<F>(
     }
 }
 
+/// C trampoline for `sqlite3_preupdate_hook`.
+///
+/// # Safety
+///
+/// `user_data` must point to a live `F` stored in
+/// `RawConnection::preupdate_hook`. `db` is the connection that fired the
+/// hook. As with the update hook, SQLite forbids the callback from modifying
+/// the connection, so the `&mut` borrow is never aliased.
+#[cfg(feature = "sqlite_preupdate_hook")]
+unsafe extern "C" fn preupdate_hook_trampoline<F>(
+    user_data: *mut libc::c_void,
+    db: *mut ffi::sqlite3,
+    op: libc::c_int,
+    db_name: *const libc::c_char,
+    table_name: *const libc::c_char,
+    old_rowid: ffi::sqlite3_int64,
+    new_rowid: ffi::sqlite3_int64,
+) where
+    F: FnMut(SqlitePreUpdateEvent<'_>),
+{
+    let result = crate::util::std_compat::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        // SAFETY: `user_data` points to a live `F` in `RawConnection::preupdate_hook`.
+        let hook = unsafe { &mut *(user_data as *mut F) };
+
+        let Some(db) = NonNull::new(db) else {
+            return;
+        };
+
+        // SAFETY: SQLite passes valid C strings. Decode lossily so a non-UTF-8
+        // name cannot abort the process (matching the update hook).
+        let db_name = unsafe { CStr::from_ptr(db_name) }.to_string_lossy();
+        let table_name = unsafe { CStr::from_ptr(table_name) }.to_string_lossy();
+
+        hook(SqlitePreUpdateEvent::new(
+            db,
+            SqliteChangeOp::from_ffi(op),
+            &db_name,
+            &table_name,
+            old_rowid,
+            new_rowid,
+        ));
+    }));
+
+    if result.is_err() {
+        assert_fail!("Panic in sqlite3_preupdate_hook trampoline. ");
+    }
+}
+
 /// C trampoline for `sqlite3_commit_hook`.
 ///
 /// # Safety
@@ -1654,7 +1876,8 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     fn test_connection() -> RawConnection {
-        RawConnection::establish(":memory:").expect("failed to establish :memory: connection")
+        RawConnection::establish(":memory:", &SqliteConnectOptions::new())
+            .expect("failed to establish :memory: connection")
     }
 
     #[test]
@@ -0,0 +1,67 @@
+#![allow(unsafe_code)] // ffi calls
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+extern crate libsqlite3_sys as ffi;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use sqlite_wasm_rs as ffi;
+
+use core::ptr::NonNull;
+
+/// A handle to the state of a [WAL](https://www.sqlite.org/wal.html) mode
+/// database at one point in time.
+///
+/// Created by [`SqliteConnection::snapshot`](super::SqliteConnection::snapshot)
+/// and passed to
+/// [`SqliteConnection::read_transaction_at`](super::SqliteConnection::read_transaction_at)
+/// on any connection to the same database to read exactly that state. The
+/// snapshot can be shared between threads and is freed when dropped.
+///
+/// See: [`sqlite3_snapshot_get`](https://www.sqlite.org/c3ref/snapshot_get.html)
+pub struct SqliteSnapshot {
+    inner: NonNull<ffi::sqlite3_snapshot>,
+}
+
+// A snapshot is an opaque, immutable blob of data owned by this value.
+// SQLite never modifies it after `sqlite3_snapshot_get` returned, it is only
+// read by `sqlite3_snapshot_open` and `sqlite3_snapshot_cmp`.
+unsafe impl Send for SqliteSnapshot {}
+unsafe impl Sync for SqliteSnapshot {}
+
+impl SqliteSnapshot {
+    /// # Safety
+    ///
+    /// `inner` must be a snapshot returned by `sqlite3_snapshot_get` that is
+    /// not freed by anything else.
+    pub(super) unsafe fn from_raw(inner: NonNull<ffi::sqlite3_snapshot>) -> Self {
+        SqliteSnapshot { inner }
+    }
+
+    pub(super) fn as_ptr(&self) -> *mut ffi::sqlite3_snapshot {
+        self.inner.as_ptr()
+    }
+
+    /// Returns `true` if this snapshot is older than `other`.
+    ///
+    /// Both snapshots must have been taken from the same database file, and
+    /// the comparison is only meaningful as long as the write-ahead log was
+    /// not restarted or truncated in between, for example by a
+    /// [`Restart`](super::WalCheckpointMode::Restart) or
+    /// [`Truncate`](super::WalCheckpointMode::Truncate) checkpoint.
+    ///
+    /// See: [`sqlite3_snapshot_cmp`](https://www.sqlite.org/c3ref/snapshot_cmp.html)
+    pub fn is_older_than(&self, other: &SqliteSnapshot) -> bool {
+        unsafe { ffi::sqlite3_snapshot_cmp(self.as_ptr(), other.as_ptr()) < 0 }
+    }
+}
+
+impl core::fmt::Debug for SqliteSnapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SqliteSnapshot").finish_non_exhaustive()
+    }
+}
+
+impl Drop for SqliteSnapshot {
+    fn drop(&mut self) {
+        unsafe { ffi::sqlite3_snapshot_free(self.inner.as_ptr()) }
+    }
+}
@@ -121,6 +121,28 @@ impl<'row, 'stmt, 'query> SqliteValue<'row, 'stmt, 'query> {
         }
     }
 
+    /// Wraps a value pointer SQLite hands out for the duration of a callback,
+    /// such as the ones returned by `sqlite3_preupdate_old`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must stay valid for `'row`.
+    #[cfg(feature = "sqlite_preupdate_hook")]
+    pub(super) unsafe fn from_raw(
+        value: NonNull<ffi::sqlite3_value>,
+    ) -> Option<SqliteValue<'row, 'stmt, 'query>> {
+        let ret = Self {
+            _row: None,
+            value,
+            string_ref: None,
+        };
+        if ret.value_type().is_none() {
+            None
+        } else {
+            Some(ret)
+        }
+    }
+
     pub(crate) fn as_byte_string(&mut self) -> &'row [u8] {
         unsafe {
             // https://sqlite.org/c3ref/value_blob.html
@@ -0,0 +1,84 @@
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+extern crate libsqlite3_sys as ffi;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use sqlite_wasm_rs as ffi;
+
+/// Per-connection runtime counters that can be read with
+/// [`SqliteConnection::db_status`](crate::sqlite::SqliteConnection::db_status).
+///
+/// See the [SQLite documentation](https://www.sqlite.org/c3ref/c_dbstatus_options.html)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SqliteDbStatus {
+    /// Number of lookaside memory slots currently checked out.
+    LookasideUsed,
+    /// Approximate number of bytes of heap memory used by all pager caches.
+    CacheUsed,
+    /// Approximate number of bytes of heap memory used to store the schema.
+    SchemaUsed,
+    /// Approximate number of bytes of heap and lookaside memory used by all
+    /// prepared statements.
+    StmtUsed,
+    /// Number of malloc attempts that were satisfied using lookaside memory.
+    /// Only the high-water value is meaningful.
+    LookasideHit,
+    /// Number of malloc attempts that might have been satisfied using
+    /// lookaside memory but failed due to the amount of memory requested.
+    /// Only the high-water value is meaningful.
+    LookasideMissSize,
+    /// Number of malloc attempts that might have been satisfied using
+    /// lookaside memory but failed because all lookaside memory was in use.
+    /// Only the high-water value is meaningful.
+    LookasideMissFull,
+    /// Number of pager cache hits. The high-water value is always `0`.
+    CacheHit,
+    /// Number of pager cache misses. The high-water value is always `0`.
+    CacheMiss,
+    /// Number of dirty cache entries written to disk. The high-water value is
+    /// always `0`.
+    CacheWrite,
+    /// `1` if all foreign key constraints are resolved, `0` otherwise. The
+    /// high-water value is always `0`.
+    DeferredFks,
+    /// Like [`CacheUsed`](Self::CacheUsed), but a cache shared between
+    /// several connections is divided evenly between them.
+    CacheUsedShared,
+    /// Number of dirty cache entries written to disk in the middle of a
+    /// transaction because the cache was full. The high-water value is always
+    /// `0`.
+    CacheSpill,
+}
+
+impl SqliteDbStatus {
+    /// Convert to the corresponding FFI constant value.
+    pub(super) fn to_ffi(self) -> i32 {
+        match self {
+            SqliteDbStatus::LookasideUsed => ffi::SQLITE_DBSTATUS_LOOKASIDE_USED,
+            SqliteDbStatus::CacheUsed => ffi::SQLITE_DBSTATUS_CACHE_USED,
+            SqliteDbStatus::SchemaUsed => ffi::SQLITE_DBSTATUS_SCHEMA_USED,
+            SqliteDbStatus::StmtUsed => ffi::SQLITE_DBSTATUS_STMT_USED,
+            SqliteDbStatus::LookasideHit => ffi::SQLITE_DBSTATUS_LOOKASIDE_HIT,
+            SqliteDbStatus::LookasideMissSize => ffi::SQLITE_DBSTATUS_LOOKASIDE_MISS_SIZE,
+            SqliteDbStatus::LookasideMissFull => ffi::SQLITE_DBSTATUS_LOOKASIDE_MISS_FULL,
+            SqliteDbStatus::CacheHit => ffi::SQLITE_DBSTATUS_CACHE_HIT,
+            SqliteDbStatus::CacheMiss => ffi::SQLITE_DBSTATUS_CACHE_MISS,
+            SqliteDbStatus::CacheWrite => ffi::SQLITE_DBSTATUS_CACHE_WRITE,
+            SqliteDbStatus::DeferredFks => ffi::SQLITE_DBSTATUS_DEFERRED_FKS,
+            SqliteDbStatus::CacheUsedShared => ffi::SQLITE_DBSTATUS_CACHE_USED_SHARED,
+            SqliteDbStatus::CacheSpill => ffi::SQLITE_DBSTATUS_CACHE_SPILL,
+        }
+    }
+}
+
+/// The value of a [`SqliteDbStatus`] counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SqliteDbStatusValue {
+    /// The current value of the counter.
+    pub current: i64,
+    /// The highest value of the counter since it was last reset. Not
+    /// meaningful for every counter, see [`SqliteDbStatus`].
+    pub highwater: i64,
+}
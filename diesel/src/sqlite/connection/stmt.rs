@@ -2,8 +2,8 @@
 use super::bind_collector::{SqliteBindCollector, SqliteBindValueRef};
 use super::raw::RawConnection;
 use super::sqlite_value::OwnedSqliteValue;
-use crate::connection::Instrumentation;
 use crate::connection::statement_cache::{MaybeCached, PrepareForCache};
+use crate::connection::{Instrumentation, InstrumentationEvent, StatementStatistics};
 use crate::query_builder::{QueryFragment, QueryId};
 use crate::result::Error::DatabaseError;
 use crate::result::*;
@@ -200,6 +200,21 @@ impl Statement {
     fn raw_connection(&self) -> *mut ffi::sqlite3 {
         unsafe { ffi::sqlite3_db_handle(self.inner_statement.as_ptr()) }
     }
+
+    /// Reads the counters collected since the last call and resets them,
+    /// as cached statements would otherwise accumulate them across runs.
+    fn take_statistics(&mut self) -> StatementStatistics {
+        let counter = |op| {
+            let value = unsafe { ffi::sqlite3_stmt_status(self.inner_statement.as_ptr(), op, 1) };
+            u64::try_from(value).unwrap_or_default()
+        };
+        StatementStatistics {
+            full_scan_steps: counter(ffi::SQLITE_STMTSTATUS_FULLSCAN_STEP),
+            sorts: counter(ffi::SQLITE_STMTSTATUS_SORT),
+            auto_index_rows: counter(ffi::SQLITE_STMTSTATUS_AUTOINDEX),
+            vm_steps: counter(ffi::SQLITE_STMTSTATUS_VM_STEP),
+        }
+    }
 }
 
 pub(super) fn ensure_sqlite_ok(
@@ -286,13 +301,15 @@ struct BoundStatement<'stmt, 'query> {
     binds_to_free: Vec<(i32, Option<NonNull<[u8]>>)>,
     instrumentation: &'stmt mut dyn Instrumentation,
     has_error: bool,
+    report_statistics: bool,
 }
 
 impl<'stmt, 'query> BoundStatement<'stmt, 'query> {
     fn bind<T>(
-        statement: MaybeCached<'stmt, Statement>,
+        mut statement: MaybeCached<'stmt, Statement>,
         query: T,
         instrumentation: &'stmt mut dyn Instrumentation,
+        report_statistics: bool,
     ) -> QueryResult<BoundStatement<'stmt, 'query>>
     where
         T: QueryFragment<Sqlite> + QueryId + 'query,
@@ -307,12 +324,18 @@ impl<'stmt, 'query> BoundStatement<'stmt, 'query> {
         query.collect_binds(&mut bind_collector, &mut (), &Sqlite)?;
         let SqliteBindCollector { binds } = bind_collector;
 
+        if report_statistics {
+            // discard anything counted while statistics were disabled
+            statement.take_statistics();
+        }
+
         let mut ret = BoundStatement {
             statement,
             query: None,
             binds_to_free: Vec::new(),
             instrumentation,
             has_error: false,
+            report_statistics,
         };
 
         ret.bind_buffers(binds)?;
@@ -433,6 +456,15 @@ impl Drop for BoundStatement<'_, '_> {
                     },
                 );
             }
+            if self.report_statistics {
+                let statistics = self.statement.take_statistics();
+                self.instrumentation.on_connection_event(
+                    InstrumentationEvent::StatementStatistics {
+                        query: &crate::debug_query(&query),
+                        statistics,
+                    },
+                );
+            }
             core::mem::drop(query);
             self.query = None;
         }
@@ -471,12 +503,13 @@ impl<'stmt, 'query> StatementUse<'stmt, 'query> {
         statement: MaybeCached<'stmt, Statement>,
         query: T,
         instrumentation: &'stmt mut dyn Instrumentation,
+        report_statistics: bool,
     ) -> QueryResult<StatementUse<'stmt, 'query>>
     where
         T: QueryFragment<Sqlite> + QueryId + 'query,
     {
         Ok(Self {
-            statement: BoundStatement::bind(statement, query, instrumentation)?,
+            statement: BoundStatement::bind(statement, query, instrumentation, report_statistics)?,
             column_names: OnceCell::new(),
         })
     }
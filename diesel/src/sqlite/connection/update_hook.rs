@@ -155,7 +155,7 @@ impl SqliteChangeEvent<'_> {
 
 // A helper trait to use `NamedTable` with trait objects
 // without requiring all the super type restrictions
-trait DynNamedTable {
+pub(super) trait DynNamedTable {
     fn schema(&self) -> Option<&str>;
     fn table(&self) -> &str;
 }
@@ -2695,6 +2695,160 @@ extern "SQL" {
         target: T,
         patch: P,
     ) -> P::Out;
+
+    /// Returns one row for each element of a JSON array or each member of a
+    /// JSON object
+    ///
+    /// This is a table-valued function, which is used as the source of a
+    /// query. Only the top-level elements are returned, use [`json_tree()`]
+    /// to walk the JSON structure recursively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_each;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let elements = json_each(json!([1, "two", [3]]))
+    ///     .select((json_each::key, json_each::value, json_each::r#type))
+    ///     .order(json_each::id)
+    ///     .load::<(Option<String>, Option<String>, String)>(connection)?;
+    /// let expected = vec![
+    ///     (Some("0".to_string()), Some("1".to_string()), "integer".to_string()),
+    ///     (Some("1".to_string()), Some("two".to_string()), "text".to_string()),
+    ///     (Some("2".to_string()), Some("[3]".to_string()), "array".to_string()),
+    /// ];
+    /// assert_eq!(expected, elements);
+    ///
+    /// let keys = json_each(json!({"a": 1, "b": null}))
+    ///     .select(json_each::key)
+    ///     .filter(json_each::value.is_not_null())
+    ///     .load::<Option<String>>(connection)?;
+    /// assert_eq!(vec![Some("a".to_string())], keys);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "__sqlite-shared")]
+    #[table_function(columns(key, value, r#type, atom, id, parent, fullkey, path))]
+    fn json_each(
+        json: Json,
+    ) -> (
+        Nullable<Text>,
+        Nullable<Text>,
+        Text,
+        Nullable<Text>,
+        Integer,
+        Nullable<Integer>,
+        Text,
+        Text,
+    );
+
+    /// Walks a JSON value recursively and returns one row for each element
+    /// of it, including the value itself
+    ///
+    /// This is a table-valued function, which is used as the source of a
+    /// query. Use [`json_each()`] to only return the top-level elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_tree;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// let paths = json_tree(json!({"a": [1, 2]}))
+    ///     .select(json_tree::fullkey)
+    ///     .order(json_tree::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["$", "$.a", "$.a[0]", "$.a[1]"], paths);
+    ///
+    /// let leaves = json_tree(json!({"a": [1, 2]}))
+    ///     .select(json_tree::atom)
+    ///     .filter(json_tree::atom.is_not_null())
+    ///     .order(json_tree::id)
+    ///     .load::<Option<String>>(connection)?;
+    /// assert_eq!(vec![Some("1".to_string()), Some("2".to_string())], leaves);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "__sqlite-shared")]
+    #[table_function(columns(key, value, r#type, atom, id, parent, fullkey, path))]
+    fn json_tree(
+        json: Json,
+    ) -> (
+        Nullable<Text>,
+        Nullable<Text>,
+        Text,
+        Nullable<Text>,
+        Integer,
+        Nullable<Integer>,
+        Text,
+        Text,
+    );
+
+    /// Concatenates the non-null values of `expression`, separated by `separator`
+    ///
+    /// Returns `NULL` if there are no non-null input values. The order of
+    /// the concatenated values is arbitrary unless specified via
+    /// [`AggregateExpressionMethods::aggregate_order`], which requires
+    /// SQLite 3.44 or newer.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::group_concat_with_separator;
+    /// #     use schema::posts::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let titles = posts
+    ///     .select(group_concat_with_separator(title, " | "))
+    ///     .filter(user_id.eq(2))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("My first post too".to_owned()), titles);
+    ///
+    /// #     assert_version!(connection, 3, 44, 0);
+    /// let titles = posts
+    ///     .select(group_concat_with_separator(title, " | ").aggregate_order(id))
+    ///     .filter(user_id.eq(1))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("My first post | About Rust".to_owned()), titles);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "__sqlite-shared")]
+    #[aggregate]
+    #[sql_name = "group_concat"]
+    fn group_concat_with_separator<T: TextOrNullableText + SingleValue>(
+        expression: T,
+        separator: Text,
+    ) -> Nullable<Text>;
 }
 
 pub(super) mod return_type_helpers_reexported {
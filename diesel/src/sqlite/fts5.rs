@@ -0,0 +1,604 @@
+//! Support for the [FTS5](https://www.sqlite.org/fts5.html) full-text search
+//! extension of SQLite.
+//!
+//! FTS5 tables are declared with [`fts5_table!`](crate::fts5_table!), which
+//! works like [`table!`](crate::table!) and additionally provides the
+//! [`Fts5Table`] methods and the auxiliary functions [`bm25`],
+//! [`highlight`] and [`snippet`] for the declared table. Custom tokenizers
+//! are registered with
+//! [`SqliteConnection::register_fts5_tokenizer`](crate::sqlite::SqliteConnection::register_fts5_tokenizer).
+//!
+//! ```rust
+//! # include!("../doctest_setup.rs");
+//! #
+//! # fn main() {
+//! #     run_test().unwrap();
+//! # }
+//! #
+//! diesel::fts5_table! {
+//!     posts_fts {
+//!         title -> Text,
+//!         body -> Text,
+//!     }
+//! }
+//!
+//! # fn run_test() -> QueryResult<()> {
+//! use diesel::sqlite::fts5::{Fts5Table, bm25, highlight};
+//! # let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+//! # diesel::sql_query("CREATE VIRTUAL TABLE posts_fts USING fts5(title, body)").execute(conn)?;
+//!
+//! diesel::insert_into(posts_fts::table)
+//!     .values(&vec![
+//!         (posts_fts::title.eq("Diesel"), posts_fts::body.eq("A safe ORM for Rust")),
+//!         (posts_fts::title.eq("SQLite"), posts_fts::body.eq("An embedded database")),
+//!     ])
+//!     .execute(conn)?;
+//!
+//! let titles = posts_fts::table
+//!     .select(highlight(posts_fts::table, 0, "[", "]"))
+//!     .filter(posts_fts::table.matches("rust OR sqlite"))
+//!     .order(bm25(posts_fts::table))
+//!     .load::<Option<String>>(conn)?;
+//! assert_eq!(titles.len(), 2);
+//! assert!(titles.contains(&Some("[SQLite]".to_string())));
+//! #     Ok(())
+//! # }
+//! ```
+
+use crate::dsl::AsExprOf;
+use crate::expression::grouped::Grouped;
+use crate::expression::{
+    AppearsOnTable, AsExpression, Expression, MixedAggregates, SelectableExpression, ValidGrouping,
+    is_aggregate,
+};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_source::{AppearsInFromClause, NamedTable, Once};
+use crate::result::QueryResult;
+use crate::sql_types::{Bool, Integer, Text};
+use crate::sqlite::Sqlite;
+use crate::sqlite::expression::expression_methods::TextOrNullableText;
+use core::marker::PhantomData;
+
+pub use super::connection::{Fts5TokenSink, Fts5TokenizeReason};
+
+/// Declares a [FTS5](https://www.sqlite.org/fts5.html) virtual table.
+///
+/// The syntax is the same as for [`table!`](crate::table!), except that no
+/// primary key is given: every FTS5 table gets a `rowid -> BigInt` primary
+/// key column in addition to the declared columns. The generated table
+/// implements [`Fts5Table`](crate::sqlite::fts5::Fts5Table).
+///
+/// This macro does not create the table, which still requires a
+/// `CREATE VIRTUAL TABLE ... USING fts5(...)` statement, for example in a
+/// migration.
+///
+/// ```rust
+/// diesel::fts5_table! {
+///     /// Full text index of the documentation
+///     docs_fts {
+///         title -> Text,
+///         content -> Text,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! fts5_table {
+    (
+        $(#[$($table_meta:tt)*])*
+        $table_name:ident {
+            $($(#[$($column_meta:tt)*])* $column_name:ident -> $column_ty:ty),+ $(,)?
+        }
+    ) => {
+        $crate::table! {
+            $(#[$($table_meta)*])*
+            $table_name (rowid) {
+                /// The rowid of the FTS5 table
+                rowid -> BigInt,
+                $($(#[$($column_meta)*])* $column_name -> $column_ty,)+
+            }
+        }
+
+        impl $crate::sqlite::fts5::Fts5Table for $table_name::table {}
+    };
+}
+
+/// A table declared with [`fts5_table!`](crate::fts5_table!).
+pub trait Fts5Table: NamedTable + Sized {
+    /// Creates a SQLite `MATCH` expression over all columns of the FTS5
+    /// table, using the table name as left operand.
+    ///
+    /// `query` uses the [FTS5 query syntax](https://www.sqlite.org/fts5.html#full_text_query_syntax).
+    /// See [`Fts5ExpressionMethods::matches`] to search a single column.
+    fn matches<T>(self, query: T) -> TableMatches<Self, T>
+    where
+        T: AsExpression<Text>,
+    {
+        Grouped(Fts5TableMatch {
+            table: self,
+            query: query.as_expression(),
+        })
+    }
+}
+
+/// FTS5 specific methods present on text expressions.
+pub trait Fts5ExpressionMethods: Expression + Sized {
+    /// Creates a SQLite `MATCH` expression with a column of a FTS5 table as
+    /// left operand, which searches only that column.
+    ///
+    /// `query` uses the [FTS5 query syntax](https://www.sqlite.org/fts5.html#full_text_query_syntax).
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # diesel::fts5_table! { posts_fts { title -> Text, body -> Text, } }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// use diesel::sqlite::fts5::Fts5ExpressionMethods;
+    /// # let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// # diesel::sql_query("CREATE VIRTUAL TABLE posts_fts USING fts5(title, body)").execute(conn)?;
+    /// # diesel::sql_query("INSERT INTO posts_fts (title, body) VALUES ('Diesel', 'About SQLite'), ('SQLite', 'About Diesel')").execute(conn)?;
+    ///
+    /// let titles = posts_fts::table
+    ///     .select(posts_fts::title)
+    ///     .filter(posts_fts::title.matches("diesel"))
+    ///     .load::<String>(conn)?;
+    /// assert_eq!(titles, ["Diesel"]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn matches<T>(self, query: T) -> Matches<Self, T>
+    where
+        T: AsExpression<Text>,
+    {
+        Grouped(Fts5Match::new(self, query.as_expression()))
+    }
+}
+
+impl<T> Fts5ExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: TextOrNullableText,
+{
+}
+
+/// Ranks a match in a FTS5 table with the
+/// [BM25](https://www.sqlite.org/fts5.html#the_bm25_function) algorithm.
+///
+/// Better matches get lower (more negative) values, so ordering ascending by
+/// this function returns the best matches first. Only valid in a query that
+/// filters with [`Fts5Table::matches`] or [`Fts5ExpressionMethods::matches`].
+pub fn bm25<T>(table: T) -> Bm25<T>
+where
+    T: Fts5Table,
+{
+    AuxiliaryFunction::new(table, ())
+}
+
+/// Returns the value of column `column` (0-based, in declaration order) of
+/// the current match, with every matched phrase surrounded by `open` and
+/// `close`.
+///
+/// Returns `NULL` if the column value is `NULL`. Only valid in a query that
+/// filters with [`Fts5Table::matches`] or [`Fts5ExpressionMethods::matches`].
+///
+/// See: <https://www.sqlite.org/fts5.html#the_highlight_function>
+pub fn highlight<T, C, O, E>(table: T, column: C, open: O, close: E) -> Highlight<T, C, O, E>
+where
+    T: Fts5Table,
+    C: AsExpression<Integer>,
+    O: AsExpression<Text>,
+    E: AsExpression<Text>,
+{
+    AuxiliaryFunction::new(
+        table,
+        (
+            column.as_expression(),
+            open.as_expression(),
+            close.as_expression(),
+        ),
+    )
+}
+
+/// Returns a short fragment of the text of the current match, with every
+/// matched phrase surrounded by `open` and `close`.
+///
+/// `column` selects the column to take the fragment from (0-based, in
+/// declaration order), `-1` lets FTS5 choose one. `ellipsis` is added where
+/// the fragment does not start or end with the column value, and
+/// `max_tokens` is the maximum length of the fragment, which must be between
+/// 1 and 64. Only valid in a query that filters with [`Fts5Table::matches`]
+/// or [`Fts5ExpressionMethods::matches`].
+///
+/// See: <https://www.sqlite.org/fts5.html#the_snippet_function>
+pub fn snippet<T, C, O, E, L, N>(
+    table: T,
+    column: C,
+    open: O,
+    close: E,
+    ellipsis: L,
+    max_tokens: N,
+) -> Snippet<T, C, O, E, L, N>
+where
+    T: Fts5Table,
+    C: AsExpression<Integer>,
+    O: AsExpression<Text>,
+    E: AsExpression<Text>,
+    L: AsExpression<Text>,
+    N: AsExpression<Integer>,
+{
+    AuxiliaryFunction::new(
+        table,
+        (
+            column.as_expression(),
+            open.as_expression(),
+            close.as_expression(),
+            ellipsis.as_expression(),
+            max_tokens.as_expression(),
+        ),
+    )
+}
+
+/// The return type of [`table.matches(query)`](Fts5Table::matches)
+pub type TableMatches<T, Q> = Grouped<Fts5TableMatch<T, AsExprOf<Q, Text>>>;
+
+/// The return type of [`expr.matches(query)`](Fts5ExpressionMethods::matches)
+pub type Matches<Lhs, Rhs> = Grouped<Fts5Match<Lhs, AsExprOf<Rhs, Text>>>;
+
+/// The return type of [`bm25(table)`](bm25())
+pub type Bm25<T> = AuxiliaryFunction<functions::bm25, T, ()>;
+
+/// The return type of [`highlight(table, column, open, close)`](highlight())
+pub type Highlight<T, C, O, E> = AuxiliaryFunction<
+    functions::highlight,
+    T,
+    (AsExprOf<C, Integer>, AsExprOf<O, Text>, AsExprOf<E, Text>),
+>;
+
+/// The return type of
+/// [`snippet(table, column, open, close, ellipsis, max_tokens)`](snippet())
+pub type Snippet<T, C, O, E, L, N> = AuxiliaryFunction<
+    functions::snippet,
+    T,
+    (
+        AsExprOf<C, Integer>,
+        AsExprOf<O, Text>,
+        AsExprOf<E, Text>,
+        AsExprOf<L, Text>,
+        AsExprOf<N, Integer>,
+    ),
+>;
+
+crate::infix_operator!(Fts5Match, " MATCH ", backend: Sqlite);
+
+/// A `MATCH` expression with a FTS5 table as left operand, see
+/// [`Fts5Table::matches`].
+#[derive(Debug, Clone, Copy, QueryId)]
+#[doc(hidden)]
+pub struct Fts5TableMatch<T, Q> {
+    table: T,
+    query: Q,
+}
+
+impl<T, Q> Expression for Fts5TableMatch<T, Q> {
+    type SqlType = Bool;
+}
+
+impl<T, Q> QueryFragment<Sqlite> for Fts5TableMatch<T, Q>
+where
+    T: NamedTable,
+    Q: QueryFragment<Sqlite>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Sqlite>) -> QueryResult<()> {
+        // the table name without schema, as `schema.table` would be parsed
+        // as a column reference
+        out.push_identifier(self.table.table())?;
+        out.push_sql(" MATCH ");
+        self.query.walk_ast(out.reborrow())
+    }
+}
+
+impl<T, Q, QS> AppearsOnTable<QS> for Fts5TableMatch<T, Q>
+where
+    QS: AppearsInFromClause<T, Count = Once>,
+    Q: AppearsOnTable<QS>,
+{
+}
+
+impl<T, Q, QS> SelectableExpression<QS> for Fts5TableMatch<T, Q>
+where
+    QS: AppearsInFromClause<T, Count = Once>,
+    Q: SelectableExpression<QS>,
+{
+}
+
+impl<T, Q> ValidGrouping<()> for Fts5TableMatch<T, Q>
+where
+    Q: ValidGrouping<()>,
+    is_aggregate::No: MixedAggregates<Q::IsAggregate>,
+{
+    type IsAggregate = <is_aggregate::No as MixedAggregates<Q::IsAggregate>>::Output;
+}
+
+#[doc(hidden)]
+pub mod functions {
+    use crate::expression::SelectableExpression;
+    use crate::sql_types::{Double, Nullable, SingleValue, Text};
+
+    pub trait AuxiliaryFunctionName {
+        const NAME: &'static str;
+        type SqlType: SingleValue;
+    }
+
+    // `()` is not an expression, so the arguments following the table
+    // cannot be required to be `SelectableExpression` directly
+    pub trait AuxiliaryFunctionArgs<QS> {}
+
+    impl<QS> AuxiliaryFunctionArgs<QS> for () {}
+
+    impl<QS, A, B, C> AuxiliaryFunctionArgs<QS> for (A, B, C) where (A, B, C): SelectableExpression<QS> {}
+
+    impl<QS, A, B, C, D, E> AuxiliaryFunctionArgs<QS> for (A, B, C, D, E) where
+        (A, B, C, D, E): SelectableExpression<QS>
+    {
+    }
+
+    #[derive(Debug, Clone, Copy, crate::query_builder::QueryId)]
+    #[allow(non_camel_case_types)]
+    pub struct bm25;
+
+    impl AuxiliaryFunctionName for bm25 {
+        const NAME: &'static str = "bm25";
+        type SqlType = Double;
+    }
+
+    #[derive(Debug, Clone, Copy, crate::query_builder::QueryId)]
+    #[allow(non_camel_case_types)]
+    pub struct highlight;
+
+    impl AuxiliaryFunctionName for highlight {
+        const NAME: &'static str = "highlight";
+        type SqlType = Nullable<Text>;
+    }
+
+    #[derive(Debug, Clone, Copy, crate::query_builder::QueryId)]
+    #[allow(non_camel_case_types)]
+    pub struct snippet;
+
+    impl AuxiliaryFunctionName for snippet {
+        const NAME: &'static str = "snippet";
+        type SqlType = Text;
+    }
+}
+
+/// A call of a FTS5 auxiliary function, which takes the FTS5 table as its
+/// first argument. See [`bm25`], [`highlight`] and [`snippet`].
+#[derive(Debug, Clone, Copy, QueryId)]
+#[doc(hidden)]
+pub struct AuxiliaryFunction<F, T, Args> {
+    table: T,
+    args: Args,
+    function: PhantomData<F>,
+}
+
+impl<F, T, Args> AuxiliaryFunction<F, T, Args> {
+    fn new(table: T, args: Args) -> Self {
+        AuxiliaryFunction {
+            table,
+            args,
+            function: PhantomData,
+        }
+    }
+}
+
+impl<F, T, Args> Expression for AuxiliaryFunction<F, T, Args>
+where
+    F: functions::AuxiliaryFunctionName,
+{
+    type SqlType = F::SqlType;
+}
+
+impl<F, T, Args> QueryFragment<Sqlite> for AuxiliaryFunction<F, T, Args>
+where
+    F: functions::AuxiliaryFunctionName,
+    T: NamedTable,
+    Args: QueryFragment<Sqlite>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Sqlite>) -> QueryResult<()> {
+        out.push_sql(F::NAME);
+        out.push_sql("(");
+        out.push_identifier(self.table.table())?;
+        if !self.args.is_noop(out.backend())? {
+            out.push_sql(", ");
+            self.args.walk_ast(out.reborrow())?;
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<F, T, Args, QS> AppearsOnTable<QS> for AuxiliaryFunction<F, T, Args>
+where
+    Self: Expression,
+    QS: AppearsInFromClause<T, Count = Once>,
+    Args: functions::AuxiliaryFunctionArgs<QS>,
+{
+}
+
+impl<F, T, Args, QS> SelectableExpression<QS> for AuxiliaryFunction<F, T, Args>
+where
+    Self: AppearsOnTable<QS>,
+    QS: AppearsInFromClause<T, Count = Once>,
+    Args: functions::AuxiliaryFunctionArgs<QS>,
+{
+}
+
+impl<F, T, Args> ValidGrouping<()> for AuxiliaryFunction<F, T, Args>
+where
+    Args: ValidGrouping<()>,
+    is_aggregate::No: MixedAggregates<Args::IsAggregate>,
+{
+    type IsAggregate = <is_aggregate::No as MixedAggregates<Args::IsAggregate>>::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::sqlite::SqliteConnection;
+
+    crate::fts5_table! {
+        books_fts {
+            title -> Text,
+            summary -> Nullable<Text>,
+        }
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        crate::sql_query("CREATE VIRTUAL TABLE books_fts USING fts5(title, summary)")
+            .execute(&mut conn)
+            .unwrap();
+        crate::insert_into(books_fts::table)
+            .values(&vec![
+                (
+                    books_fts::title.eq("The Rust Book"),
+                    books_fts::summary.eq(Some("Learning rust step by step")),
+                ),
+                (
+                    books_fts::title.eq("Rust in Action"),
+                    books_fts::summary.eq(None::<&str>),
+                ),
+                (
+                    books_fts::title.eq("SQLite internals"),
+                    books_fts::summary.eq(Some("How a database engine works")),
+                ),
+            ])
+            .execute(&mut conn)
+            .unwrap();
+        conn
+    }
+
+    #[test]
+    fn renders_table_and_column_matches() {
+        let query = books_fts::table
+            .select((books_fts::rowid, bm25(books_fts::table)))
+            .filter(books_fts::table.matches("rust"))
+            .filter(books_fts::summary.matches("step"));
+        assert_eq!(
+            crate::debug_query::<Sqlite, _>(&query).to_string(),
+            "SELECT `books_fts`.`rowid`, bm25(`books_fts`) FROM `books_fts` \
+             WHERE ((`books_fts` MATCH ?) AND (`books_fts`.`summary` MATCH ?)) \
+             -- binds: [\"rust\", \"step\"]"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn matches_table_and_single_column() {
+        let conn = &mut connection();
+
+        let mut all = books_fts::table
+            .select(books_fts::title)
+            .filter(books_fts::table.matches("rust"))
+            .load::<String>(conn)
+            .unwrap();
+        all.sort();
+        assert_eq!(all, ["Rust in Action", "The Rust Book"]);
+
+        let summaries = books_fts::table
+            .select(books_fts::title)
+            .filter(books_fts::summary.matches("rust"))
+            .load::<String>(conn)
+            .unwrap();
+        assert_eq!(summaries, ["The Rust Book"]);
+    }
+
+    #[diesel_test_helper::test]
+    fn auxiliary_functions_have_sql_types() {
+        let conn = &mut connection();
+
+        let ranked = books_fts::table
+            .select((
+                bm25(books_fts::table),
+                highlight(books_fts::table, 1, "<b>", "</b>"),
+                snippet(books_fts::table, 0, "[", "]", "...", 2),
+            ))
+            .filter(books_fts::table.matches("rust"))
+            .order(bm25(books_fts::table))
+            .load::<(f64, Option<String>, String)>(conn)
+            .unwrap();
+
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked[0].0 <= ranked[1].0);
+        assert_eq!(
+            ranked[0].1.as_deref(),
+            Some("Learning <b>rust</b> step by step")
+        );
+        assert_eq!(ranked[0].2, "The [Rust]...");
+        assert_eq!(ranked[1].1, None);
+    }
+
+    #[diesel_test_helper::test]
+    fn custom_tokenizer_is_used() {
+        use std::sync::{Arc, Mutex};
+
+        let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+        let reasons = Arc::new(Mutex::new(Vec::new()));
+        let captured = reasons.clone();
+        // splits on `-` only and folds to lowercase
+        conn.register_fts5_tokenizer(
+            "dashes",
+            move |text: &str, reason, sink: &mut Fts5TokenSink<'_>| {
+                captured.lock().unwrap().push(reason);
+                let mut start = 0;
+                for part in text.split('-') {
+                    sink.push(&part.to_lowercase(), start..start + part.len());
+                    start += part.len() + 1;
+                }
+            },
+        )
+        .unwrap();
+        crate::sql_query(
+            "CREATE VIRTUAL TABLE books_fts USING fts5(title, summary, tokenize = 'dashes')",
+        )
+        .execute(conn)
+        .unwrap();
+        crate::insert_into(books_fts::table)
+            .values(books_fts::title.eq("Crab-Book"))
+            .execute(conn)
+            .unwrap();
+
+        // whitespace is not a separator for this tokenizer
+        let hits = books_fts::table
+            .select(books_fts::title)
+            .filter(books_fts::table.matches("\"crab\""))
+            .load::<String>(conn)
+            .unwrap();
+        assert_eq!(hits, ["Crab-Book"]);
+
+        let reasons = reasons.lock().unwrap();
+        assert!(reasons.contains(&Fts5TokenizeReason::Document));
+        assert!(reasons.contains(&Fts5TokenizeReason::Query));
+    }
+
+    #[diesel_test_helper::test]
+    fn panicking_tokenizer_fails_the_statement() {
+        let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+        conn.register_fts5_tokenizer("broken", |_: &str, _, _: &mut Fts5TokenSink<'_>| {
+            panic!("broken tokenizer")
+        })
+        .unwrap();
+        crate::sql_query("CREATE VIRTUAL TABLE t USING fts5(a, tokenize = 'broken')")
+            .execute(conn)
+            .unwrap();
+        assert!(
+            crate::sql_query("INSERT INTO t (a) VALUES ('x')")
+                .execute(conn)
+                .is_err()
+        );
+    }
+}
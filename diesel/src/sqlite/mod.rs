@@ -8,6 +8,7 @@ mod auto_extension;
 pub(crate) mod backend;
 pub(crate) mod connection;
 pub mod expression;
+pub mod fts5;
 mod function_behavior;
 
 pub mod query_builder;
@@ -26,6 +27,8 @@ pub use self::connection::SerializedDatabase;
 pub use self::connection::SqliteBindValue;
 pub use self::connection::SqliteConnection;
 pub use self::connection::SqliteLimit;
+#[cfg(feature = "sqlite_snapshot")]
+pub use self::connection::SqliteSnapshot;
 pub use self::connection::SqliteTraceEvent;
 pub use self::connection::SqliteTraceFlags;
 pub use self::connection::SqliteValue;
@@ -42,6 +45,12 @@ pub use self::connection::{
 pub use self::connection::{
     SqliteChangeEvent, SqliteChangeOp, SqliteChangeOps, SqliteUpdateRouter,
 };
+pub use self::connection::{
+    SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous, SqliteThreadingMode,
+};
+pub use self::connection::{SqliteDbStatus, SqliteDbStatusValue};
+#[cfg(feature = "sqlite_preupdate_hook")]
+pub use self::connection::{SqlitePreUpdateEvent, SqlitePreUpdateRouter};
 #[cfg(feature = "__sqlite-shared")]
 pub use self::function_behavior::SqliteFunctionBehavior;
 pub use self::query_builder::SqliteQueryBuilder;
@@ -1,6 +1,7 @@
 use crate::QueryResult;
 use crate::query_builder::AstPass;
 use crate::query_builder::BoxedSelectStatement;
+use crate::query_builder::OptimizerHint;
 use crate::query_builder::QueryFragment;
 use crate::query_builder::SelectStatement;
 use crate::query_builder::select_statement::boxed::BoxedQueryHelper;
@@ -41,3 +42,16 @@ where
         })
     }
 }
+
+// SQLite does not support optimizer hints,
+// so we only emit them as leading comment
+#[cfg(feature = "__sqlite-shared")]
+impl<Q> QueryFragment<crate::sqlite::Sqlite> for OptimizerHint<Q>
+where
+    Q: QueryFragment<crate::sqlite::Sqlite>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, crate::sqlite::Sqlite>) -> QueryResult<()> {
+        self.walk_hint(out.reborrow())?;
+        self.query.walk_ast(out)
+    }
+}
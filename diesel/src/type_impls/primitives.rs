@@ -21,6 +21,10 @@ mod foreign_impls {
     #[derive(AsExpression, FromSqlRow)]
     #[diesel(foreign_derive)]
     #[diesel(sql_type = Bool)]
+    #[cfg_attr(
+        any(feature = "mysql_backend", feature = "mariadb_backend"),
+        diesel(sql_type = crate::mysql_like::sql_types::Bit)
+    )]
     struct BoolProxy(bool);
 
     #[derive(FromSqlRow)]
@@ -94,6 +98,10 @@ mod foreign_impls {
     )]
     #[diesel(foreign_derive)]
     #[cfg_attr(any(feature = "mysql_backend", feature = "mariadb_backend"), diesel(sql_type = crate::sql_types::Unsigned<BigInt>))]
+    #[cfg_attr(
+        any(feature = "mysql_backend", feature = "mariadb_backend"),
+        diesel(sql_type = crate::mysql_like::sql_types::Bit)
+    )]
     struct U64Proxy(u64);
 
     #[derive(AsExpression, FromSqlRow)]
@@ -114,6 +122,9 @@ mod foreign_impls {
     #[cfg_attr(feature = "__sqlite-shared", diesel(sql_type = crate::sql_types::Timestamp))]
     #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Citext))]
     #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::pg::sql_types::Bpchar))]
+    #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Ltree))]
+    #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Lquery))]
+    #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Ltxtquery))]
     struct StringProxy(String);
 
     #[derive(AsExpression)]
@@ -124,6 +135,9 @@ mod foreign_impls {
     #[cfg_attr(feature = "__sqlite-shared", diesel(sql_type = crate::sql_types::Timestamp))]
     #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Citext))]
     #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::pg::sql_types::Bpchar))]
+    #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Ltree))]
+    #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Lquery))]
+    #[cfg_attr(feature = "postgres_backend", diesel(sql_type = crate::sql_types::Ltxtquery))]
     struct StrProxy(str);
 
     #[derive(FromSqlRow)]
@@ -133,11 +147,19 @@ mod foreign_impls {
     #[derive(AsExpression)]
     #[diesel(foreign_derive)]
     #[diesel(sql_type = Binary)]
+    #[cfg_attr(
+        any(feature = "mysql_backend", feature = "mariadb_backend"),
+        diesel(sql_type = crate::mysql_like::sql_types::Bit)
+    )]
     struct BinaryVecProxy(Vec<u8>);
 
     #[derive(AsExpression)]
     #[diesel(foreign_derive, not_sized)]
     #[diesel(sql_type = Binary)]
+    #[cfg_attr(
+        any(feature = "mysql_backend", feature = "mariadb_backend"),
+        diesel(sql_type = crate::mysql_like::sql_types::Bit)
+    )]
     struct BinarySliceProxy([u8]);
 
     #[derive(AsExpression)]
@@ -7,3 +7,6 @@ pub use self::enum_::{EnumMapping, EnumTypeMapping, EnumVariant, IntMapping, Str
 
 #[doc(inline)]
 pub use diesel_derives::Enum;
+
+#[doc(inline)]
+pub use diesel_derives::PgComposite;
@@ -256,6 +256,7 @@ pub enum StructAttr {
     NotSized(Ident),
     ForeignDerive(Ident),
     EnumType(Ident),
+    SetType(Ident),
 
     TableName(Ident, Path),
     SqlType(Ident, TypePath),
@@ -284,6 +285,7 @@ impl Parse for StructAttr {
             "not_sized" => Ok(StructAttr::NotSized(name)),
             "foreign_derive" => Ok(StructAttr::ForeignDerive(name)),
             "enum_type" => Ok(StructAttr::EnumType(name)),
+            "set_type" => Ok(StructAttr::SetType(name)),
 
             "table_name" => Ok(StructAttr::TableName(
                 name,
@@ -369,6 +371,7 @@ impl Parse for StructAttr {
                     "base_query",
                     "base_query_type",
                     "enum_type",
+                    "set_type",
                     "rename_all",
                 ],
             )),
@@ -383,6 +386,7 @@ impl MySpanned for StructAttr {
             | StructAttr::NotSized(ident)
             | StructAttr::ForeignDerive(ident)
             | StructAttr::EnumType(ident)
+            | StructAttr::SetType(ident)
             | StructAttr::TableName(ident, _)
             | StructAttr::SqlType(ident, _)
             | StructAttr::TreatNoneAsDefaultValue(ident, _)
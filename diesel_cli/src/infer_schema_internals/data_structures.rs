@@ -79,6 +79,8 @@ impl ColumnType {
         {
             if last.ident == "PgLsn" {
                 "pg_lsn".to_string()
+            } else if last.ident == "GeometricBox" {
+                "box".to_string()
             } else {
                 last.ident.to_string()
             }
@@ -314,3 +316,17 @@ impl EnumVariant {
         n.to_pascal_case()
     }
 }
+
+/// A single attribute of a PostgreSQL composite type
+#[derive(PartialEq, Debug, Clone)]
+pub struct CompositeField {
+    pub sql_name: String,
+    pub ty: ColumnType,
+}
+
+#[cfg(feature = "postgres")]
+impl CompositeField {
+    pub fn rust_name(&self) -> String {
+        super::inference::rust_name_for_sql_name(&self.sql_name, None)
+    }
+}
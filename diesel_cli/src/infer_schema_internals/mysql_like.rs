@@ -238,7 +238,15 @@ pub(super) fn determine_unsigned(sql_type_name: &str) -> bool {
 }
 
 pub fn get_enum_variants(ct: &ColumnType) -> Option<Vec<EnumVariant>> {
-    if let Some(enum_variants) = ct.unmodified_type.strip_prefix("enum('")
+    parse_variant_list(&ct.unmodified_type, "enum('")
+}
+
+pub fn get_set_members(ct: &ColumnType) -> Option<Vec<EnumVariant>> {
+    parse_variant_list(&ct.unmodified_type, "set('")
+}
+
+fn parse_variant_list(unmodified_type: &str, prefix: &str) -> Option<Vec<EnumVariant>> {
+    if let Some(enum_variants) = unmodified_type.strip_prefix(prefix)
         && let Some(enum_variants) = enum_variants.strip_suffix("')")
     {
         Some(
@@ -46,6 +46,17 @@ mod information_schema {
             domain_schema -> Nullable<VarChar>,
         }
     }
+
+    table! {
+        information_schema.attributes (udt_schema, udt_name, attribute_name) {
+            udt_schema -> VarChar,
+            udt_name -> VarChar,
+            attribute_name -> VarChar,
+            ordinal_position -> BigInt,
+            attribute_udt_name -> VarChar,
+            attribute_udt_schema -> VarChar,
+        }
+    }
 }
 
 table! {
@@ -103,7 +114,7 @@ pub fn determine_column_type(
             }
         }),
         sql_name: tpe.to_string(),
-        rust_name: tpe.to_upper_camel_case(),
+        rust_name: rust_type_name(tpe),
         is_array,
         is_nullable: attr.nullable,
         is_unsigned: false,
@@ -113,6 +124,18 @@ pub fn determine_column_type(
     })
 }
 
+/// Maps a PostgreSQL type name to the name of the corresponding diesel
+/// SQL type
+fn rust_type_name(tpe: &str) -> String {
+    match tpe {
+        // `Box` would shadow `std::boxed::Box` in the generated schema
+        "box" => "GeometricBox".to_owned(),
+        "halfvec" => "HalfVec".to_owned(),
+        "sparsevec" => "SparseVec".to_owned(),
+        _ => tpe.to_upper_camel_case(),
+    }
+}
+
 diesel::postfix_operator!(Regclass, "::regclass", sql_types::Oid, backend: Pg);
 
 fn regclass(table: &TableName) -> Regclass<AsExprOf<String, sql_types::Text>> {
@@ -298,6 +321,54 @@ pub fn load_enum_variants(
     if r.is_empty() { Ok(None) } else { Ok(Some(r)) }
 }
 
+/// Loads the attributes of the composite type `type_name`
+///
+/// Returns `None` if the type does not exist or is not a composite type
+pub fn load_composite_fields(
+    conn: &mut PgConnection,
+    type_name: &str,
+    schema_name: Option<&str>,
+) -> Result<Option<Vec<CompositeField>>, crate::errors::Error> {
+    use self::information_schema::attributes::dsl::*;
+
+    let default_schema = Pg::default_schema(conn)?;
+
+    let rows = attributes
+        .select((
+            attribute_name,
+            attribute_udt_name,
+            attribute_udt_schema.nullable(),
+        ))
+        .filter(udt_name.eq(type_name))
+        .filter(udt_schema.eq(schema_name.unwrap_or(&default_schema)))
+        .order(ordinal_position)
+        .load::<(String, String, Option<String>)>(conn)?;
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    rows.into_iter()
+        .map(|(name, field_type, field_type_schema)| {
+            // attributes of a composite type cannot be declared as `NOT NULL`
+            let info = ColumnInformation::new(
+                name,
+                field_type,
+                field_type_schema,
+                true,
+                None,
+                None,
+                false,
+            );
+            let ty = determine_column_type(&info, default_schema.clone())?;
+            Ok(CompositeField {
+                sql_name: info.column_name,
+                ty,
+            })
+        })
+        .collect::<Result<Vec<_>, crate::errors::Error>>()
+        .map(Some)
+}
+
 #[cfg(test)]
 mod test {
     extern crate dotenvy;
@@ -608,4 +679,34 @@ mod test {
         let variants = super::load_enum_variants(&mut connection, "non_existing", None).unwrap();
         assert!(variants.is_none());
     }
+
+    #[test]
+    fn load_composite_fields() {
+        let mut connection = connection();
+
+        diesel::sql_query("CREATE TYPE test_composite AS (id int4, tags text[])")
+            .execute(&mut connection)
+            .unwrap();
+
+        let fields = super::load_composite_fields(&mut connection, "test_composite", None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].sql_name, "id");
+        assert_eq!(fields[0].ty.rust_name, "Int4");
+        assert!(!fields[0].ty.is_array);
+        assert_eq!(fields[1].sql_name, "tags");
+        assert_eq!(fields[1].ty.rust_name, "Text");
+        assert!(fields[1].ty.is_array);
+
+        let fields = super::load_composite_fields(&mut connection, "non_existing", None).unwrap();
+        assert!(fields.is_none());
+    }
+
+    #[test]
+    fn rust_type_name_maps_pgvector_types() {
+        assert_eq!(rust_type_name("vector"), "Vector");
+        assert_eq!(rust_type_name("halfvec"), "HalfVec");
+        assert_eq!(rust_type_name("sparsevec"), "SparseVec");
+    }
 }
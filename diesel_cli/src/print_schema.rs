@@ -2,7 +2,6 @@ use crate::config::{self, Config};
 use crate::database::{Backend, InferConnection};
 use crate::infer_schema_internals::*;
 use clap::{ArgAction, ArgMatches, Args, FromArgMatches};
-use diesel::QueryResult;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -461,19 +460,37 @@ fn pg_diesel_types() -> HashSet<&'static str> {
     let mut types = HashSet::new();
     types.insert("Cidr");
     types.insert("Citext");
+    types.insert("HalfVec");
+    types.insert("Hstore");
     types.insert("Inet");
     types.insert("Jsonb");
+    types.insert("Lquery");
+    types.insert("Ltree");
+    types.insert("Ltxtquery");
     types.insert("MacAddr");
     types.insert("MacAddr8");
     types.insert("Money");
     types.insert("Oid");
     types.insert("Range");
+    types.insert("SparseVec");
     types.insert("Timestamptz");
     types.insert("Uuid");
+    types.insert("Vector");
     types.insert("Json");
     types.insert("PgLsn");
     types.insert("Record");
     types.insert("Interval");
+    types.insert("TsVector");
+    types.insert("TsQuery");
+    types.insert("RegConfig");
+    types.insert("Point");
+    types.insert("Line");
+    types.insert("LineSegment");
+    types.insert("GeometricBox");
+    types.insert("Path");
+    types.insert("Polygon");
+    types.insert("Circle");
+    types.insert("Varbit");
 
     // hidden type defs
     types.insert("Int4range");
@@ -495,6 +512,10 @@ fn pg_diesel_types() -> HashSet<&'static str> {
     types.insert("Bpchar");
     types.insert("Macaddr");
     types.insert("Macaddr8");
+    types.insert("Tsvector");
+    types.insert("Tsquery");
+    types.insert("Regconfig");
+    types.insert("Lseg");
 
     common_diesel_types(&mut types);
     types
@@ -509,6 +530,10 @@ fn mysql_diesel_types() -> HashSet<&'static str> {
     types.insert("Tinyint");
     types.insert("Datetime");
     types.insert("Json");
+    types.insert("Geometry");
+    types.insert("Point");
+    types.insert("Linestring");
+    types.insert("Polygon");
     types
 }
 
@@ -521,9 +546,38 @@ fn mariadb_diesel_types() -> HashSet<&'static str> {
     types.insert("Tinyint");
     types.insert("Datetime");
     types.insert("Json");
+    types.insert("Geometry");
+    types.insert("Point");
+    types.insert("Linestring");
+    types.insert("Polygon");
     types
 }
 
+/// SQL types that are only exported from the backend specific `sql_types`
+/// module, as `diesel::sql_types` uses the same names for different types
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+const MYSQL_LIKE_SQL_TYPES: &[&str] = &["Bit", "Geometry", "Linestring", "Point", "Polygon"];
+
+/// SQL types that are only exported from `diesel::pg::sql_types`, as
+/// `diesel::sql_types` or commonly used third party crates use the same
+/// names for different types
+#[cfg(feature = "postgres")]
+const PG_SQL_TYPES: &[&str] = &["Bit", "HalfVec", "SparseVec", "Vector"];
+
+#[cfg(any(feature = "mysql", feature = "mariadb", feature = "postgres"))]
+fn backend_sql_types_module(backend: Backend) -> Option<(&'static str, &'static [&'static str])> {
+    match backend {
+        #[cfg(feature = "postgres")]
+        Backend::Pg => Some(("diesel::pg::sql_types", PG_SQL_TYPES)),
+        #[cfg(feature = "mysql")]
+        Backend::Mysql => Some(("diesel::mysql::sql_types", MYSQL_LIKE_SQL_TYPES)),
+        #[cfg(feature = "mariadb")]
+        Backend::Mariadb => Some(("diesel::mariadb::sql_types", MYSQL_LIKE_SQL_TYPES)),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
 #[cfg(feature = "sqlite")]
 fn sqlite_diesel_types() -> HashSet<&'static str> {
     let mut types = HashSet::new();
@@ -566,13 +620,14 @@ fn join_string(mut acc: String, el: &String) -> String {
 pub(crate) struct CustomTypeInfos {
     pub(crate) custom_type_list: Vec<Vec<Option<ColumnType>>>,
     pub(crate) enum_variant_list: HashMap<(String, Option<String>), Vec<EnumVariant>>,
+    pub(crate) composite_field_list: HashMap<(String, Option<String>), Vec<CompositeField>>,
 }
 
 pub(crate) fn load_custom_types(
     connection: &mut InferConnection,
     data: &[QueryRelationData],
     config: &config::PrintSchema,
-) -> QueryResult<CustomTypeInfos> {
+) -> Result<CustomTypeInfos, crate::errors::Error> {
     let backend = Backend::for_connection(connection);
     let diesel_provided_types = match backend {
         #[cfg(feature = "postgres")]
@@ -648,29 +703,56 @@ pub(crate) fn load_custom_types(
         })
         .collect::<Vec<_>>();
 
-    let enum_variants = match connection {
+    let (enum_variants, composite_fields) = match connection {
         #[cfg(feature = "postgres")]
         InferConnection::Pg(pg_connection) => {
-            let types_to_generate = pg_types_to_generate(&custom_types);
-            let mut out = HashMap::new();
-            for t in types_to_generate {
+            let mut types_to_generate = pg_types_to_generate(&custom_types)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut seen = HashSet::new();
+            let mut enum_variants = HashMap::new();
+            let mut composite_fields = HashMap::new();
+            while let Some(t) = types_to_generate.pop() {
+                let key = (t.sql_name.clone(), t.schema.clone());
+                if !seen.insert(key.clone()) {
+                    continue;
+                }
                 if let Some(variants) = crate::infer_schema_internals::pg::load_enum_variants(
                     pg_connection,
                     &t.sql_name,
                     t.schema.as_deref(),
                 )? {
-                    out.insert((t.sql_name.clone(), t.schema.clone()), variants);
+                    enum_variants.insert(key, variants);
+                } else if let Some(fields) =
+                    crate::infer_schema_internals::pg::load_composite_fields(
+                        pg_connection,
+                        &t.sql_name,
+                        t.schema.as_deref(),
+                    )?
+                {
+                    // composite types might contain other custom types,
+                    // which need to be loaded as well
+                    types_to_generate.extend(
+                        fields
+                            .iter()
+                            .map(|f| &f.ty)
+                            .filter(|ty| !diesel_provided_types.contains(ty.rust_name.as_str()))
+                            .cloned(),
+                    );
+                    composite_fields.insert(key, fields);
                 }
             }
-            out
+            (enum_variants, composite_fields)
         }
         #[cfg(any(feature = "mysql", feature = "mariadb", feature = "sqlite"))]
-        _ => HashMap::new(),
+        _ => (HashMap::new(), HashMap::new()),
     };
 
     Ok(CustomTypeInfos {
         custom_type_list: custom_types,
         enum_variant_list: enum_variants,
+        composite_field_list: composite_fields,
     })
 }
 
@@ -818,6 +900,7 @@ pub fn output_schema(
     };
 
     let definitions = QueryRelationDefinitions {
+        backend,
         data,
         fk_constraints_for_joinable: foreign_keys_for_joinable,
         fk_constraints_for_allow_tables: foreign_keys_for_allow_tables,
@@ -828,6 +911,7 @@ pub fn output_schema(
             backend,
             types_overrides_sorted: t.custom_type_list,
             enum_variants: t.enum_variant_list,
+            composite_fields: t.composite_field_list,
             with_docs: match config.with_docs {
                 DocConfig::DatabaseCommentsFallbackToAutoGeneratedDocComment => true,
                 DocConfig::OnlyDatabaseComments | DocConfig::NoDocComments => false,
@@ -938,21 +1022,44 @@ struct RustEnum<'a> {
     tpe: &'a ColumnType,
     variants: Vec<EnumVariant>,
     custom_derives: &'a BTreeSet<String>,
+    is_set: bool,
 }
 
 impl Display for RustEnum<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        writeln!(
-            f,
-            "/// A Rust enum matching the database type [`{s}`](super::sql_types::{s})",
-            s = self.tpe.rust_name
-        )?;
+        if self.is_set {
+            writeln!(
+                f,
+                "/// A Rust enum matching the members of the database type [`{s}`](super::sql_types::{s})",
+                s = self.tpe.rust_name
+            )?;
+            writeln!(f, "///")?;
+            writeln!(
+                f,
+                "/// Use a `std::collections::BTreeSet` of this enum to represent the whole set."
+            )?;
+        } else {
+            writeln!(
+                f,
+                "/// A Rust enum matching the database type [`{s}`](super::sql_types::{s})",
+                s = self.tpe.rust_name
+            )?;
+        }
         writeln!(f, "///")?;
         writeln!(f, "/// (Automatically generated by Diesel.)")?;
+        let mut derives = self.custom_derives.clone();
+        if self.is_set {
+            // required to store the members in a `BTreeSet`
+            derives.extend(
+                ["PartialEq", "Eq", "PartialOrd", "Ord"]
+                    .into_iter()
+                    .map(String::from),
+            );
+        }
         writeln!(
             f,
             "#[derive({})]",
-            self.custom_derives.iter().fold(String::new(), join_string)
+            derives.iter().fold(String::new(), join_string)
         )?;
         writeln!(
             f,
@@ -974,12 +1081,139 @@ impl Display for RustEnum<'_> {
     }
 }
 
-struct RustEnums<'a>(Vec<RustEnum<'a>>);
+#[cfg(feature = "postgres")]
+struct RustCompositeField {
+    rust_name: String,
+    sql_type: String,
+    rust_type: String,
+}
+
+#[cfg(feature = "postgres")]
+struct RustComposite<'a> {
+    tpe: &'a ColumnType,
+    fields: Vec<RustCompositeField>,
+}
+
+#[cfg(feature = "postgres")]
+impl<'a> RustComposite<'a> {
+    /// Returns `None` if any attribute of the composite type
+    /// has a type we cannot map to a Rust type
+    fn new(tpe: &'a ColumnType, custom_types: &CustomTypesForTables) -> Option<Self> {
+        let fields = custom_types
+            .composite_fields
+            .get(&(tpe.sql_name.clone(), tpe.schema.clone()))?;
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let (sql_type, rust_type) = pg_composite_field_type(&field.ty, custom_types)?;
+                Some(RustCompositeField {
+                    rust_name: field.rust_name(),
+                    sql_type,
+                    rust_type,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { tpe, fields })
+    }
+}
 
-impl Display for RustEnums<'_> {
+#[cfg(feature = "postgres")]
+impl Display for RustComposite<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for e in &self.0 {
-            writeln!(f, "{e}\n")?;
+        writeln!(
+            f,
+            "/// A Rust struct matching the database type [`{s}`](super::sql_types::{s})",
+            s = self.tpe.rust_name
+        )?;
+        writeln!(f, "///")?;
+        writeln!(f, "/// (Automatically generated by Diesel.)")?;
+        writeln!(f, "#[derive(Debug, diesel::types::PgComposite)]")?;
+        writeln!(
+            f,
+            "#[diesel(sql_type = super::sql_types::{})]",
+            self.tpe.rust_name
+        )?;
+        writeln!(f, "pub struct {} {{", self.tpe.rust_name)?;
+        let mut out = PadAdapter::new(f);
+        for field in &self.fields {
+            writeln!(out, "#[diesel(sql_type = {})]", field.sql_type)?;
+            writeln!(out, "pub {}: {},", field.rust_name, field.rust_type)?;
+        }
+        writeln!(f, "}}\n")?;
+        Ok(())
+    }
+}
+
+/// Maps the type of a composite type attribute to the
+/// corresponding SQL type and Rust type
+///
+/// Composite type attributes are always nullable.
+#[cfg(feature = "postgres")]
+fn pg_composite_field_type(
+    ty: &ColumnType,
+    custom_types: &CustomTypesForTables,
+) -> Option<(String, String)> {
+    let (sql_type, rust_type) = match ty.rust_name.as_str() {
+        "Bool" => ("diesel::sql_types::Bool".to_owned(), "bool".to_owned()),
+        "Int2" => ("diesel::sql_types::Int2".to_owned(), "i16".to_owned()),
+        "Int4" => ("diesel::sql_types::Int4".to_owned(), "i32".to_owned()),
+        "Int8" => ("diesel::sql_types::Int8".to_owned(), "i64".to_owned()),
+        "Float4" => ("diesel::sql_types::Float4".to_owned(), "f32".to_owned()),
+        "Float8" => ("diesel::sql_types::Float8".to_owned(), "f64".to_owned()),
+        "Oid" => ("diesel::sql_types::Oid".to_owned(), "u32".to_owned()),
+        "Bytea" => ("diesel::sql_types::Bytea".to_owned(), "Vec<u8>".to_owned()),
+        "Text" | "Varchar" | "Bpchar" => (
+            format!("diesel::sql_types::{}", ty.rust_name),
+            "String".to_owned(),
+        ),
+        name => {
+            let key = (ty.sql_name.clone(), ty.schema.clone());
+            let is_mapped = custom_types.enum_variants.contains_key(&key)
+                || (custom_types.composite_fields.contains_key(&key)
+                    && RustComposite::new(ty, custom_types).is_some());
+            if !is_mapped {
+                return None;
+            }
+            (format!("super::sql_types::{name}"), name.to_owned())
+        }
+    };
+    if ty.is_array {
+        Some((
+            format!(
+                "diesel::sql_types::Nullable<diesel::sql_types::Array<diesel::sql_types::Nullable<{sql_type}>>>"
+            ),
+            format!("Option<Vec<Option<{rust_type}>>>"),
+        ))
+    } else {
+        Some((
+            format!("diesel::sql_types::Nullable<{sql_type}>"),
+            format!("Option<{rust_type}>"),
+        ))
+    }
+}
+
+enum RustType<'a> {
+    Enum(RustEnum<'a>),
+    #[cfg(feature = "postgres")]
+    Composite(RustComposite<'a>),
+}
+
+impl Display for RustType<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RustType::Enum(e) => write!(f, "{e}"),
+            #[cfg(feature = "postgres")]
+            RustType::Composite(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+struct RustTypes<'a>(Vec<RustType<'a>>);
+
+impl Display for RustTypes<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for t in &self.0 {
+            writeln!(f, "{t}\n")?;
         }
         Ok(())
     }
@@ -990,6 +1224,7 @@ struct CustomTypesForTables {
     // To be zipped with tables then columns
     types_overrides_sorted: Vec<Vec<Option<ColumnType>>>,
     enum_variants: HashMap<(String, Option<String>), Vec<EnumVariant>>,
+    composite_fields: HashMap<(String, Option<String>), Vec<CompositeField>>,
     with_docs: bool,
     sql_type_derives: BTreeSet<String>,
     rust_type_derives: BTreeSet<String>,
@@ -1008,8 +1243,22 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
             #[cfg(feature = "postgres")]
             Backend::Pg => {
                 let _ = &self.tables;
-                let types_to_generate =
+                let mut types_to_generate =
                     pg_types_to_generate(&self.custom_types.types_overrides_sorted);
+                // custom types only used by attributes of composite types
+                // need a definition as well
+                let diesel_provided_types = pg_diesel_types();
+                types_to_generate.extend(
+                    self.custom_types
+                        .composite_fields
+                        .values()
+                        .flatten()
+                        .map(|f| &f.ty)
+                        .filter(|ty| !diesel_provided_types.contains(ty.rust_name.as_str())),
+                );
+                types_to_generate
+                    .sort_unstable_by_key(|column_type| column_type.rust_name.as_str());
+                types_to_generate.dedup_by_key(|column_type| column_type.rust_name.as_str());
                 if types_to_generate.is_empty() {
                     return Ok(());
                 }
@@ -1028,13 +1277,17 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                         .enum_variants
                         .get(&(ct.sql_name.clone(), ct.schema.clone()))
                     {
-                        rust_types.push(RustEnum {
+                        rust_types.push(RustType::Enum(RustEnum {
                             tpe: ct,
                             variants: variants.clone(),
                             custom_derives: &self.custom_types.rust_type_derives,
-                        });
+                            is_set: false,
+                        }));
                         true
                     } else {
+                        if let Some(composite) = RustComposite::new(ct, self.custom_types) {
+                            rust_types.push(RustType::Composite(composite));
+                        }
                         false
                     };
 
@@ -1079,7 +1332,7 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                 }
 
                 writeln!(f, "}}\n")?;
-                rust_enum_module(f, rust_types, self.custom_types.generate_rust_enums)?;
+                rust_types_module(f, rust_types, self.custom_types.generate_rust_enums)?;
 
                 Ok(())
             }
@@ -1092,6 +1345,7 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                     &self.custom_types.generate_rust_enums,
                     &self.custom_types.rust_type_derives,
                     &self.custom_types.enum_variants,
+                    &self.custom_types.composite_fields,
                     &self.custom_types.sql_type_derives,
                 );
 
@@ -1118,7 +1372,13 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                         sql_name: "".into(),
                     };
                     let _ = a.rust_name();
-                    rust_enum_module(f, Vec::new(), false)?;
+                    let rust_types = vec![RustType::Enum(RustEnum {
+                        tpe: types_to_generate[0],
+                        variants: vec![a],
+                        custom_derives: &self.custom_types.rust_type_derives,
+                        is_set: false,
+                    })];
+                    rust_types_module(f, rust_types, false)?;
                 }
                 unreachable!(
                     "Diesel only support a closed set of types for Sqlite. \
@@ -1138,7 +1398,10 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
 impl<'a> CustomTypesForTablesForDisplay<'a> {
     #[cfg(any(feature = "mysql", feature = "mariadb"))]
     fn mysql_like_display(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
-        let _ = &self.custom_types.enum_variants;
+        let _ = (
+            &self.custom_types.enum_variants,
+            &self.custom_types.composite_fields,
+        );
         let CustomTypesForTables {
             types_overrides_sorted,
             with_docs,
@@ -1173,11 +1436,25 @@ impl<'a> CustomTypesForTablesForDisplay<'a> {
             let enum_type = if let Some(variants) =
                 crate::infer_schema_internals::mysql_like::get_enum_variants(&column.ty)
             {
-                rust_types.push(RustEnum {
+                rust_types.push(RustType::Enum(RustEnum {
                     tpe: custom_type,
                     variants,
                     custom_derives: &self.custom_types.rust_type_derives,
-                });
+                    is_set: false,
+                }));
+                true
+            } else {
+                false
+            };
+            let set_type = if let Some(members) =
+                crate::infer_schema_internals::mysql_like::get_set_members(&column.ty)
+            {
+                rust_types.push(RustType::Enum(RustEnum {
+                    tpe: custom_type,
+                    variants: members,
+                    custom_derives: &self.custom_types.rust_type_derives,
+                    is_set: true,
+                }));
                 true
             } else {
                 false
@@ -1221,18 +1498,21 @@ impl<'a> CustomTypesForTablesForDisplay<'a> {
             if enum_type {
                 writeln!(out, "#[diesel(enum_type)]")?;
             }
+            if set_type {
+                writeln!(out, "#[diesel(set_type)]")?;
+            }
             writeln!(out, "pub struct {};", custom_type.rust_name)?;
         }
 
         writeln!(f, "}}\n")?;
-        rust_enum_module(f, rust_types, self.custom_types.generate_rust_enums)?;
+        rust_types_module(f, rust_types, self.custom_types.generate_rust_enums)?;
         Ok(())
     }
 }
 
-fn rust_enum_module(
+fn rust_types_module(
     f: &mut Formatter<'_>,
-    rust_types: Vec<RustEnum<'_>>,
+    rust_types: Vec<RustType<'_>>,
     generate_rust_enums: bool,
 ) -> Result<(), fmt::Error> {
     if generate_rust_enums && !rust_types.is_empty() {
@@ -1241,7 +1521,7 @@ fn rust_enum_module(
         writeln!(f, "/// (Automatically generated by Diesel.)")?;
         writeln!(f, "pub mod rust_types {{")?;
         let mut out = PadAdapter::new(f);
-        writeln!(out, "{}", RustEnums(rust_types))?;
+        writeln!(out, "{}", RustTypes(rust_types))?;
         writeln!(f, "}}\n")?;
     }
     Ok(())
@@ -1272,6 +1552,7 @@ impl Display for ModuleDefinition<'_> {
 }
 
 struct QueryRelationDefinitions<'a> {
+    backend: Backend,
     data: Vec<QueryRelationData>,
     fk_constraints_for_joinable: Vec<ForeignKeyConstraint>,
     fk_constraints_for_allow_tables: Vec<ForeignKeyConstraint>,
@@ -1296,6 +1577,7 @@ impl<'a> Display for QueryRelationDefinitions<'a> {
                 f,
                 "{}",
                 QueryRelationDefinition {
+                    backend: self.backend,
                     table,
                     with_docs: self.with_docs,
                     import_types: self.import_types,
@@ -1468,6 +1750,7 @@ fn foreign_key_table_groups<'a>(
 }
 
 struct QueryRelationDefinition<'a> {
+    backend: Backend,
     table: &'a QueryRelationData,
     with_docs: DocConfig,
     import_types: Option<&'a [String]>,
@@ -1501,6 +1784,31 @@ impl<'a> Display for QueryRelationDefinition<'a> {
                 }
             }
 
+            #[cfg(any(feature = "mysql", feature = "mariadb", feature = "postgres"))]
+            if let Some((sql_types_module, backend_types)) = backend_sql_types_module(self.backend)
+            {
+                let mut already_imported_backend_types: HashSet<&str> = HashSet::new();
+                for (column_idx, column) in self.table.columns().iter().enumerate() {
+                    let column_type = self
+                        .custom_type_overrides
+                        .and_then(|ct| ct[column_idx].as_ref())
+                        .unwrap_or(&column.ty);
+                    let rust_name = column_type.rust_name.as_str();
+                    if backend_types.contains(&rust_name)
+                        && already_imported_backend_types.insert(rust_name)
+                    {
+                        if !has_written_import {
+                            writeln!(out, "use diesel::sql_types::*;")?;
+                        }
+                        writeln!(out, "use {sql_types_module}::{rust_name};")?;
+                        has_written_import = true;
+                    }
+                }
+            }
+
+            #[cfg(not(any(feature = "mysql", feature = "mariadb", feature = "postgres")))]
+            let _ = self.backend;
+
             #[cfg(any(feature = "mysql", feature = "mariadb", feature = "postgres"))]
             {
                 let mut already_imported_custom_types: HashSet<&str> = HashSet::new();
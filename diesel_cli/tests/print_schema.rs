@@ -164,6 +164,18 @@ fn print_schema_datetime_for_mysql() {
     test_print_schema("print_schema_datetime_for_mysql", vec!["--with-docs"]);
 }
 
+#[test]
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+fn print_schema_spatial_types_for_mysql() {
+    test_print_schema("print_schema_spatial_types_for_mysql", vec!["--with-docs"]);
+}
+
+#[test]
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+fn print_schema_bit_for_mysql() {
+    test_print_schema("print_schema_bit_for_mysql", vec![]);
+}
+
 #[test]
 #[cfg(any(feature = "mysql", feature = "mariadb"))]
 fn print_schema_auto_increment() {
@@ -458,6 +470,48 @@ fn print_schema_citext() {
     test_print_schema("print_schema_citext", vec![])
 }
 
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_hstore() {
+    test_print_schema("print_schema_hstore", vec![])
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_ltree() {
+    test_print_schema("print_schema_ltree", vec![])
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_pgvector() {
+    test_print_schema("print_schema_pgvector", vec![])
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_geometric() {
+    test_print_schema("print_schema_geometric", vec![])
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_bit_string() {
+    test_print_schema("print_schema_bit_string", vec![])
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_composite_types() {
+    test_print_schema("print_schema_composite_types", vec![])
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_text_search() {
+    test_print_schema("print_schema_text_search", vec![])
+}
+
 #[test]
 fn print_schema_with_multiple_schema() {
     test_multiple_print_schema(
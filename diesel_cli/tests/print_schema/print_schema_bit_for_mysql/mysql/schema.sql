@@ -0,0 +1,5 @@
+CREATE TABLE permissions (
+    id INTEGER PRIMARY KEY,
+    flags BIT(8) NOT NULL,
+    mask BIT(64)
+);
@@ -0,0 +1,6 @@
+CREATE TABLE permissions (
+    id SERIAL PRIMARY KEY,
+    flags bit(8) NOT NULL,
+    mask varbit(16),
+    history bit varying
+);
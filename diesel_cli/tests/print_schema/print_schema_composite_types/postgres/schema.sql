@@ -0,0 +1,14 @@
+CREATE TYPE color AS ENUM ('red', 'green');
+CREATE TYPE geo_point AS (lat float8, lon float8);
+CREATE TYPE address AS (
+    street text,
+    number int4,
+    location geo_point,
+    tags text[],
+    color color
+);
+CREATE TABLE locations (
+    id SERIAL PRIMARY KEY,
+    address address NOT NULL,
+    previous_addresses address[]
+);
@@ -0,0 +1,11 @@
+CREATE TABLE shapes (
+    id SERIAL PRIMARY KEY,
+    location point NOT NULL,
+    direction line,
+    edge lseg,
+    bounds box NOT NULL,
+    route path,
+    outline polygon,
+    area circle,
+    waypoints point[] NOT NULL
+);
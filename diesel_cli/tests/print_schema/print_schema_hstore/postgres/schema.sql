@@ -0,0 +1,7 @@
+CREATE EXTENSION IF NOT EXISTS hstore;
+
+CREATE TABLE products (
+    id SERIAL PRIMARY KEY,
+    attributes hstore NOT NULL,
+    extra_attributes hstore
+);
@@ -0,0 +1,9 @@
+CREATE EXTENSION IF NOT EXISTS ltree;
+
+CREATE TABLE categories (
+    id SERIAL PRIMARY KEY,
+    path ltree NOT NULL,
+    parent_path ltree,
+    pattern lquery,
+    search ltxtquery
+);
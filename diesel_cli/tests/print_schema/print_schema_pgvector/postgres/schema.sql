@@ -0,0 +1,8 @@
+CREATE EXTENSION IF NOT EXISTS vector;
+
+CREATE TABLE items (
+    id SERIAL PRIMARY KEY,
+    embedding vector(3) NOT NULL,
+    half_embedding halfvec(3),
+    sparse_embedding sparsevec(5)
+);
@@ -0,0 +1,7 @@
+CREATE TABLE places (
+    id INTEGER PRIMARY KEY,
+    area GEOMETRY NOT NULL,
+    location POINT NOT NULL,
+    route LINESTRING,
+    boundary POLYGON
+);
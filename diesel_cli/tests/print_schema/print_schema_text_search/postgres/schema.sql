@@ -0,0 +1,6 @@
+CREATE TABLE documents (
+    id SERIAL PRIMARY KEY,
+    search tsvector NOT NULL,
+    saved_query tsquery,
+    config regconfig NOT NULL
+);
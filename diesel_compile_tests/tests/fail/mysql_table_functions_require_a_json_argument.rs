@@ -0,0 +1,29 @@
+extern crate diesel;
+
+use diesel::expression::functions::declare_sql_function;
+use diesel::sql_types::*;
+use diesel::*;
+
+#[declare_sql_function]
+extern "SQL" {
+    #[table_function(columns(value))]
+    fn generate_series(start: Integer, stop: Integer) -> Integer;
+
+    #[table_function(columns(value))]
+    fn single_integer(start: Integer) -> Integer;
+
+    #[table_function(columns(value))]
+    fn json_values(json: Json) -> Integer;
+}
+
+fn main() {
+    let mut connection = MysqlConnection::establish("").unwrap();
+
+    generate_series(1, 10).load::<i32>(&mut connection);
+    //~^ ERROR: the trait bound
+
+    single_integer(1).load::<i32>(&mut connection);
+    //~^ ERROR: the trait bound
+
+    json_values(dsl::sql::<Json>("'[{\"value\": 1}]'")).load::<i32>(&mut connection);
+}
@@ -0,0 +1,26 @@
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+    let mut connection = MariadbConnection::establish("").unwrap();
+
+    update(users.filter(id.eq(1)))
+        .set(name.eq("Bill"))
+        .get_result(&mut connection);
+    //~^ ERROR: the backend does not support `RETURNING` clauses for `UPDATE` statements
+
+    update(users.filter(id.eq(1)))
+        .set(name.eq("Bill"))
+        .returning(name)
+        .get_result(&mut connection);
+    //~^ ERROR: the backend does not support `RETURNING` clauses for `UPDATE` statements
+}
@@ -242,6 +242,10 @@ fn main() {
             "from_sql_row",
             vec![Example::new("diesel_derives__tests__from_sql_row_1.snap")],
         ),
+        (
+            "pg_composite",
+            vec![Example::new("diesel_derives__tests__pg_composite_1.snap")],
+        ),
         (
             "identifiable",
             vec![
@@ -43,6 +43,7 @@ mod has_query;
 mod identifiable;
 mod insertable;
 mod multiconnection;
+mod pg_composite;
 mod query_id;
 mod queryable;
 mod queryable_by_name;
@@ -1063,6 +1064,13 @@ fn derive_selectable_inner(input: proc_macro2::TokenStream) -> proc_macro2::Toke
 ///   with the given name. `TypeName` needs to be one of the possible values
 ///   in `MariadbType`
 ///
+/// ## Optional container attributes
+///
+/// * `#[diesel(enum_type)]`, to indicate that the type represents a SQL side enum
+/// * `#[diesel(set_type)]`, to indicate that the type represents a MySQL or MariaDB `SET`.
+///   The members of the set can be mapped with [`#[derive(Enum)]`](derive@Enum)
+///   and a `std::collections::BTreeSet` of these enums represents the whole value
+///
 #[cfg_attr(diesel_docsrs, doc = include_str!(concat!(env!("OUT_DIR"), "/sql_type.md")))]
 #[cfg_attr(
     all(not(feature = "without-deprecated"), feature = "with-deprecated"),
@@ -2217,6 +2225,9 @@ const AUTO_TYPE_DEFAULT_FUNCTION_TYPE_CASE: dsl_auto_type::Case = dsl_auto_type:
 ///   - Indicates that this is a variadic function, where `argument_count` is a
 ///     nonnegative integer representing the number of variadic arguments the
 ///     function accepts.
+/// - `#[table_function(columns(a, b), define_column_types = true)]`
+///   - Indicates that this is a set-returning function, which is used as the
+///     source of a query. See [Table functions](#table-functions) for details.
 ///
 /// Functions can also be generic. Take the definition of `sum`, for example:
 ///
@@ -2675,6 +2686,114 @@ const AUTO_TYPE_DEFAULT_FUNCTION_TYPE_CASE: dsl_auto_type::Case = dsl_auto_type:
 /// # type skipped_type = return_type_helpers::f;
 /// ```
 ///
+/// ## Table functions
+///
+/// Set-returning functions like `generate_series` or `jsonb_each` on PostgreSQL
+/// or `json_each` on SQLite return a set of rows instead of a single value.
+/// They can be declared with the `#[table_function(columns(...))]` attribute,
+/// which lists the names of the columns returned by the function. The return
+/// type of the declaration is the SQL type of the only column or a tuple with
+/// the SQL types of all columns.
+///
+/// Instead of an expression, the generated function returns a
+/// [`TableFunction`](diesel::query_source::TableFunction), which can be used
+/// like a table: Query builder methods can be called on it directly and it can
+/// be joined to other tables with an explicit `ON` clause. Additionally a module
+/// with the same name as the function is generated. It contains one type per
+/// column, which can be used in the query like a column of a table. No return
+/// type helper is generated for table functions.
+///
+/// On PostgreSQL the column names are passed as column definition list after
+/// the function call, so they can be chosen freely. Functions returning
+/// `record` require the SQL types of the columns as well, which are
+/// added to this list by setting `define_column_types = true`. On SQLite the
+/// column names need to match the columns of the function.
+///
+/// ```rust
+/// # extern crate diesel;
+/// # use diesel::prelude::*;
+/// # use diesel::expression::functions::declare_sql_function;
+/// # use diesel::sql_types::*;
+/// #
+/// table! {
+///     events {
+///         id -> Integer,
+///         payload -> Jsonb,
+///     }
+/// }
+///
+/// # #[cfg(feature = "postgres")]
+/// #[declare_sql_function]
+/// extern "SQL" {
+///     #[table_function(columns(a, b), define_column_types = true)]
+///     fn jsonb_to_recordset(json: Jsonb) -> (Integer, Nullable<Text>);
+/// }
+///
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// let query = events::table
+///     .inner_join(jsonb_to_recordset(events::payload).on(jsonb_to_recordset::a.gt(events::id)))
+///     .select((events::id, jsonb_to_recordset::b));
+///
+/// let sql = diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string();
+/// assert_eq!(
+///     sql,
+///     "SELECT \"events\".\"id\", \"jsonb_to_recordset\".\"b\" \
+///      FROM (\"events\" INNER JOIN jsonb_to_recordset(\"events\".\"payload\") \
+///      AS \"jsonb_to_recordset\"(\"a\" int4, \"b\" text) \
+///      ON (\"jsonb_to_recordset\".\"a\" > \"events\".\"id\")) -- binds: []"
+/// );
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+///
+/// MySQL and MariaDB only provide `JSON_TABLE`, which turns the elements of
+/// a JSON array into rows. Any table function declared with a single JSON
+/// argument is written as `JSON_TABLE` call for these backends, independent of
+/// its SQL name. Table functions with other arguments cannot be used with
+/// these backends. Each column is filled from the member with the same name of
+/// the array elements, as in `COLUMNS (a INTEGER PATH '$."a"')`, so the columns
+/// need to be named after these members. Missing members are returned as
+/// `NULL`, which requires a `Nullable` column type. To turn a nested array
+/// into rows pass it as argument, e.g. by using
+/// [`json_extract`](diesel::dsl::json_extract) on the document.
+///
+/// ```rust
+/// # extern crate diesel;
+/// # use diesel::prelude::*;
+/// # use diesel::expression::functions::declare_sql_function;
+/// # use diesel::sql_types::*;
+/// #
+/// # #[cfg(feature = "mysql")]
+/// #[declare_sql_function]
+/// extern "SQL" {
+///     #[table_function(columns(id, name))]
+///     fn json_users(json: Json) -> (Integer, Nullable<Text>);
+/// }
+///
+/// # #[cfg(feature = "mysql")]
+/// # fn main() {
+/// let query = json_users(diesel::dsl::sql::<Json>("'[{\"id\": 1, \"name\": \"Sean\"}]'"))
+///     .select((json_users::id, json_users::name));
+///
+/// let sql = diesel::debug_query::<diesel::mysql::Mysql, _>(&query).to_string();
+/// assert_eq!(
+///     sql,
+///     "SELECT `json_users`.`id`, `json_users`.`name` \
+///      FROM JSON_TABLE('[{\"id\": 1, \"name\": \"Sean\"}]', '$[*]' \
+///      COLUMNS (`id` INTEGER PATH '$.\"id\"', `name` TEXT PATH '$.\"name\"')) \
+///      AS `json_users` -- binds: []"
+/// );
+/// # }
+/// # #[cfg(not(feature = "mysql"))]
+/// # fn main() {}
+/// ```
+///
+/// The columns of a table function refer to the function itself, not to a
+/// specific call of it. Therefore each table function can only appear once in
+/// a query and different table functions cannot be combined in the same query.
+///
 #[cfg_attr(diesel_docsrs, doc = include_str!(concat!(env!("OUT_DIR"), "/declare_sql_function.md")))]
 #[proc_macro_attribute]
 pub fn declare_sql_function(
@@ -2903,6 +3022,9 @@ fn derive_has_query_inner(input: proc_macro2::TokenStream) -> proc_macro2::Token
 ///   This requires annotating every variant with an explicit discriminant value
 /// * `diesel::sql_types::Text` for all backend to (de)serialize a Rust enum as text values.
 /// * Any custom SQL type marked with `#[diesel(enum_type)]`
+/// * Any custom SQL type marked with `#[diesel(set_type)]` to (de)serialize a single member
+///   of a MySQL or MariaDB `SET`. Use a `std::collections::BTreeSet` of the enum to map the
+///   whole set
 ///
 /// Additional it internally generates the same implementations as `#[derive(FromSqlRow)]`
 /// and `#[derive(AsExpression)]`
@@ -3015,3 +3137,90 @@ fn derive_enum_inner(input: proc_macro2::TokenStream) -> proc_macro2::TokenStrea
         .and_then(enum_::derive)
         .unwrap_or_else(syn::Error::into_compile_error)
 }
+
+/// Implements `FromSql` and `ToSql` for PostgreSQL composite types
+///
+/// This derive maps a struct to a named PostgreSQL composite type
+/// (created via `CREATE TYPE name AS (...)`). Values are (de)serialized
+/// using the binary record format, with one record attribute per struct field
+/// in declaration order.
+///
+/// The SQL type referenced by the struct needs to be declared with
+/// `#[diesel(postgres_type(name = "..."))]`, so that diesel can look up the
+/// OID of the composite type at runtime. `diesel print-schema` generates
+/// these SQL types and, by default, a matching Rust struct using this derive.
+///
+/// Fields can use any type that implements `FromSql` and `ToSql` for the
+/// given field SQL type. This includes other structs using this derive to
+/// represent nested composite types and `Vec<T>` for arrays of composite types.
+///
+/// Additionally it internally generates the same implementations as `#[derive(FromSqlRow)]`
+/// and `#[derive(AsExpression)]`
+///
+/// # Attributes
+///
+/// ## Required container attributes
+///
+/// * `#[diesel(sql_type = path::to::MyCompositeType)]`, specifies the composite type this
+///   struct represents, can appear several times
+///
+/// ## Required field attributes
+///
+/// * `#[diesel(sql_type = path::to::SqlType)]`, specifies the SQL type of the
+///   corresponding composite type attribute. Remember that attributes of composite
+///   types are always nullable on the database side, so you might want to use
+///   `Nullable<_>` and `Option<_>` here
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate diesel;
+/// # extern crate dotenvy;
+/// # include!("../../diesel/src/doctest_setup.rs");
+/// #
+/// # #[cfg(feature = "postgres")]
+/// mod sql_types {
+///     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+///     #[diesel(postgres_type(name = "address"))]
+///     pub struct Address;
+/// }
+///
+/// # #[cfg(feature = "postgres")]
+/// #[derive(Debug, PartialEq, diesel::types::PgComposite)]
+/// #[diesel(sql_type = sql_types::Address)]
+/// struct Address {
+///     #[diesel(sql_type = diesel::sql_types::Text)]
+///     street: String,
+///     #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Integer>)]
+///     number: Option<i32>,
+/// }
+///
+/// # #[cfg(feature = "postgres")]
+/// # fn main() -> QueryResult<()> {
+/// # let connection = &mut connection_no_data();
+/// # diesel::sql_query("DROP TYPE IF EXISTS address").execute(connection)?;
+/// # diesel::sql_query("CREATE TYPE address AS (street TEXT, number INTEGER)").execute(connection)?;
+/// let address = Address {
+///     street: "Main Street".into(),
+///     number: Some(42),
+/// };
+/// let r = diesel::select(address.into_sql::<sql_types::Address>())
+///     .get_result::<Address>(connection)?;
+/// assert_eq!(r.street, "Main Street");
+/// assert_eq!(r.number, Some(42));
+/// Ok(())
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+#[cfg_attr(diesel_docsrs, doc = include_str!(concat!(env!("OUT_DIR"), "/pg_composite.md")))]
+#[proc_macro_derive(PgComposite, attributes(diesel))]
+pub fn derive_pg_composite(input: TokenStream) -> TokenStream {
+    derive_pg_composite_inner(input.into()).into()
+}
+
+fn derive_pg_composite_inner(input: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    syn::parse2(input)
+        .and_then(pg_composite::derive)
+        .unwrap_or_else(syn::Error::into_compile_error)
+}
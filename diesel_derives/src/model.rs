@@ -28,6 +28,7 @@ pub struct Model {
     pub not_sized: bool,
     pub foreign_derive: bool,
     pub enum_type: bool,
+    pub set_type: bool,
     pub mysql_type: Option<MysqlType>,
     pub mariadb_type: Option<MariadbType>,
     pub sqlite_type: Option<SqliteType>,
@@ -75,6 +76,7 @@ impl Model {
         let mut aggregate = false;
         let mut not_sized = false;
         let mut enum_type = false;
+        let mut set_type = false;
         let mut foreign_derive = false;
         let mut mysql_type = None;
         let mut mariadb_type = None;
@@ -109,6 +111,7 @@ impl Model {
                 StructAttr::NotSized(_) => not_sized = true,
                 StructAttr::ForeignDerive(_) => foreign_derive = true,
                 StructAttr::EnumType(_) => enum_type = true,
+                StructAttr::SetType(_) => set_type = true,
                 StructAttr::MysqlType(_, val) => mysql_type = Some(val),
                 StructAttr::MariadbType(_, val) => mariadb_type = Some(val),
                 StructAttr::SqliteType(_, val) => sqlite_type = Some(val),
@@ -144,6 +147,7 @@ impl Model {
             base_query,
             base_query_type,
             enum_type,
+            set_type,
         })
     }
 
@@ -0,0 +1,98 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Ident, Result};
+
+use crate::model::Model;
+use crate::util::wrap_in_dummy_mod;
+
+pub fn derive(item: DeriveInput) -> Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "this derive can only be used on structs without generic parameters",
+        ));
+    }
+    let model = Model::from_item(&item, false, false)?;
+    if model.sql_types.is_empty() {
+        return Err(syn::Error::new(
+            item.ident.span(),
+            "no `#[diesel(sql_type = ...)]` attribute provided",
+        ));
+    }
+
+    let struct_name = &item.ident;
+    let field_ty = model.fields().iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let field_sql_type = model
+        .fields()
+        .iter()
+        .map(|f| {
+            f.sql_type.as_ref().map(|st| &st.item).ok_or_else(|| {
+                syn::Error::new(
+                    f.span,
+                    "all fields of a `PgComposite` struct must be annotated with \
+                     `#[diesel(sql_type = ...)]`",
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let field_name = model.fields().iter().map(|f| &f.name).collect::<Vec<_>>();
+    let field_binding = (0..model.fields().len())
+        .map(|i| Ident::new(&format!("__field_{i}"), Span::mixed_site()))
+        .collect::<Vec<_>>();
+
+    let impls = model.sql_types.iter().map(|sql_type| {
+        quote! {
+            diesel::internal::derives::pg_composite::expand_pg! {
+                impl diesel::deserialize::FromSql<#sql_type, diesel::pg::Pg> for #struct_name {
+                    fn from_sql(
+                        value: diesel::pg::PgValue<'_>,
+                    ) -> diesel::deserialize::Result<Self> {
+                        let (#(#field_binding,)*) = <(#(#field_ty,)*) as diesel::deserialize::FromSql<
+                            diesel::sql_types::Record<(#(#field_sql_type,)*)>,
+                            diesel::pg::Pg,
+                        >>::from_sql(value)?;
+                        diesel::deserialize::Result::Ok(Self {
+                            #(#field_name: #field_binding,)*
+                        })
+                    }
+                }
+            }
+
+            diesel::internal::derives::pg_composite::expand_pg! {
+                impl diesel::serialize::ToSql<#sql_type, diesel::pg::Pg> for #struct_name {
+                    fn to_sql<'b>(
+                        &'b self,
+                        out: &mut diesel::serialize::Output<'b, '_, diesel::pg::Pg>,
+                    ) -> diesel::serialize::Result {
+                        diesel::serialize::WriteTuple::<(#(#field_sql_type,)*)>::write_tuple(
+                            &(#(&self.#field_name,)*),
+                            out,
+                        )
+                    }
+                }
+            }
+        }
+    });
+
+    let struct_ty = syn::Type::Path(syn::TypePath {
+        qself: None,
+        path: item.ident.clone().into(),
+        attrs: Vec::new(),
+    });
+    let as_expression_impl = super::as_expression::derive_inner(
+        model.sql_types.clone(),
+        syn::Generics::default(),
+        struct_ty.clone(),
+        false,
+        false,
+    )?;
+    let from_sql_row_impl = super::from_sql_row::derive_inner(struct_ty, syn::Generics::default())?;
+
+    Ok(wrap_in_dummy_mod(quote! {
+        #(#impls)*
+
+        #as_expression_impl
+        #from_sql_row_impl
+    }))
+}
@@ -7,6 +7,7 @@ use quote::TokenStreamExt;
 use quote::format_ident;
 use quote::quote;
 use std::iter;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Result};
 use syn::punctuated::Pair;
 use syn::punctuated::Punctuated;
@@ -95,6 +96,13 @@ fn expand_one(
         }
     });
 
+    if attributes
+        .iter()
+        .any(|attr| matches!(attr.item, SqlFunctionAttribute::TableFunction { .. }))
+    {
+        return expand_table_function(input, legacy_helper_type_and_module);
+    }
+
     let Some((variadic_argument_count, variadic_span, non_zero_variadic)) = variadic_argument_count
     else {
         let sql_name = parse_sql_name_attr(&mut input);
@@ -695,6 +703,426 @@ fn expand_nonvariadic(
     })
 }
 
+fn expand_table_function(
+    mut input: SqlFunctionDecl,
+    legacy_helper_type_and_module: bool,
+) -> syn::Result<ExpandedSqlFunction> {
+    let sql_name = parse_sql_name_attr(&mut input);
+    let SqlFunctionDecl {
+        attributes,
+        fn_token,
+        fn_name,
+        generics,
+        args,
+        return_type,
+    } = input;
+
+    if legacy_helper_type_and_module {
+        return Err(syn::Error::new(
+            fn_name.span(),
+            "`#[table_function]` is only supported by `#[declare_sql_function]`",
+        ));
+    }
+
+    let mut table_function = None;
+    let mut cfg_attributes = Vec::new();
+    let mut other_attributes = Vec::new();
+    for attr in attributes {
+        match attr.item {
+            SqlFunctionAttribute::TableFunction {
+                columns,
+                define_column_types,
+                ..
+            } => table_function = Some((columns, define_column_types)),
+            SqlFunctionAttribute::Aggregate { .. }
+            | SqlFunctionAttribute::Window { .. }
+            | SqlFunctionAttribute::Variadic { .. } => {
+                return Err(syn::Error::new(
+                    attr.ident_span,
+                    "`#[table_function]` cannot be combined with \
+                     `#[aggregate]`, `#[window]` or `#[variadic]`",
+                ));
+            }
+            // table functions never generate return type helpers and the
+            // supported backends are determined by the `QueryFragment`
+            // implementations of `TableFunction`
+            SqlFunctionAttribute::SqlName { .. }
+            | SqlFunctionAttribute::SkipReturnTypeHelper { .. }
+            | SqlFunctionAttribute::Restriction { .. } => {}
+            SqlFunctionAttribute::Other(a) if a.path().is_ident("cfg") => cfg_attributes.push(a),
+            SqlFunctionAttribute::Other(a) => other_attributes.push(a),
+        }
+    }
+    let (column_name, define_column_types) =
+        table_function.expect("We only call this function for table functions");
+
+    let column_type = match &return_type {
+        _ if column_name.len() == 1 => vec![return_type.clone()],
+        Type::Tuple(tuple) if tuple.elems.len() == column_name.len() => {
+            tuple.elems.iter().cloned().collect()
+        }
+        _ => {
+            return Err(syn::Error::new(
+                return_type.span(),
+                format!(
+                    "expect a tuple of {} SQL types as return type, one for each column",
+                    column_name.len()
+                ),
+            ));
+        }
+    };
+    let column_sql_name = column_name
+        .iter()
+        .map(|c| c.unraw().to_string())
+        .collect::<Vec<_>>();
+    let alias_name = fn_name.to_string();
+
+    let (ref arg_name, ref arg_type): (Vec<_>, Vec<_>) = args
+        .iter()
+        .map(|StrictFnArg { name, ty, .. }| (name, ty))
+        .unzip();
+
+    let type_args = generics
+        .type_params()
+        .map(|type_param| type_param.ident.clone())
+        .collect::<Vec<_>>();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // Even if we force an empty where clause, it still won't print the where
+    // token with no bounds.
+    let where_clause = where_clause
+        .map(|w| quote!(#w))
+        .unwrap_or_else(|| quote!(where));
+
+    let mut fn_generics = generics.clone();
+    for StrictFnArg { name, .. } in &args {
+        fn_generics.params.push(parse_quote!(#name));
+    }
+    let (fn_impl_generics, fn_ty_generics, _) = fn_generics.split_for_impl();
+
+    let mut generics_with_internal = generics.clone();
+    generics_with_internal
+        .params
+        .push(parse_quote!(__DieselInternal));
+    let (impl_generics_internal, _, _) = generics_with_internal.split_for_impl();
+
+    let mut generics_with_join = generics.clone();
+    generics_with_join.params.push(parse_quote!(Left));
+    generics_with_join.params.push(parse_quote!(Right));
+    let (impl_generics_join, _, _) = generics_with_join.split_for_impl();
+
+    let mut generics_with_join_on = generics.clone();
+    generics_with_join_on.params.push(parse_quote!(Join));
+    generics_with_join_on.params.push(parse_quote!(On));
+    let (impl_generics_join_on, _, _) = generics_with_join_on.split_for_impl();
+
+    let mut generics_with_from = generics.clone();
+    generics_with_from.params.push(parse_quote!(From));
+    let (impl_generics_from, _, _) = generics_with_from.split_for_impl();
+
+    // columns of generic functions need to carry the generic parameters
+    // to be able to name their SQL type
+    let (struct_body, constructor) = if type_args.is_empty() {
+        (quote!(;), quote!())
+    } else {
+        (
+            quote!((::core::marker::PhantomData<(#(#type_args,)*)>);),
+            quote!((::core::marker::PhantomData)),
+        )
+    };
+
+    let all_columns_type = if column_name.len() == 1 {
+        quote!(#(#column_name #ty_generics)*)
+    } else {
+        quote!((#(#column_name #ty_generics,)*))
+    };
+    let all_columns_value = if column_name.len() == 1 {
+        quote!(#(#column_name #constructor)*)
+    } else {
+        quote!((#(#column_name #constructor,)*))
+    };
+
+    let column_type_bounds = define_column_types.then(|| {
+        quote! {
+            #(#column_type: diesel::expression::KnownCastSqlTypeName<__DieselInternal>,)*
+        }
+    });
+    let walk_column_list = column_name
+        .iter()
+        .zip(&column_sql_name)
+        .zip(&column_type)
+        .enumerate()
+        .map(|(idx, ((_, sql_name), column_type))| {
+            let separator = (idx != 0).then(|| quote!(out.push_sql(", ");));
+            let column_type = define_column_types.then(|| {
+                quote! {
+                    out.push_sql(" ");
+                    out.push_sql(
+                        <#column_type as diesel::expression::KnownCastSqlTypeName<
+                            __DieselInternal,
+                        >>::SQL_TYPE_NAME,
+                    );
+                }
+            });
+            quote! {
+                #separator
+                out.push_identifier(#sql_name)?;
+                #column_type
+            }
+        });
+
+    let walk_json_table_column = column_sql_name.iter().zip(&column_type).enumerate().map(
+        |(idx, (sql_name, column_type))| {
+            let separator = (idx != 0).then(|| quote!(out.push_sql(", ");));
+            // the member name is quoted as JSON string inside of a SQL string literal
+            let member = sql_name
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\'', "''");
+            let path = format!(" PATH '$.\"{member}\"'");
+            quote! {
+                #separator
+                out.push_identifier(#sql_name)?;
+                out.push_sql(" ");
+                out.push_sql(
+                    <#column_type as diesel::internal::sql_functions::JsonTableColumnType<
+                        __DieselInternal,
+                    >>::JSON_TABLE_TYPE_NAME,
+                );
+                out.push_sql(#path);
+            }
+        },
+    );
+
+    let column_default_impl = column_name.iter().map(|column_name| {
+        if type_args.is_empty() {
+            None
+        } else {
+            Some(quote! {
+                impl #impl_generics Default for #column_name #ty_generics {
+                    fn default() -> Self {
+                        #column_name(::core::marker::PhantomData)
+                    }
+                }
+            })
+        }
+    });
+    let column_default_derive = type_args.is_empty().then(|| quote!(Default,));
+    let column_doc = column_sql_name.iter().map(|name| {
+        format!("The `{name}` column of the [`{fn_name}()`](fn@super::{fn_name}) table function")
+    });
+
+    let columns = column_name
+        .iter()
+        .zip(&column_sql_name)
+        .zip(&column_type)
+        .zip(column_doc)
+        .zip(column_default_impl)
+        .map(|((((column_name, sql_name), column_type), column_doc), default_impl)| {
+            quote! {
+                #[doc = #column_doc]
+                #[derive(Debug, Clone, Copy, #column_default_derive diesel::query_builder::QueryId)]
+                pub struct #column_name #ty_generics #struct_body
+
+                #default_impl
+
+                impl #impl_generics diesel::expression::Expression for #column_name #ty_generics
+                #where_clause
+                {
+                    type SqlType = #column_type;
+                }
+
+                impl #impl_generics_internal QueryFragment<__DieselInternal> for #column_name #ty_generics
+                #where_clause
+                    __DieselInternal: diesel::backend::Backend,
+                {
+                    fn walk_ast<'__b>(&'__b self, mut out: AstPass<'_, '__b, __DieselInternal>) -> QueryResult<()> {
+                        if !out.should_skip_from() {
+                            out.push_identifier(#alias_name)?;
+                            out.push_sql(".");
+                        }
+                        out.push_identifier(#sql_name)
+                    }
+                }
+
+                // __DieselInternal is what we call QS normally
+                impl #impl_generics_internal diesel::AppearsOnTable<__DieselInternal> for #column_name #ty_generics
+                #where_clause
+                    __DieselInternal: diesel::query_source::AppearsInFromClause<
+                        diesel::query_source::TableFunction<function #ty_generics>,
+                        Count = diesel::query_source::Once,
+                    >,
+                {
+                }
+
+                // __DieselInternal are the arguments of the function call here
+                impl #impl_generics_internal diesel::SelectableExpression<
+                    diesel::query_source::TableFunction<function #ty_generics, __DieselInternal>,
+                > for #column_name #ty_generics
+                #where_clause
+                {
+                }
+
+                impl #impl_generics_join diesel::SelectableExpression<
+                    diesel::internal::table_macro::Join<Left, Right, diesel::internal::table_macro::LeftOuter>,
+                > for #column_name #ty_generics
+                #where_clause
+                    Self: diesel::AppearsOnTable<diesel::internal::table_macro::Join<Left, Right, diesel::internal::table_macro::LeftOuter>>,
+                    Self: diesel::SelectableExpression<Left>,
+                    // If our function is on the right side of this join, only
+                    // `Nullable<Self>` can be selected
+                    Right: diesel::query_source::AppearsInFromClause<
+                        diesel::query_source::TableFunction<function #ty_generics>,
+                        Count = diesel::query_source::Never,
+                    > + diesel::query_source::QuerySource,
+                    Left: diesel::query_source::QuerySource,
+                {
+                }
+
+                impl #impl_generics_join diesel::SelectableExpression<
+                    diesel::internal::table_macro::Join<Left, Right, diesel::internal::table_macro::Inner>,
+                > for #column_name #ty_generics
+                #where_clause
+                    Self: diesel::AppearsOnTable<diesel::internal::table_macro::Join<Left, Right, diesel::internal::table_macro::Inner>>,
+                    Left: diesel::query_source::AppearsInFromClause<diesel::query_source::TableFunction<function #ty_generics>>
+                        + diesel::query_source::QuerySource,
+                    Right: diesel::query_source::AppearsInFromClause<diesel::query_source::TableFunction<function #ty_generics>>
+                        + diesel::query_source::QuerySource,
+                    (Left::Count, Right::Count): diesel::internal::table_macro::Pick<Left, Right>,
+                    Self: diesel::SelectableExpression<
+                        <(Left::Count, Right::Count) as diesel::internal::table_macro::Pick<Left, Right>>::Selection,
+                    >,
+                {
+                }
+
+                // FIXME: Remove this when overlapping marker traits are stable
+                impl #impl_generics_join_on diesel::SelectableExpression<
+                    diesel::internal::table_macro::JoinOn<Join, On>,
+                > for #column_name #ty_generics
+                #where_clause
+                    Self: diesel::SelectableExpression<Join>
+                        + diesel::AppearsOnTable<diesel::internal::table_macro::JoinOn<Join, On>>,
+                {
+                }
+
+                // FIXME: Remove this when overlapping marker traits are stable
+                impl #impl_generics_from diesel::SelectableExpression<
+                    diesel::internal::table_macro::SelectStatement<diesel::internal::table_macro::FromClause<From>>,
+                > for #column_name #ty_generics
+                #where_clause
+                    From: diesel::query_source::QuerySource,
+                    Self: diesel::SelectableExpression<From>
+                        + diesel::AppearsOnTable<diesel::internal::table_macro::SelectStatement<diesel::internal::table_macro::FromClause<From>>>,
+                {
+                }
+
+                impl #impl_generics_internal diesel::expression::ValidGrouping<__DieselInternal> for #column_name #ty_generics
+                #where_clause
+                    __DieselInternal: diesel::expression::IsContainedInGroupBy<
+                        Self,
+                        Output = diesel::expression::is_contained_in_group_by::Yes,
+                    >,
+                {
+                    type IsAggregate = diesel::expression::is_aggregate::Yes;
+                }
+
+                impl #impl_generics diesel::expression::ValidGrouping<()> for #column_name #ty_generics
+                #where_clause
+                {
+                    type IsAggregate = diesel::expression::is_aggregate::No;
+                }
+
+                impl #impl_generics diesel::expression::IsContainedInGroupBy<Self> for #column_name #ty_generics
+                #where_clause
+                {
+                    type Output = diesel::expression::is_contained_in_group_by::Yes;
+                }
+            }
+        });
+
+    let module_doc = format!("Columns of the [`{fn_name}()`](fn@{fn_name}) table function");
+    let function_doc =
+        format!("Identifies the [`{fn_name}()`](fn@super::{fn_name}) table function");
+    let helper_type_doc = format!("The return type of [`{fn_name}()`](fn@super::{fn_name})");
+    let args_iter = args.iter();
+
+    let tokens = quote! {
+        #(#cfg_attributes)*
+        #(#other_attributes)*
+        #[allow(non_camel_case_types)]
+        pub #fn_token #fn_name #fn_impl_generics (#(#args_iter,)*)
+            -> #fn_name::HelperType #fn_ty_generics
+        #where_clause
+            #(#arg_name: diesel::expression::AsExpression<#arg_type>,)*
+        {
+            diesel::query_source::TableFunction::new((#(#arg_name.as_expression(),)*))
+        }
+
+        #(#cfg_attributes)*
+        #[doc = #module_doc]
+        #[allow(non_camel_case_types, non_snake_case, unused_imports)]
+        pub mod #fn_name {
+            use diesel::{self, QueryResult};
+            use diesel::expression::AsExpression;
+            use diesel::query_builder::{QueryFragment, AstPass};
+            use diesel::sql_types::*;
+            use super::*;
+
+            #[doc = #function_doc]
+            #[derive(Debug, Clone, Copy, diesel::query_builder::QueryId)]
+            pub struct function #ty_generics #struct_body
+
+            #[doc = #helper_type_doc]
+            pub type HelperType #fn_ty_generics = diesel::query_source::TableFunction<
+                function #ty_generics,
+                (#(<#arg_name as AsExpression<#arg_type>>::Expression,)*),
+            >;
+
+            impl #impl_generics diesel::query_source::TableFunctionDefinition for function #ty_generics
+            #where_clause
+            {
+                const FUNCTION_NAME: &'static str = #sql_name;
+                const NAME: &'static str = #alias_name;
+                type Columns = #all_columns_type;
+
+                fn columns() -> Self::Columns {
+                    #all_columns_value
+                }
+            }
+
+            impl #impl_generics_internal diesel::internal::sql_functions::TableFunctionColumnList<__DieselInternal>
+                for function #ty_generics
+            #where_clause
+                __DieselInternal: diesel::backend::Backend,
+                #column_type_bounds
+            {
+                fn walk_column_list<'__b>(mut out: AstPass<'_, '__b, __DieselInternal>) -> QueryResult<()> {
+                    #(#walk_column_list)*
+                    Ok(())
+                }
+            }
+
+            impl #impl_generics_internal diesel::internal::sql_functions::JsonTableColumnList<__DieselInternal>
+                for function #ty_generics
+            #where_clause
+                __DieselInternal: diesel::backend::Backend,
+                #(#column_type: diesel::internal::sql_functions::JsonTableColumnType<__DieselInternal>,)*
+            {
+                fn walk_json_table_columns<'__b>(mut out: AstPass<'_, '__b, __DieselInternal>) -> QueryResult<()> {
+                    #(#walk_json_table_column)*
+                    Ok(())
+                }
+            }
+
+            #(#columns)*
+        }
+    };
+
+    Ok(ExpandedSqlFunction {
+        tokens,
+        return_type_helper_module_path: None,
+    })
+}
+
 fn generate_window_function_tokens(
     window: &AttributeSpanWrapper<SqlFunctionAttribute>,
     generics: Generics,
@@ -1237,6 +1665,10 @@ fn merge_attributes(
             | (
                 SqlFunctionAttribute::SkipReturnTypeHelper { .. },
                 SqlFunctionAttribute::SkipReturnTypeHelper { .. },
+            )
+            | (
+                SqlFunctionAttribute::TableFunction { .. },
+                SqlFunctionAttribute::TableFunction { .. },
             ) => false,
             _ => true,
         }) {
@@ -1876,6 +2308,11 @@ enum SqlFunctionAttribute {
     SkipReturnTypeHelper {
         ident: Ident,
     },
+    TableFunction {
+        ident: Ident,
+        columns: Vec<Ident>,
+        define_column_types: bool,
+    },
     Other(Attribute),
 }
 
@@ -1898,6 +2335,7 @@ impl MySpanned for SqlFunctionAttribute {
             | SqlFunctionAttribute::Window { ident, .. }
             | SqlFunctionAttribute::Variadic { ident, .. }
             | SqlFunctionAttribute::SkipReturnTypeHelper { ident, .. }
+            | SqlFunctionAttribute::TableFunction { ident, .. }
             | SqlFunctionAttribute::SqlName { ident, .. } => ident.span(),
             SqlFunctionAttribute::Restriction {
                 restriction: BackendRestriction::None,
@@ -2060,6 +2498,43 @@ impl SqlFunctionAttribute {
                         }
                     }
                 }
+                "table_function" => {
+                    let key: Ident = input.parse()?;
+                    if key != "columns" {
+                        return Err(syn::Error::new(key.span(), "expect `columns`"));
+                    }
+                    let content;
+                    let _paren = parenthesized!(content in input);
+                    let columns = content
+                        .parse_terminated(Ident::parse, Token![,])?
+                        .into_iter()
+                        .collect::<Vec<_>>();
+                    if columns.is_empty() {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "a table function needs at least one column",
+                        ));
+                    }
+                    let define_column_types = if input.peek(Token![,]) {
+                        let _: Token![,] = input.parse()?;
+                        let key: Ident = input.parse()?;
+                        if key != "define_column_types" {
+                            return Err(syn::Error::new(
+                                key.span(),
+                                "expect `define_column_types`",
+                            ));
+                        }
+                        let _eq: Token![=] = input.parse()?;
+                        input.parse::<LitBool>()?.value
+                    } else {
+                        false
+                    };
+                    Self::TableFunction {
+                        ident: name,
+                        columns,
+                        define_column_types,
+                    }
+                }
                 _ => {
                     // empty the parse buffer otherwise syn will return an error
                     let _ = input.step(|cursor| {
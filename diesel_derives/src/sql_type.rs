@@ -21,7 +21,8 @@ pub fn derive(item: DeriveInput) -> Result<TokenStream> {
 
     let is_array = struct_name == "Array" && generic_count == 1;
 
-    let enum_sql_type = if model.enum_type {
+    // the members of a set are mapped like enum variants
+    let enum_sql_type = if model.enum_type || model.set_type {
         let mut generics = item.generics.clone();
         generics.params.push(syn::parse_quote!(__DB));
         generics
@@ -48,6 +49,17 @@ pub fn derive(item: DeriveInput) -> Result<TokenStream> {
         None
     };
 
+    let set_sql_type = if model.set_type {
+        Some(quote::quote! {
+            impl #impl_generics diesel::sql_types::SetSqlType for #struct_name #ty_generics
+                #where_clause
+            {
+            }
+        })
+    } else {
+        None
+    };
+
     Ok(wrap_in_dummy_mod(quote! {
         impl #impl_generics diesel::sql_types::SqlType
             for #struct_name #ty_generics
@@ -65,6 +77,7 @@ pub fn derive(item: DeriveInput) -> Result<TokenStream> {
         }
 
         #enum_sql_type
+        #set_sql_type
         #sqlite_tokens
         #mysql_tokens
         #mariadb_tokens
@@ -576,6 +576,34 @@ fn expand(input: TableDecl, kind: QuerySourceMacroKind) -> TokenStream {
                     type Count = diesel::query_source::Once;
                 }
             }
+            diesel::internal::table_macro::expand_mysql_like! {
+                impl<S> diesel::JoinTo<diesel::query_builder::IndexHints<S>> for table
+                where
+                    diesel::query_builder::IndexHints<S>: diesel::JoinTo<table>,
+                {
+                    type FromClause = diesel::query_builder::IndexHints<S>;
+                    type OnClause = <diesel::query_builder::IndexHints<S> as diesel::JoinTo<table>>::OnClause;
+
+                    fn join_target(__diesel_internal_rhs: diesel::query_builder::IndexHints<S>) -> (Self::FromClause, Self::OnClause) {
+                        let (_, __diesel_internal_on_clause) = diesel::query_builder::IndexHints::<S>::join_target(table);
+                        (__diesel_internal_rhs, __diesel_internal_on_clause)
+                    }
+                }
+            }
+            diesel::internal::table_macro::expand_mysql_like! {
+                impl diesel::query_source::AppearsInFromClause<diesel::query_builder::IndexHints<table>>
+                    for table
+                {
+                    type Count = diesel::query_source::Once;
+                }
+            }
+            diesel::internal::table_macro::expand_mysql_like! {
+                impl diesel::query_source::AppearsInFromClause<table>
+                    for diesel::query_builder::IndexHints<table>
+                {
+                    type Count = diesel::query_source::Once;
+                }
+            }
         })
     } else {
         None
@@ -1086,6 +1114,18 @@ fn expand_column_def(
                 impl<TSM> diesel::SelectableExpression<diesel::query_builder::Tablesample<super::table, TSM>>
                     for #column_name where TSM: diesel::internal::table_macro::TablesampleMethod {}
             }
+            #(#cfg_attrs)*
+            diesel::internal::table_macro::expand_mysql_like! {
+                impl diesel::query_source::AppearsInFromClause<diesel::query_builder::IndexHints<super::table>>
+                    for #column_name
+                {
+                    type Count = diesel::query_source::Once;
+                }
+            }
+            #(#cfg_attrs)*
+            diesel::internal::table_macro::expand_mysql_like! {
+                impl diesel::SelectableExpression<diesel::query_builder::IndexHints<super::table>> for #column_name {}
+            }
         })
     } else {
         None
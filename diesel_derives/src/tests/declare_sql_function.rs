@@ -22,3 +22,20 @@ pub(crate) fn declare_sql_function_1() {
         name,
     );
 }
+
+#[test]
+pub(crate) fn declare_sql_function_table_function_1() {
+    let input = quote::quote! {
+        extern "SQL" {
+            #[table_function(columns(key, value))]
+            fn jsonb_each(input: Jsonb) -> (Text, Jsonb);
+        }
+    };
+    let attr = Default::default();
+    expand_with(
+        &crate::declare_sql_function_inner as &dyn Fn(_, _) -> _,
+        (attr, input),
+        AttributeMacro(syn::parse_quote!(diesel::declare_sql_function)),
+        "declare_sql_function_table_function_1",
+    );
+}
@@ -232,6 +232,8 @@ mod has_query;
 mod identifiable;
 mod insertable;
 mod multiconnection;
+#[cfg(feature = "postgres")]
+mod pg_composite;
 mod query_id;
 mod queryable;
 mod queryable_by_name;
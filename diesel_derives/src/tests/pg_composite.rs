@@ -0,0 +1,23 @@
+use super::derive;
+use super::expand_with;
+
+#[test]
+pub(crate) fn pg_composite_1() {
+    let input = quote::quote! {
+        #[derive(Debug, diesel::types::PgComposite)]
+        #[diesel(sql_type = schema::sql_types::Address)]
+        struct Address {
+            #[diesel(sql_type = diesel::sql_types::Text)]
+            street: String,
+            #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Integer>)]
+            number: Option<i32>,
+        }
+    };
+
+    expand_with(
+        &crate::derive_pg_composite_inner as &dyn Fn(_) -> _,
+        input,
+        derive(syn::parse_quote!(#[derive(PgComposite)])),
+        "pg_composite_1",
+    );
+}
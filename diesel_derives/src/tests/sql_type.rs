@@ -80,3 +80,32 @@ pub(crate) fn sql_type_enum() {
         name,
     );
 }
+
+#[test]
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+pub(crate) fn sql_type_set() {
+    let (input, name) = if cfg!(feature = "mysql") {
+        (
+            quote::quote! {#[diesel(mysql_type(name = "Set"))]},
+            "sql_type_set (mysql)",
+        )
+    } else {
+        (
+            quote::quote! {#[diesel(mariadb_type(name = "Set"))]},
+            "sql_type_set (mariadb)",
+        )
+    };
+
+    let input = quote::quote! {
+        #input
+        #[diesel(set_type)]
+        struct Features;
+    };
+
+    expand_with(
+        &crate::derive_sql_type_inner as &dyn Fn(_) -> _,
+        input,
+        derive(syn::parse_quote!(#[derive(SqlType)])),
+        name,
+    );
+}
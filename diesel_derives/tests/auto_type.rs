@@ -65,6 +65,7 @@ table! {
         text_array -> Array<Text>,
         record -> Record<(Integer, Text, Date)>,
         boolean -> Bool,
+        bits -> diesel::pg::sql_types::Bit,
     }
 }
 
@@ -369,6 +370,17 @@ fn test_pg_binary_expression_methods() -> _ {
         .and(pg_extras::blob.not_like(b))
 }
 
+#[cfg(feature = "postgres")]
+#[auto_type]
+fn test_pg_bit_string_expression_methods() -> _ {
+    pg_extras::bits
+        .bit_and(pg_extras::bits)
+        .bit_or(pg_extras::bits)
+        .bit_xor(pg_extras::bits)
+        .shift_left(1_i32)
+        .shift_right(1_i32)
+}
+
 #[cfg(feature = "postgres")]
 #[auto_type]
 fn test_pg_any_json_expression_methods() -> _ {
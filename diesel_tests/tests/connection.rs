@@ -202,3 +202,82 @@ fn use_the_same_connection_multiple_times() {
         insert_or_fetch(connection, 1);
     }
 }
+
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+struct DropProcedure<'a> {
+    connection: &'a mut TestConnection,
+    name: &'static str,
+}
+
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+impl<'a> DropProcedure<'a> {
+    fn create(connection: &'a mut TestConnection, name: &'static str, body: &str) -> Self {
+        use diesel::connection::SimpleConnection;
+
+        connection
+            .batch_execute(&format!("DROP PROCEDURE IF EXISTS {name}"))
+            .unwrap();
+        connection
+            .batch_execute(&format!("CREATE PROCEDURE {name}() BEGIN {body} END"))
+            .unwrap();
+        DropProcedure { connection, name }
+    }
+}
+
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+impl Drop for DropProcedure<'_> {
+    fn drop(&mut self) {
+        use diesel::connection::SimpleConnection;
+
+        self.connection
+            .batch_execute(&format!("DROP PROCEDURE IF EXISTS {}", self.name))
+            .unwrap();
+    }
+}
+
+#[diesel_test_helper::test]
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+fn call_procedure_returns_all_result_sets() {
+    use diesel::sql_types::{BigInt, Text};
+
+    let connection = &mut connection_without_transaction();
+    let procedure = DropProcedure::create(
+        connection,
+        "diesel_test_multiple_result_sets",
+        "SELECT 1 UNION ALL SELECT 2; SELECT 'Sean' UNION ALL SELECT 'Tess';",
+    );
+
+    let mut results = procedure
+        .connection
+        .call_procedure(procedure.name, ())
+        .unwrap();
+    let numbers = results.next().unwrap().load_typed::<BigInt, i64>().unwrap();
+    assert_eq!(vec![1, 2], numbers);
+    let names = results
+        .next()
+        .unwrap()
+        .load_typed::<Text, String>()
+        .unwrap();
+    assert_eq!(vec!["Sean", "Tess"], names);
+    assert!(results.next().is_none());
+}
+
+#[diesel_test_helper::test]
+#[cfg(any(feature = "mysql", feature = "mariadb"))]
+fn call_procedure_returns_error_if_a_later_result_set_fails() {
+    let connection = &mut connection_without_transaction();
+    let procedure = DropProcedure::create(
+        connection,
+        "diesel_test_failing_result_sets",
+        "SELECT 1; SELECT 2; \
+         SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'procedure failed';",
+    );
+
+    match procedure.connection.call_procedure(procedure.name, ()) {
+        Err(result::Error::DatabaseError(_, info)) => {
+            assert_eq!("procedure failed", info.message())
+        }
+        Err(e) => panic!("Unexpected error: {e}"),
+        Ok(_) => panic!("Expected the procedure call to fail"),
+    }
+}
@@ -212,3 +212,145 @@ fn custom_type_schema_inference() {
     assert_eq!(other_ty.oid().unwrap(), inferred_ty.oid().unwrap());
     assert_ne!(public_ty.oid().unwrap(), other_ty.oid().unwrap());
 }
+
+mod composite_sql_types {
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "address"))]
+    pub struct Address;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "location_color"))]
+    #[diesel(enum_type)]
+    pub struct Color;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "geo_point"))]
+    pub struct GeoPoint;
+}
+
+#[derive(Debug, PartialEq, diesel::types::PgComposite)]
+#[diesel(sql_type = composite_sql_types::Address)]
+struct Address {
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Text>)]
+    street: Option<String>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Int4>)]
+    number: Option<i32>,
+    #[diesel(sql_type = sql_types::Nullable<composite_sql_types::GeoPoint>)]
+    location: Option<GeoPoint>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Array<sql_types::Nullable<sql_types::Text>>>)]
+    tags: Option<Vec<Option<String>>>,
+    #[diesel(sql_type = sql_types::Nullable<composite_sql_types::Color>)]
+    color: Option<Color>,
+}
+
+#[derive(Debug, PartialEq, diesel::types::Enum)]
+#[diesel(sql_type = composite_sql_types::Color)]
+enum Color {
+    #[diesel(rename = "red")]
+    Red,
+    #[diesel(rename = "green")]
+    Green,
+}
+
+#[derive(Debug, PartialEq, diesel::types::PgComposite)]
+#[diesel(sql_type = composite_sql_types::GeoPoint)]
+struct GeoPoint {
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Float8>)]
+    lat: Option<f64>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Float8>)]
+    lon: Option<f64>,
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::composite_sql_types::Address;
+
+    locations (id) {
+        id -> Int4,
+        address -> Address,
+        previous_addresses -> Nullable<Array<Nullable<Address>>>,
+    }
+}
+
+#[derive(Insertable, Queryable, Debug, PartialEq)]
+#[diesel(table_name = locations)]
+struct Location {
+    id: i32,
+    address: Address,
+    previous_addresses: Option<Vec<Option<Address>>>,
+}
+
+#[diesel_test_helper::test]
+fn nested_composite_types_and_arrays_of_composite_types_round_trip() {
+    let connection = &mut connection();
+    connection
+        .batch_execute(
+            "
+        -- `color` is already used by the test schema
+        CREATE TYPE location_color AS ENUM ('red', 'green');
+        CREATE TYPE geo_point AS (lat float8, lon float8);
+        CREATE TYPE address AS (
+            street text,
+            number int4,
+            location geo_point,
+            tags text[],
+            color location_color
+        );
+        CREATE TABLE locations (
+            id SERIAL PRIMARY KEY,
+            address address NOT NULL,
+            previous_addresses address[]
+        );
+    ",
+        )
+        .unwrap();
+
+    let data = vec![
+        Location {
+            id: 1,
+            address: Address {
+                street: Some("Main Street".into()),
+                number: Some(42),
+                location: Some(GeoPoint {
+                    lat: Some(52.5),
+                    lon: None,
+                }),
+                tags: Some(vec![Some("home".into()), None]),
+                color: Some(Color::Red),
+            },
+            previous_addresses: Some(vec![
+                Some(Address {
+                    street: Some("Old Street".into()),
+                    number: None,
+                    location: None,
+                    tags: Some(Vec::new()),
+                    color: Some(Color::Green),
+                }),
+                None,
+            ]),
+        },
+        Location {
+            id: 2,
+            address: Address {
+                street: None,
+                number: None,
+                location: None,
+                tags: None,
+                color: None,
+            },
+            previous_addresses: None,
+        },
+    ];
+
+    let inserted = insert_into(locations::table)
+        .values(&data)
+        .get_results::<Location>(connection)
+        .unwrap();
+    assert_eq!(data, inserted);
+
+    let loaded = locations::table
+        .order(locations::id)
+        .load::<Location>(connection)
+        .unwrap();
+    assert_eq!(data, loaded);
+}